@@ -15,6 +15,10 @@ impl<B: Backend> BoolTensorOps<Self> for Autodiff<B> {
         B::bool_shape(tensor)
     }
 
+    fn bool_memory_footprint<const D: usize>(tensor: &BoolTensor<B, D>) -> usize {
+        B::bool_memory_footprint(tensor)
+    }
+
     fn bool_to_data<const D: usize>(tensor: &BoolTensor<B, D>) -> Reader<Data<bool, D>> {
         B::bool_to_data(tensor)
     }
@@ -18,6 +18,10 @@ impl<B: Backend> IntTensorOps<Autodiff<B>> for Autodiff<B> {
         B::int_shape(tensor)
     }
 
+    fn int_memory_footprint<const D: usize>(tensor: &IntTensor<B, D>) -> usize {
+        B::int_memory_footprint(tensor)
+    }
+
     fn int_to_data<const D: usize>(tensor: &IntTensor<B, D>) -> Reader<Data<B::IntElem, D>> {
         B::int_to_data(tensor)
     }
@@ -489,17 +489,18 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 3> {
         #[derive(Debug)]
         struct AvgPool1D;
 
         impl<B: Backend> Backward<B, 3, 1> for AvgPool1D {
-            type State = (B::TensorPrimitive<3>, usize, usize, usize, bool);
+            type State = (B::TensorPrimitive<3>, usize, usize, usize, bool, bool);
 
             fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
                 let [node_parent] = ops.parents;
                 let grad = grads.consume::<B, 3>(&ops.node);
-                let (x, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let (x, kernel_size, stride, padding, count_include_pad, ceil_mode) = ops.state;
 
                 if let Some(node) = node_parent {
                     let grad = B::avg_pool1d_backward(
@@ -509,6 +510,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                         stride,
                         padding,
                         count_include_pad,
+                        ceil_mode,
                     );
                     grads.register::<B, 3>(node, grad);
                 }
@@ -523,9 +525,17 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                     stride,
                     padding,
                     count_include_pad,
+                    ceil_mode,
                 );
                 prep.finish(
-                    (x.primitive, kernel_size, stride, padding, count_include_pad),
+                    (
+                        x.primitive,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                        ceil_mode,
+                    ),
                     output,
                 )
             }
@@ -535,6 +545,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                 stride,
                 padding,
                 count_include_pad,
+                ceil_mode,
             )),
         }
     }
@@ -545,6 +556,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 4> {
         #[derive(Debug)]
         struct AvgPool2D;
@@ -556,12 +568,13 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                 [usize; 2],
                 [usize; 2],
                 bool,
+                bool,
             );
 
             fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
                 let [node_parent] = ops.parents;
                 let grad = grads.consume::<B, 4>(&ops.node);
-                let (x, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let (x, kernel_size, stride, padding, count_include_pad, ceil_mode) = ops.state;
 
                 if let Some(node) = node_parent {
                     let grad = B::avg_pool2d_backward(
@@ -571,6 +584,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                         stride,
                         padding,
                         count_include_pad,
+                        ceil_mode,
                     );
                     grads.register::<B, 4>(node, grad);
                 }
@@ -585,9 +599,17 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                     stride,
                     padding,
                     count_include_pad,
+                    ceil_mode,
                 );
                 prep.finish(
-                    (x.primitive, kernel_size, stride, padding, count_include_pad),
+                    (
+                        x.primitive,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                        ceil_mode,
+                    ),
                     output,
                 )
             }
@@ -597,6 +619,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                 stride,
                 padding,
                 count_include_pad,
+                ceil_mode,
             )),
         }
     }
@@ -608,6 +631,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         _stride: [usize; 2],
         _padding: [usize; 2],
         _count_include_pad: bool,
+        _ceil_mode: bool,
     ) -> AutodiffTensor<B, 4> {
         panic!("Can't differentiate avg pool 2d backward.");
     }
@@ -618,6 +642,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 3> {
         match MaxPool1D.prepare([x.node], [x.graph]).stateful() {
             OpsKind::Tracked(prep) => {
@@ -627,6 +652,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                     stride,
                     padding,
                     dilation,
+                    ceil_mode,
                 );
                 prep.finish(
                     (
@@ -636,6 +662,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 )
@@ -646,6 +673,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
             )),
         }
     }
@@ -656,6 +684,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<Autodiff<B>> {
         match MaxPool1D.prepare([x.node], [x.graph]).stateful() {
             OpsKind::Tracked(prep) => {
@@ -665,6 +694,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                     stride,
                     padding,
                     dilation,
+                    ceil_mode,
                 );
 
                 let output_tensor = prep.finish(
@@ -675,6 +705,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 );
@@ -682,8 +713,14 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                 MaxPool1dWithIndices::new(output_tensor, output.indices)
             }
             OpsKind::UnTracked(prep) => {
-                let output =
-                    B::max_pool1d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool1d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
                 let output_tensor = prep.finish(output.output);
 
                 MaxPool1dWithIndices::new(output_tensor, output.indices)
@@ -697,6 +734,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
         output_grad: AutodiffTensor<B, 3>,
         indices: IntTensor<B, 3>,
     ) -> MaxPool1dBackward<Autodiff<B>> {
@@ -706,6 +744,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             output_grad.primitive,
             indices,
         );
@@ -718,6 +757,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 4> {
         match MaxPool2D.prepare([x.node], [x.graph]).stateful() {
             OpsKind::Tracked(prep) => {
@@ -727,6 +767,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                     stride,
                     padding,
                     dilation,
+                    ceil_mode,
                 );
                 prep.finish(
                     (
@@ -736,6 +777,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 )
@@ -746,6 +788,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
             )),
         }
     }
@@ -756,6 +799,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<Autodiff<B>> {
         match MaxPool2D.prepare([x.node], [x.graph]).stateful() {
             OpsKind::Tracked(prep) => {
@@ -765,6 +809,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                     stride,
                     padding,
                     dilation,
+                    ceil_mode,
                 );
 
                 let output_tensor = prep.finish(
@@ -775,6 +820,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 );
@@ -782,8 +828,14 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
                 MaxPool2dWithIndices::new(output_tensor, output.indices)
             }
             OpsKind::UnTracked(prep) => {
-                let output =
-                    B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool2d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
                 let output_tensor = prep.finish(output.output);
 
                 MaxPool2dWithIndices::new(output_tensor, output.indices)
@@ -797,6 +849,7 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         _stride: [usize; 2],
         _padding: [usize; 2],
         _dilation: [usize; 2],
+        _ceil_mode: bool,
         _output_grad: AutodiffTensor<B, 4>,
         _indices: IntTensor<B, 4>,
     ) -> MaxPool2dBackward<Autodiff<B>> {
@@ -882,12 +935,13 @@ impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
         usize,
         usize,
         usize,
+        bool,
     );
 
     fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
         let [node_parent] = ops.parents;
         let grad = grads.consume::<B, 3>(&ops.node);
-        let (x, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let (x, indices, kernel_size, stride, padding, dilation, ceil_mode) = ops.state;
 
         if let Some(node) = node_parent {
             let grad = B::max_pool1d_with_indices_backward(
@@ -896,6 +950,7 @@ impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
                 grad,
                 indices,
             );
@@ -916,12 +971,13 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
         [usize; 2],
         [usize; 2],
         [usize; 2],
+        bool,
     );
 
     fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
         let [node_parent] = ops.parents;
         let grad = grads.consume::<B, 4>(&ops.node);
-        let (x, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let (x, indices, kernel_size, stride, padding, dilation, ceil_mode) = ops.state;
 
         if let Some(node) = node_parent {
             let grad = B::max_pool2d_with_indices_backward(
@@ -930,6 +986,7 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
                 grad,
                 indices,
             );
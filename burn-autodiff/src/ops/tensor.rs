@@ -45,6 +45,10 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         B::shape(&tensor.primitive)
     }
 
+    fn memory_footprint<const D: usize>(tensor: &FloatTensor<Self, D>) -> usize {
+        B::memory_footprint(&tensor.primitive)
+    }
+
     fn to_data<const D: usize>(tensor: &FloatTensor<Self, D>) -> Reader<Data<FloatElem<B>, D>> {
         B::to_data(&tensor.primitive)
     }
@@ -461,6 +465,71 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Floor;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Floor {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::shape(&grad);
+                    let device = B::device(&grad);
+
+                    B::zeros(shape, &device)
+                });
+            }
+        }
+
+        Floor
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::floor(tensor.primitive))
+    }
+
+    fn ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Ceil;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Ceil {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::shape(&grad);
+                    let device = B::device(&grad);
+
+                    B::zeros(shape, &device)
+                });
+            }
+        }
+
+        Ceil.prepare([tensor.node], [tensor.graph])
+            .stateless(B::ceil(tensor.primitive))
+    }
+
+    fn round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Round;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Round {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::shape(&grad);
+                    let device = B::device(&grad);
+
+                    B::zeros(shape, &device)
+                });
+            }
+        }
+
+        Round
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::round(tensor.primitive))
+    }
+
     fn swap_dims<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim1: usize,
@@ -571,24 +640,17 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         struct Scatter;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Scatter {
-            type State = (usize, IntTensor<B, D>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, D>);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::zeros(shape_lhs, &device);
-                        B::scatter(dim, grad, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::zeros(shape_rhs, &device);
-                        B::scatter(dim, zeros, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| grad,
+                    |grad| B::gather(dim, grad, indices),
                 );
             }
         }
@@ -598,13 +660,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             .stateful()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::shape(&tensor.primitive),
-                    B::shape(&value.primitive),
-                    B::device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::scatter(dim, tensor.primitive, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => {
@@ -872,6 +928,14 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         B::equal(lhs.primitive, rhs.primitive)
     }
 
+    fn is_nan<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<B, D> {
+        B::is_nan(tensor.primitive)
+    }
+
+    fn is_inf<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<B, D> {
+        B::is_inf(tensor.primitive)
+    }
+
     fn equal_elem<const D: usize>(
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
@@ -1019,6 +1083,67 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn prod<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        #[derive(Debug)]
+        struct Prod<const D: usize>;
+
+        impl<B: Backend, const D: usize> Backward<B, 1, 1> for Prod<D> {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, 1, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let tensor: Tensor<B, D> = Tensor::from_primitive(ops.state);
+                    let grad: Tensor<B, 1> = Tensor::from_primitive(grad);
+                    let shape = tensor.shape();
+                    let tensor: Tensor<B, 1> = tensor.reshape([shape.num_elements()]);
+
+                    // The gradient of each element is the product of all the other elements,
+                    // computed without dividing the product so that zeros are supported.
+                    let others = exclusive_products(tensor.clone(), false)
+                        .mul(exclusive_products(tensor, true));
+
+                    others.mul(grad).reshape(shape).into_primitive()
+                });
+            }
+        }
+
+        /// The product of the elements before each element, or after it when reversed, computed
+        /// with a logarithmic number of shifted multiplications.
+        fn exclusive_products<B: Backend>(tensor: Tensor<B, 1>, reverse: bool) -> Tensor<B, 1> {
+            let [size] = tensor.dims();
+            let device = tensor.device();
+
+            if size <= 1 {
+                return Tensor::ones([size], &device);
+            }
+
+            let shift = |tensor: Tensor<B, 1>, offset: usize| {
+                let ones = Tensor::ones([offset], &device);
+                match reverse {
+                    false => Tensor::cat(vec![ones, tensor.narrow(0, 0, size - offset)], 0),
+                    true => Tensor::cat(vec![tensor.narrow(0, offset, size - offset), ones], 0),
+                }
+            };
+
+            let mut products = shift(tensor, 1);
+            let mut offset = 1;
+
+            while offset < size {
+                products = products.clone().mul(shift(products, offset));
+                offset *= 2;
+            }
+
+            products
+        }
+
+        match Prod.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::prod(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::prod(tensor.primitive)),
+        }
+    }
+
     fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
         #[derive(Debug)]
         struct MeamDim;
@@ -1285,6 +1410,27 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Sign;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Sign {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::shape(&grad);
+                    let device = B::device(&grad);
+
+                    B::zeros(shape, &device)
+                });
+            }
+        }
+
+        Sign.prepare([tensor.node], [tensor.graph])
+            .stateless(B::sign(tensor.primitive))
+    }
+
     fn cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         #[derive(Debug)]
         struct Cos;
@@ -123,4 +123,23 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[18.0, 18.0], [71.0, 71.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_sum_stable() {
+        let data = Data::<f32, 2>::from([[1.0, 7.0, 2.0], [-2.0, -3.0, 0.5]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .mul(tensor_1.clone().sum_stable().unsqueeze());
+        let grads = tensor_2.sum().backward();
+
+        // d/dx_i (sum(x) * sum(x)) = 2 * sum(x)
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[11.0, 11.0, 11.0], [11.0, 11.0, 11.0]]), 5);
+    }
 }
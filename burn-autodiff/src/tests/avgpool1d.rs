@@ -92,6 +92,7 @@ mod tests {
                 self.stride,
                 self.padding,
                 self.count_include_pad,
+                false,
             );
             let grads = output.backward();
             let x_grad_actual = x.grad(&grads).unwrap();
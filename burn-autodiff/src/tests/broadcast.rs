@@ -33,6 +33,48 @@ mod tests {
         test_ops_broadcast_backward(|x, y| x.mask_where(y.clone().equal_elem(4), y));
     }
 
+    #[test]
+    fn should_sum_broadcasted_dims_of_both_operands() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[1.0, 2.0, 3.0, 4.0]], [[5.0, 6.0, 7.0, 8.0]]],
+            &device,
+        )
+        .require_grad();
+        let y = TestAutodiffTensor::from_floats([[[1.0], [2.0], [4.0]]], &device).require_grad();
+
+        let z = x.clone() * y.clone() + x.clone();
+        assert_eq!(z.dims(), [2, 3, 4]);
+        let grads = z.sum().backward();
+
+        // Each element of x is used by the 3 rows of y, and each element of y by the 8 of x.
+        let x_grad = x.grad(&grads).unwrap();
+        let y_grad = y.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[10.0; 4]], [[10.0; 4]]]), 5);
+        y_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[36.0], [36.0], [36.0]]]), 5);
+    }
+
+    #[test]
+    fn should_sum_broadcasted_single_element() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+        let s = TestAutodiffTensor::from_floats([2.0], &device).require_grad();
+
+        let grads = (x.clone() / s.clone()).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let s_grad = s.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([0.5, 0.5, 0.5]), 5);
+        // d(x / s)/ds = -x / s^2, summed over x.
+        s_grad.to_data().assert_approx_eq(&Data::from([-1.5]), 5);
+    }
+
     fn test_ops_broadcast_backward<F>(func: F)
     where
         F: Fn(TestAutodiffTensor<3>, TestAutodiffTensor<3>) -> TestAutodiffTensor<3>,
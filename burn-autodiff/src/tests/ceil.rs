@@ -0,0 +1,20 @@
+#[burn_tensor_testgen::testgen(ad_ceil)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_ceil() {
+        let data = Data::<f32, 2>::from([[-2.5, -1.5], [1.5, 2.5]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().ceil().mul_scalar(3.0);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+    }
+}
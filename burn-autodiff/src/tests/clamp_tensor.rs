@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_clamp_tensor)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_clamp_tensor() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_floats([-2.0, 0.5, 3.0, 1.0], &device).require_grad();
+        let min = TestAutodiffTensor::from_floats([-1.0, 0.0, 0.0, 0.0], &device).require_grad();
+        let max = TestAutodiffTensor::from_floats([1.0, 2.0, 2.0, 2.0], &device).require_grad();
+
+        let output = tensor.clone().clamp_tensor(min.clone(), max.clone());
+        let grads = output.mul_scalar(3.0).sum().backward();
+
+        // The gradient is zeroed where the input is clamped and flows to the selected bound.
+        let tensor_grad = tensor.grad(&grads).unwrap();
+        let min_grad = min.grad(&grads).unwrap();
+        let max_grad = max.grad(&grads).unwrap();
+        assert_eq!(tensor_grad.to_data(), Data::from([0.0, 3.0, 0.0, 3.0]));
+        assert_eq!(min_grad.to_data(), Data::from([3.0, 0.0, 0.0, 0.0]));
+        assert_eq!(max_grad.to_data(), Data::from([0.0, 0.0, 3.0, 0.0]));
+    }
+}
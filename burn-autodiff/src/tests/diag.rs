@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(ad_diag)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_diag() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(
+            Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::from_data(Data::from([1.0, 2.0, 3.0]), &device);
+
+        let tensor_2 = tensor_1.clone().diag().mul(weights).sum();
+        let grads = tensor_2.backward();
+
+        // Only the diagonal positions receive a gradient
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_diag_embed() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data(Data::from([1.0, 2.0, 3.0]), &device).require_grad();
+        let weights = TestAutodiffTensor::from_data(
+            Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
+            &device,
+        );
+
+        let tensor_2 = tensor_1.clone().diag_embed().mul(weights).sum();
+        let grads = tensor_2.backward();
+
+        // The off-diagonal weights don't contribute to the gradient
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        assert_eq!(grad_1.into_data(), Data::from([1.0, 5.0, 9.0]));
+    }
+}
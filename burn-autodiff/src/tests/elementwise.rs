@@ -0,0 +1,100 @@
+#[burn_tensor_testgen::testgen(ad_elementwise)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+    use core::f32::consts::E;
+
+    fn tensor<const N: usize>(values: [f32; N]) -> TestAutodiffTensor<1> {
+        TestAutodiffTensor::from_floats(values, &Default::default()).require_grad()
+    }
+
+    #[test]
+    fn should_diff_mul_elementwise() {
+        let x = tensor([1.0, 2.0, 3.0]);
+        let y = tensor([4.0, 5.0, 6.0]);
+
+        let grads = x.clone().mul(y.clone()).sum().backward();
+
+        // d(xy)/dx = y, d(xy)/dy = x
+        let x_grad = x.grad(&grads).unwrap();
+        let y_grad = y.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([4.0, 5.0, 6.0]), 4);
+        y_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0, 3.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_div_elementwise() {
+        let x = tensor([1.0, 2.0, 3.0]);
+        let y = tensor([2.0, 4.0, 0.5]);
+
+        let grads = x.clone().div(y.clone()).sum().backward();
+
+        // d(x/y)/dx = 1/y, d(x/y)/dy = -x/y^2
+        let x_grad = x.grad(&grads).unwrap();
+        let y_grad = y.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([0.5, 0.25, 2.0]), 4);
+        y_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.25, -0.125, -12.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_powf_elementwise() {
+        let x = tensor([1.0, 2.0, -3.0]);
+
+        let grads = x.clone().powf(3.0).sum().backward();
+
+        // d(x^3)/dx = 3x^2
+        let grad = x.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([3.0, 12.0, 27.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_exp_log_elementwise() {
+        let x = tensor([0.0, 1.0, -1.0]);
+        let y = tensor([1.0, 2.0, 4.0]);
+
+        let grads = x.clone().exp().add(y.clone().log()).sum().backward();
+
+        // d(exp(x))/dx = exp(x), d(log(y))/dy = 1/y
+        let x_grad = x.grad(&grads).unwrap();
+        let y_grad = y.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, E, (-1.0f32).exp()]), 4);
+        y_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, 0.5, 0.25]), 4);
+    }
+
+    #[test]
+    fn should_diff_abs_elementwise() {
+        let x = tensor([-2.0, 0.5, 3.0]);
+
+        let grads = x.clone().abs().sum().backward();
+
+        // d|x|/dx = sign(x)
+        let grad = x.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([-1.0, 1.0, 1.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_clamp_elementwise() {
+        let x = tensor([-2.0, 0.5, 3.0, -0.25]);
+
+        let grads = x.clone().clamp(-1.0, 1.0).mul_scalar(2.0).sum().backward();
+
+        // The gradient only flows where the input isn't clamped.
+        let grad = x.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([0.0, 2.0, 0.0, 2.0]), 4);
+    }
+}
@@ -26,4 +26,17 @@ mod tests {
             3,
         );
     }
+
+    #[test]
+    fn should_diff_exp_elementwise() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats([-1.0, 0.0, 2.0], &device).require_grad();
+
+        let grads = x.clone().exp().sum().backward();
+
+        // d/dx exp(x) = exp(x)
+        let grad = x.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([0.36788, 1.0, 7.38906]), 4);
+    }
 }
@@ -60,4 +60,25 @@ mod tests {
             Data::from([[19., 19., 19.], [64., 64., 64.]])
         );
     }
+
+    #[test]
+    fn test_scatter_grad_values_smaller_than_tensor() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(Data::from([[0.0, 1.0, 2.0, 3.0]]), &device)
+            .require_grad();
+        let values =
+            TestAutodiffTensor::from_data(Data::from([[5.0, 6.0]]), &device).require_grad();
+        let weights = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0, 3.0, 4.0]]), &device);
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_data(Data::from([[3, 0]]), &device);
+
+        let tensor_2 = tensor_1.clone().scatter(1, indices, values.clone());
+        let grads = tensor_2.mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[1., 2., 3., 4.]]));
+        assert_eq!(grad_2.into_data(), Data::from([[4., 1.]]));
+    }
 }
@@ -26,4 +26,17 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[22.8614, 24.5043], [24.5729, 26.8507]]), 3);
     }
+
+    #[test]
+    fn should_diff_log_elementwise() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats([0.5, 1.0, 4.0], &device).require_grad();
+
+        let grads = x.clone().log().sum().backward();
+
+        // d/dx log(x) = 1 / x
+        let grad = x.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([2.0, 1.0, 0.25]), 4);
+    }
 }
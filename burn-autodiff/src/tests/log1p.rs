@@ -23,4 +23,17 @@ mod tests {
             3,
         );
     }
+
+    #[test]
+    fn should_diff_log1p_elementwise() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats([0.0, 1.0, 3.0], &device).require_grad();
+
+        let grads = x.clone().log1p().sum().backward();
+
+        // d/dx log(1 + x) = 1 / (1 + x)
+        let grad = x.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([1.0, 0.5, 0.25]), 4);
+    }
 }
@@ -25,6 +25,21 @@ mod tests {
         assert_eq!(grad_2.to_data(), Data::from([[2.0, 1.0], [3.0, 7.0]]));
     }
 
+    #[test]
+    fn should_diff_tril() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[5.0, 6.0], [7.0, 8.0]], &device);
+
+        let tensor_3 = tensor_1.clone().tril(0).mul(tensor_2).sum();
+        let grads = tensor_3.backward();
+
+        // The masked elements above the diagonal don't receive a gradient
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        assert_eq!(grad_1.to_data(), Data::from([[5.0, 0.0], [7.0, 8.0]]));
+    }
+
     #[test]
     fn should_diff_mask_where() {
         let device = Default::default();
@@ -58,4 +73,27 @@ mod tests {
             .into_data()
             .assert_approx_eq(&Data::from([[15., 18.], [23., 29.]]), 3);
     }
+
+    #[test]
+    fn should_diff_select_where() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_data([[4.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let condition = Tensor::<TestAutodiffBackend, 2, Bool>::from_data(
+            [[true, false], [false, true]],
+            &device,
+        );
+
+        let tensor_3 = Tensor::select_where(condition, tensor_1.clone(), tensor_2.clone());
+        let tensor_4 = tensor_3.mul_scalar(2.0);
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, 0.0], [0.0, 2.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[0.0, 2.0], [2.0, 0.0]]));
+    }
 }
@@ -78,4 +78,122 @@ mod tests {
             Data::from([[264., 264.0], [344.0, 344.0]])
         );
     }
+
+    #[test]
+    fn should_diff_matmul_broadcast_rhs_batch() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats(
+            [
+                [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+                [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+                [[12.0, 13.0, 14.0], [15.0, 16.0, 17.0]],
+                [[18.0, 19.0, 20.0], [21.0, 22.0, 23.0]],
+            ],
+            &device,
+        )
+        .require_grad();
+        let tensor_2 = TestAutodiffTensor::from_floats(
+            [[
+                [0.0, 1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0, 9.0],
+                [10.0, 11.0, 12.0, 13.0, 14.0],
+            ]],
+            &device,
+        )
+        .require_grad();
+
+        let tensor_3 = tensor_1.clone().matmul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([
+                [[10.0, 35.0, 60.0], [10.0, 35.0, 60.0]],
+                [[10.0, 35.0, 60.0], [10.0, 35.0, 60.0]],
+                [[10.0, 35.0, 60.0], [10.0, 35.0, 60.0]],
+                [[10.0, 35.0, 60.0], [10.0, 35.0, 60.0]]
+            ])
+        );
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[
+                [84.0, 84.0, 84.0, 84.0, 84.0],
+                [92.0, 92.0, 92.0, 92.0, 92.0],
+                [100.0, 100.0, 100.0, 100.0, 100.0]
+            ]])
+        );
+    }
+
+    #[test]
+    fn should_diff_matmul_broadcast_lhs_batch() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]], &device)
+                .require_grad();
+        let tensor_2 = TestAutodiffTensor::from_floats(
+            [
+                [
+                    [0.0, 1.0, 2.0, 3.0, 4.0],
+                    [5.0, 6.0, 7.0, 8.0, 9.0],
+                    [10.0, 11.0, 12.0, 13.0, 14.0],
+                ],
+                [
+                    [15.0, 16.0, 17.0, 18.0, 19.0],
+                    [20.0, 21.0, 22.0, 23.0, 24.0],
+                    [25.0, 26.0, 27.0, 28.0, 29.0],
+                ],
+                [
+                    [30.0, 31.0, 32.0, 33.0, 34.0],
+                    [35.0, 36.0, 37.0, 38.0, 39.0],
+                    [40.0, 41.0, 42.0, 43.0, 44.0],
+                ],
+                [
+                    [45.0, 46.0, 47.0, 48.0, 49.0],
+                    [50.0, 51.0, 52.0, 53.0, 54.0],
+                    [55.0, 56.0, 57.0, 58.0, 59.0],
+                ],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let tensor_3 = tensor_1.clone().matmul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[[490.0, 590.0, 690.0], [490.0, 590.0, 690.0]]])
+        );
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([
+                [
+                    [3.0, 3.0, 3.0, 3.0, 3.0],
+                    [5.0, 5.0, 5.0, 5.0, 5.0],
+                    [7.0, 7.0, 7.0, 7.0, 7.0]
+                ],
+                [
+                    [3.0, 3.0, 3.0, 3.0, 3.0],
+                    [5.0, 5.0, 5.0, 5.0, 5.0],
+                    [7.0, 7.0, 7.0, 7.0, 7.0]
+                ],
+                [
+                    [3.0, 3.0, 3.0, 3.0, 3.0],
+                    [5.0, 5.0, 5.0, 5.0, 5.0],
+                    [7.0, 7.0, 7.0, 7.0, 7.0]
+                ],
+                [
+                    [3.0, 3.0, 3.0, 3.0, 3.0],
+                    [5.0, 5.0, 5.0, 5.0, 5.0],
+                    [7.0, 7.0, 7.0, 7.0, 7.0]
+                ]
+            ])
+        );
+    }
 }
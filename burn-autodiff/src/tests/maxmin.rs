@@ -48,4 +48,43 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[10.0, 8.0], [15.0, 56.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_maximum() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0], [-2.0, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_floats([[4.0, -7.0], [2.0, 3.0]], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().maximum(tensor_2.clone());
+        let tensor_4 = tensor_3.mul_scalar(2.0);
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[0.0, 2.0], [0.0, 2.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[2.0, 0.0], [2.0, 0.0]]));
+    }
+
+    #[test]
+    fn should_diff_minimum() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0], [-2.0, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_floats([[4.0, -7.0], [2.0, 3.0]], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().minimum(tensor_2.clone());
+        let tensor_4 = tensor_3.mul(tensor_1.clone());
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        // The product also depends on tensor_1 directly: d/dx (min(x, y) * x).
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, -7.0], [-4.0, 6.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[0.0, 7.0], [0.0, 0.0]]));
+    }
 }
@@ -19,7 +19,7 @@ mod tests {
         let x_grad_expected =
             TestAutodiffTensor::from_floats([[[1., 1., 0., 0., 0., 1.]]], &device);
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
         let grads = output.backward();
 
         // Asserts
@@ -54,7 +54,7 @@ mod tests {
             &device,
         );
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
         let grads = output.backward();
 
         // Asserts
@@ -89,7 +89,7 @@ mod tests {
             &device,
         );
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
         let grads = output.backward();
 
         // Asserts
@@ -124,7 +124,7 @@ mod tests {
             &device,
         );
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
         let grads = output.backward();
 
         // Asserts
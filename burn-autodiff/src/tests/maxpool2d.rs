@@ -41,6 +41,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
         let grads = output.backward();
 
@@ -89,6 +90,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
         let grads = output.backward();
 
@@ -137,6 +139,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
         let grads = output.backward();
 
@@ -187,6 +190,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
         let grads = output.backward();
 
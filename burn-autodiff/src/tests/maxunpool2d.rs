@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(ad_max_unpool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool2d_with_indices, max_unpool2d};
+
+    #[test]
+    fn test_max_unpool2d_backward() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[
+                [1., 5., 2., 0.],
+                [3., 4., 8., 6.],
+                [9., 0., 1., 2.],
+                [7., 6., 3., 4.],
+            ]]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [[[
+                [0., 1., 2., 3.],
+                [4., 5., 6., 7.],
+                [8., 9., 10., 11.],
+                [12., 13., 14., 15.],
+            ]]],
+            &device,
+        );
+        let x_grad_expected = TestAutodiffTensor::from_floats(
+            [[[
+                [0., 1., 0., 0.],
+                [0., 0., 6., 0.],
+                [8., 0., 0., 0.],
+                [0., 0., 0., 15.],
+            ]]],
+            &device,
+        );
+
+        let (pooled, indices) =
+            max_pool2d_with_indices(x.clone(), [2, 2], [2, 2], [0, 0], [1, 1], false);
+        let output = max_unpool2d(pooled, indices, [2, 2], [2, 2], [0, 0], None);
+        let grads = output.mul(weights).sum().backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
@@ -10,6 +10,8 @@ mod avgpool2d;
 mod backward;
 mod broadcast;
 mod cat;
+mod ceil;
+mod clamp_tensor;
 mod complex;
 mod conv1d;
 mod conv2d;
@@ -17,9 +19,12 @@ mod conv_transpose1d;
 mod conv_transpose2d;
 mod cos;
 mod cross_entropy;
+mod diag;
 mod div;
+mod elementwise;
 mod erf;
 mod exp;
+mod floor;
 mod gather_scatter;
 mod gelu;
 mod gradients;
@@ -30,17 +35,24 @@ mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod maxunpool2d;
 mod mul;
 mod multithread;
 mod neg;
 mod pow;
+mod prod;
 mod recip;
+mod reduce_dim;
 mod relu;
 mod reshape;
+mod roll;
+mod round;
 mod select;
+mod sign;
 mod sin;
 mod slice;
 mod softmax;
+mod sort;
 mod sqrt;
 mod sub;
 mod tanh;
@@ -67,6 +79,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_conv_transpose2d!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
+        burn_autodiff::testgen_ad_max_unpool2d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
@@ -82,7 +95,9 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_diag!();
         burn_autodiff::testgen_ad_div!();
+        burn_autodiff::testgen_ad_elementwise!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
@@ -95,10 +110,19 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
         burn_autodiff::testgen_ad_powf!();
+        burn_autodiff::testgen_ad_prod!();
         burn_autodiff::testgen_ad_recip!();
+        burn_autodiff::testgen_ad_reduce_dim!();
+        burn_autodiff::testgen_ad_floor!();
+        burn_autodiff::testgen_ad_ceil!();
+        burn_autodiff::testgen_ad_clamp_tensor!();
+        burn_autodiff::testgen_ad_round!();
         burn_autodiff::testgen_ad_reshape!();
+        burn_autodiff::testgen_ad_roll!();
         burn_autodiff::testgen_ad_sin!();
+        burn_autodiff::testgen_ad_sign!();
         burn_autodiff::testgen_ad_softmax!();
+        burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_sqrt!();
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(ad_prod)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_prod() {
+        let tensor = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &Default::default())
+            .require_grad();
+
+        let grads = tensor.clone().prod().mul_scalar(2.0).backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[48.0, 24.0], [16.0, 12.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_prod_with_zeros() {
+        let device = Default::default();
+        let one_zero =
+            TestAutodiffTensor::from_floats([2.0, 0.0, 3.0, 4.0, 5.0], &device).require_grad();
+        let two_zeros =
+            TestAutodiffTensor::from_floats([2.0, 0.0, 3.0, 0.0], &device).require_grad();
+
+        let grads_one_zero = one_zero.clone().prod().backward();
+        let grads_two_zeros = two_zeros.clone().prod().backward();
+
+        // Only the zero gets the product of the other elements.
+        let grad = one_zero.grad(&grads_one_zero).unwrap();
+        assert_eq!(grad.to_data(), Data::from([0.0, 120.0, 0.0, 0.0, 0.0]));
+        let grad = two_zeros.grad(&grads_two_zeros).unwrap();
+        assert_eq!(grad.to_data(), Data::from([0.0, 0.0, 0.0, 0.0]));
+    }
+}
@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(ad_reduce_dim)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    fn input() -> TestAutodiffTensor<2> {
+        TestAutodiffTensor::from_floats([[1.0, -2.0, 3.0], [4.0, 5.0, -6.0]], &Default::default())
+            .require_grad()
+    }
+
+    fn weights<const D: usize>(weights: [[f32; D]; 1]) -> TestAutodiffTensor<2> {
+        TestAutodiffTensor::from_floats(weights, &Default::default())
+    }
+
+    #[test]
+    fn should_broadcast_sum_dim_gradient() {
+        let x = input();
+
+        // The output gradients are weighted so that each reduced position can be told apart.
+        let grads_dim_0 = x
+            .clone()
+            .sum_dim(0)
+            .mul(weights([[1.0, 2.0, 3.0]]))
+            .sum()
+            .backward();
+        let grads_dim_1 = x.clone().sum_dim(1).mul(weights([[2.0]])).sum().backward();
+
+        let grad = x.grad(&grads_dim_0).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]), 4);
+        let grad = x.grad(&grads_dim_1).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0, 2.0], [2.0, 2.0, 2.0]]), 4);
+    }
+
+    #[test]
+    fn should_broadcast_mean_dim_gradient() {
+        let x = input();
+        let rows = TestAutodiffTensor::from_floats([[3.0], [6.0]], &Default::default());
+
+        let grads_dim_0 = x
+            .clone()
+            .mean_dim(0)
+            .mul(weights([[1.0, 2.0, 3.0]]))
+            .sum()
+            .backward();
+        let grads_dim_1 = x.clone().mean_dim(1).mul(rows).sum().backward();
+
+        // Each input position receives the output gradient divided by the reduced size.
+        let grad = x.grad(&grads_dim_0).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.5, 1.0, 1.5], [0.5, 1.0, 1.5]]), 4);
+        let grad = x.grad(&grads_dim_1).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]]), 4);
+    }
+}
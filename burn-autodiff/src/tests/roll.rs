@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_roll() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_data(
+            Data::from([[10.0, 20.0, 30.0], [40.0, 50.0, 60.0]]),
+            &device,
+        );
+
+        let tensor_2 = tensor_1.clone().roll(1, 1).mul(weights).sum();
+        let grads = tensor_2.backward();
+
+        // The gradient is the output gradient rolled by the negated shift
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[20.0, 30.0, 10.0], [50.0, 60.0, 40.0]])
+        );
+    }
+}
@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(ad_sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_sort() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[3.0, -1.0, 2.0], [0.0, 5.0, 4.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        // Each sorted position has a different weight, so the gradient identifies where each
+        // element ended up.
+        let tensor_2 = tensor_1.clone().sort(1);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[3.0, 1.0, 2.0], [4.0, 6.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_sort_dim_0() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().sort(0);
+        let tensor_4 = tensor_3.mul(tensor_2.clone());
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[3.0, 2.0], [1.0, 4.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[1.0, 1.0], [2.0, 3.0]]));
+    }
+}
@@ -26,4 +26,17 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[30.3093, 33.1204], [34.5819, 38.7694]]), 3);
     }
+
+    #[test]
+    fn should_diff_sqrt_elementwise() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats([0.25, 1.0, 4.0], &device).require_grad();
+
+        let grads = x.clone().sqrt().sum().backward();
+
+        // d/dx sqrt(x) = 0.5 / sqrt(x)
+        let grad = x.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([1.0, 0.5, 0.25]), 4);
+    }
 }
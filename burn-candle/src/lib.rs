@@ -59,11 +59,16 @@ mod tests {
     burn_tensor::testgen_cast!();
     burn_tensor::testgen_cat!();
     burn_tensor::testgen_recip!();
+    burn_tensor::testgen_floor!();
+    burn_tensor::testgen_ceil!();
+    burn_tensor::testgen_round!();
+    burn_tensor::testgen_remainder!();
     burn_tensor::testgen_clamp!();
     burn_tensor::testgen_cos!();
     // burn_tensor::testgen_div!();
     burn_tensor::testgen_erf!();
     burn_tensor::testgen_exp!();
+    burn_tensor::testgen_fftshift!();
     burn_tensor::testgen_flatten!();
     burn_tensor::testgen_full!();
     burn_tensor::testgen_gather_scatter!();
@@ -85,6 +90,7 @@ mod tests {
     burn_tensor::testgen_slice!();
     burn_tensor::testgen_sqrt!();
     burn_tensor::testgen_abs!();
+    burn_tensor::testgen_sign!();
     burn_tensor::testgen_squeeze!();
     burn_tensor::testgen_sub!();
     burn_tensor::testgen_tanh!();
@@ -137,6 +143,9 @@ mod tests {
     burn_autodiff::testgen_ad_neg!();
     burn_autodiff::testgen_ad_powf!();
     burn_autodiff::testgen_ad_recip!();
+    burn_autodiff::testgen_ad_floor!();
+    burn_autodiff::testgen_ad_ceil!();
+    burn_autodiff::testgen_ad_round!();
     burn_autodiff::testgen_ad_reshape!();
     burn_autodiff::testgen_ad_sin!();
     burn_autodiff::testgen_ad_softmax!();
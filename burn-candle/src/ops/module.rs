@@ -155,6 +155,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         assert!(
             padding[0] == 0 && padding[1] == 0,
@@ -164,6 +165,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
             count_include_pad,
             "Candle does not support excluding pad count in pooling"
         );
+        assert!(!ceil_mode, "Candle does not support ceil mode in pooling");
         CandleTensor::new(
             x.tensor
                 .avg_pool2d_with_stride((kernel_size[0], kernel_size[1]), (stride[0], stride[1]))
@@ -178,6 +180,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         panic!("avg_pool2d_backward is not supported by Candle")
     }
@@ -188,6 +191,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         assert!(
             padding[0] == 0 && padding[1] == 0,
@@ -197,6 +201,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
             dilation[0] == 1 && dilation[1] == 1,
             "Candle does not support dilation in pooling"
         );
+        assert!(!ceil_mode, "Candle does not support ceil mode in pooling");
         CandleTensor::new(
             x.tensor
                 .max_pool2d_with_stride((kernel_size[0], kernel_size[1]), (stride[0], stride[1]))
@@ -210,6 +215,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<Candle<F, I>> {
         panic!("max_pool2d_with_indices is not supported by Candle")
     }
@@ -220,6 +226,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: FloatTensor<Self, 4>,
         indices: IntTensor<Self, 4>,
     ) -> MaxPool2dBackward<Candle<F, I>> {
@@ -458,6 +458,32 @@ impl<F: FloatCandleElement, I: IntCandleElement> TensorOps<Self> for Candle<F, I
         CandleTensor::new(tensor.tensor.recip().unwrap())
     }
 
+    fn floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        CandleTensor::new(tensor.tensor.floor().unwrap())
+    }
+
+    fn ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        CandleTensor::new(tensor.tensor.ceil().unwrap())
+    }
+
+    fn round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // Candle rounds halfway cases away from zero, those are rounded to the nearest even
+        // integer instead.
+        let inner = |tensor: &Tensor| -> candle_core::Result<Tensor> {
+            let rounded = tensor.round()?;
+            let fract = (tensor - tensor.floor()?)?;
+            let is_half = fract.eq(&super::candle_utils::fill_like::<F, D>(
+                0.5f64.elem(),
+                tensor,
+            ))?;
+            let even = ((tensor / 2.0)?.round()? * 2.0)?;
+
+            is_half.where_cond(&even, &rounded)
+        };
+
+        CandleTensor::new(inner(&tensor.tensor).unwrap())
+    }
+
     fn narrow<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
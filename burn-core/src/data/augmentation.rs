@@ -0,0 +1,492 @@
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Bool, Data, Int, Shape, Tensor};
+use libm::{exp, log, round, sqrt};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use rand_distr::{Beta, Distribution};
+
+/// How the pixels uncovered by a [shift](random_shift) are filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShiftFill {
+    /// The uncovered pixels are set to zero.
+    Zeros,
+    /// The uncovered pixels mirror the image at its boundary, without repeating the edge pixels.
+    Reflect,
+}
+
+/// Randomly translate each image of the batch along its height and width.
+///
+/// Each image is shifted by an offset sampled uniformly in `[-max_shift, max_shift]` for both
+/// spatial dimensions, using a random number generator seeded with `seed`, so the same seed
+/// always produces the same shifts.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, height, width]`
+///
+/// # Panics
+///
+/// If `max_shift` isn't lower than the height and the width of the images.
+pub fn random_shift<B: Backend>(
+    x: Tensor<B, 4>,
+    max_shift: usize,
+    fill: ShiftFill,
+    seed: u64,
+) -> Tensor<B, 4> {
+    let [batch_size, channels, height, width] = x.dims();
+    assert!(
+        max_shift < height && max_shift < width,
+        "Random shift: the maximum shift {max_shift} must be lower than the image size {height}x{width}"
+    );
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let max_shift = max_shift as isize;
+
+    let images = (0..batch_size)
+        .map(|b| {
+            let shift_y = rng.gen_range(-max_shift..=max_shift);
+            let shift_x = rng.gen_range(-max_shift..=max_shift);
+            let image = x.clone().narrow(0, b, 1);
+
+            shift(image, [shift_y, shift_x], fill, channels)
+        })
+        .collect();
+
+    Tensor::cat(images, 0)
+}
+
+/// Shift a `[1, channels, height, width]` image by gathering the source rows and columns.
+fn shift<B: Backend>(
+    image: Tensor<B, 4>,
+    offsets: [isize; 2],
+    fill: ShiftFill,
+    channels: usize,
+) -> Tensor<B, 4> {
+    let [_, _, height, width] = image.dims();
+    let device = image.device();
+
+    // The source index of each output index, None when it falls outside of the image.
+    let sources = |size: usize, offset: isize| -> Vec<Option<usize>> {
+        (0..size as isize)
+            .map(|i| {
+                let source = i - offset;
+                match fill {
+                    _ if (0..size as isize).contains(&source) => Some(source as usize),
+                    ShiftFill::Zeros => None,
+                    ShiftFill::Reflect if source < 0 => Some(source.unsigned_abs()),
+                    ShiftFill::Reflect => Some(2 * (size - 1) - source as usize),
+                }
+            })
+            .collect()
+    };
+    let rows = sources(height, offsets[0]);
+    let cols = sources(width, offsets[1]);
+
+    let indices = |sources: &[Option<usize>]| {
+        let indices = sources
+            .iter()
+            .map(|source| source.unwrap_or(0) as i64)
+            .collect::<Vec<_>>();
+        Tensor::<B, 1, Int>::from_data(
+            Data::new(indices, Shape::new([sources.len()])).convert(),
+            &device,
+        )
+    };
+
+    let shifted = image.select(2, indices(&rows)).select(3, indices(&cols));
+
+    if fill == ShiftFill::Reflect {
+        return shifted;
+    }
+
+    let mut uncovered = Vec::with_capacity(channels * height * width);
+    for _ in 0..channels {
+        for row in rows.iter() {
+            for col in cols.iter() {
+                uncovered.push(row.is_none() || col.is_none());
+            }
+        }
+    }
+    let uncovered = Tensor::<B, 4, Bool>::from_data(
+        Data::new(uncovered, Shape::new([1, channels, height, width])),
+        &device,
+    );
+
+    shifted.mask_fill(uncovered, 0.0)
+}
+
+/// Randomly erase a rectangular region of each image of the batch with zeros.
+///
+/// Each image is erased with the given probability. The region covers a fraction of the image
+/// area sampled uniformly in `area_range`, with an aspect ratio sampled log-uniformly between
+/// `0.3` and `1 / 0.3`, as introduced in
+/// [Random Erasing Data Augmentation](https://arxiv.org/abs/1708.04896). The random number
+/// generator is seeded with `seed`, so the same seed always erases the same regions.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, height, width]`
+///
+/// # Panics
+///
+/// If the probability or the area range isn't between 0 and 1.
+pub fn random_erasing<B: Backend>(
+    x: Tensor<B, 4>,
+    probability: f64,
+    area_range: (f64, f64),
+    seed: u64,
+) -> Tensor<B, 4> {
+    let [batch_size, channels, height, width] = x.dims();
+    let (min_area, max_area) = area_range;
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "Random erasing: the probability {probability} must be between 0 and 1"
+    );
+    assert!(
+        0.0 <= min_area && min_area <= max_area && max_area <= 1.0,
+        "Random erasing: the area range {area_range:?} must be an ordered range between 0 and 1"
+    );
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut erased = Vec::with_capacity(batch_size * channels * height * width);
+
+    for _ in 0..batch_size {
+        let region = match rng.gen_bool(probability) {
+            true => erasing_region(&mut rng, [height, width], area_range),
+            false => None,
+        };
+
+        for _ in 0..channels {
+            for row in 0..height {
+                for col in 0..width {
+                    erased.push(
+                        region
+                            .as_ref()
+                            .is_some_and(|[rows, cols]| rows.contains(&row) && cols.contains(&col)),
+                    );
+                }
+            }
+        }
+    }
+
+    let erased = Tensor::<B, 4, Bool>::from_data(
+        Data::new(erased, Shape::new([batch_size, channels, height, width])),
+        &x.device(),
+    );
+
+    x.mask_fill(erased, 0.0)
+}
+
+/// Sample the rows and the columns of the region to erase, giving up after a few attempts when
+/// the sampled region doesn't fit in the image.
+fn erasing_region(
+    rng: &mut StdRng,
+    [height, width]: [usize; 2],
+    (min_area, max_area): (f64, f64),
+) -> Option<[core::ops::Range<usize>; 2]> {
+    const ATTEMPTS: usize = 10;
+    const MIN_ASPECT_RATIO: f64 = 0.3;
+
+    for _ in 0..ATTEMPTS {
+        let area = rng.gen_range(min_area..=max_area) * (height * width) as f64;
+        let aspect_ratio = exp(rng.gen_range(log(MIN_ASPECT_RATIO)..=-log(MIN_ASPECT_RATIO)));
+
+        let rows = round(sqrt(area * aspect_ratio)) as usize;
+        let cols = round(sqrt(area / aspect_ratio)) as usize;
+
+        if (1..=height).contains(&rows) && (1..=width).contains(&cols) {
+            let top = rng.gen_range(0..=height - rows);
+            let left = rng.gen_range(0..=width - cols);
+
+            return Some([top..top + rows, left..left + cols]);
+        }
+    }
+
+    None
+}
+
+/// Blend each example of the batch and its label with another random example of the batch, as
+/// introduced in [mixup: Beyond Empirical Risk Minimization](https://arxiv.org/abs/1710.09412).
+///
+/// The blending factor `lambda` is sampled from a `Beta(alpha, alpha)` distribution, and each
+/// example `i` becomes `lambda * x[i] + (1 - lambda) * x[j]` where `j` is a random permutation of
+/// the batch. The random number generator is seeded with `seed`.
+///
+/// # Shapes
+///
+/// - x: `[batch_size, ...]`
+/// - y: `[batch_size, num_classes]`, the one-hot or soft labels.
+///
+/// # Panics
+///
+/// If `alpha` isn't positive.
+pub fn mixup<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    y: Tensor<B, 2>,
+    alpha: f64,
+    seed: u64,
+) -> (Tensor<B, D>, Tensor<B, 2>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let lambda = sample_lambda(&mut rng, alpha);
+    let permutation = permutation::<B>(&mut rng, x.dims()[0], &x.device());
+
+    let x = mix(x, permutation.clone(), lambda);
+    let y = mix(y, permutation, lambda);
+
+    (x, y)
+}
+
+/// Paste a random rectangular patch of another random image of the batch into each image, and mix
+/// the labels by the area of the patch, as introduced in
+/// [CutMix](https://arxiv.org/abs/1905.04899).
+///
+/// The patch covers `1 - lambda` of the image area, with `lambda` sampled from a
+/// `Beta(alpha, alpha)` distribution, and is clipped at the image boundary. The labels are mixed
+/// with the area of the clipped patch. The random number generator is seeded with `seed`.
+///
+/// # Shapes
+///
+/// - x: `[batch_size, channels, height, width]`
+/// - y: `[batch_size, num_classes]`, the one-hot or soft labels.
+///
+/// # Panics
+///
+/// If `alpha` isn't positive.
+pub fn cutmix<B: Backend>(
+    x: Tensor<B, 4>,
+    y: Tensor<B, 2>,
+    alpha: f64,
+    seed: u64,
+) -> (Tensor<B, 4>, Tensor<B, 2>) {
+    let [batch_size, channels, height, width] = x.dims();
+    let device = x.device();
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let lambda = sample_lambda(&mut rng, alpha);
+    let permutation = permutation::<B>(&mut rng, batch_size, &device);
+
+    // The patch has the aspect ratio of the image, centered on a random pixel.
+    let ratio = sqrt(1.0 - lambda);
+    let patch_range = |size: usize, center: usize| {
+        let half = round(size as f64 * ratio) as usize / 2;
+        center.saturating_sub(half)..(center + half).min(size)
+    };
+    let rows = patch_range(height, rng.gen_range(0..height));
+    let cols = patch_range(width, rng.gen_range(0..width));
+
+    let mut patch = Vec::with_capacity(batch_size * channels * height * width);
+    for _ in 0..batch_size * channels {
+        for row in 0..height {
+            for col in 0..width {
+                patch.push(rows.contains(&row) && cols.contains(&col));
+            }
+        }
+    }
+    let patch = Tensor::<B, 4, Bool>::from_data(
+        Data::new(patch, Shape::new([batch_size, channels, height, width])),
+        &device,
+    );
+
+    let shuffled = x.clone().select(0, permutation.clone());
+    let x = x.mask_where(patch, shuffled);
+
+    // The labels are mixed with the area actually pasted.
+    let lambda = 1.0 - (rows.len() * cols.len()) as f64 / (height * width) as f64;
+    let y = mix(y, permutation, lambda);
+
+    (x, y)
+}
+
+/// Randomly shuffle the examples of the batch, and return the permutation that was applied.
+///
+/// Example `i` of the output is example `permutation[i]` of the input, so the labels can be
+/// shuffled consistently with `y.select(0, permutation)`, and the batch restored with
+/// `x.select(0, permutation.argsort(0))`. The random number generator is seeded with `seed`.
+///
+/// # Shapes
+///
+/// - x: `[batch_size, ...]`
+/// - permutation: `[batch_size]`
+pub fn shuffle_batch<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    seed: u64,
+) -> (Tensor<B, D>, Tensor<B, 1, Int>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let permutation = permutation::<B>(&mut rng, x.dims()[0], &x.device());
+
+    (x.select(0, permutation.clone()), permutation)
+}
+
+fn sample_lambda(rng: &mut StdRng, alpha: f64) -> f64 {
+    assert!(alpha > 0.0, "The alpha {alpha} must be positive");
+
+    Beta::new(alpha, alpha).unwrap().sample(rng)
+}
+
+/// A random permutation of the batch indices.
+fn permutation<B: Backend>(
+    rng: &mut StdRng,
+    batch_size: usize,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let mut indices: Vec<i64> = (0..batch_size as i64).collect();
+    indices.shuffle(rng);
+
+    Tensor::from_data(
+        Data::new(indices, Shape::new([batch_size])).convert(),
+        device,
+    )
+}
+
+/// `lambda * x + (1 - lambda) * x[permutation]`
+fn mix<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    permutation: Tensor<B, 1, Int>,
+    lambda: f64,
+) -> Tensor<B, D> {
+    let shuffled = x.clone().select(0, permutation);
+
+    x.mul_scalar(lambda) + shuffled.mul_scalar(1.0 - lambda)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    fn images() -> Tensor<TestBackend, 4> {
+        Tensor::<TestBackend, 1, Int>::arange(0..2 * 3 * 4 * 5, &Default::default())
+            .float()
+            .reshape([2, 3, 4, 5])
+    }
+
+    #[test]
+    fn test_random_shift_is_reproducible() {
+        for fill in [ShiftFill::Zeros, ShiftFill::Reflect] {
+            let output = random_shift(images(), 2, fill, 42);
+            let output_same_seed = random_shift(images(), 2, fill, 42);
+
+            assert_eq!(output.dims(), [2, 3, 4, 5]);
+            assert_eq!(output.into_data(), output_same_seed.into_data());
+        }
+    }
+
+    #[test]
+    fn test_random_shift_without_shift() {
+        let output = random_shift(images(), 0, ShiftFill::Zeros, 42);
+
+        assert_eq!(output.into_data(), images().into_data());
+    }
+
+    #[test]
+    fn test_random_erasing_is_reproducible() {
+        let x = Tensor::<TestBackend, 4>::ones([2, 3, 8, 8], &Default::default());
+
+        let output = random_erasing(x.clone(), 1.0, (0.25, 0.25), 42);
+        let output_same_seed = random_erasing(x, 1.0, (0.25, 0.25), 42);
+        let output = output.into_data();
+        assert_eq!(output, output_same_seed.into_data());
+
+        for image in output.value.chunks(3 * 8 * 8) {
+            let channels: Vec<_> = image.chunks(8 * 8).collect();
+            let erased: Vec<_> = (0..8 * 8).filter(|&i| channels[0][i] == 0.0).collect();
+
+            // The erased region is a rectangle covering about a quarter of the image, the same
+            // for all the channels.
+            let rows = erased.first().unwrap() / 8..erased.last().unwrap() / 8 + 1;
+            let cols = erased.iter().map(|i| i % 8).min().unwrap()
+                ..erased.iter().map(|i| i % 8).max().unwrap() + 1;
+            assert_eq!(erased.len(), rows.len() * cols.len());
+            assert!((12..=20).contains(&erased.len()));
+            assert!(channels.iter().all(|channel| channel == &channels[0]));
+        }
+    }
+
+    #[test]
+    fn test_random_erasing_without_probability() {
+        let output = random_erasing(images(), 0.0, (0.1, 0.5), 42);
+
+        assert_eq!(output.into_data(), images().into_data());
+    }
+
+    #[test]
+    fn test_mixup_with_lambda_one() {
+        // With this alpha and seed, lambda is sampled as exactly 1 and the examples are swapped
+        let x = images();
+        let y = Tensor::from_floats([[1.0, 0.0], [0.0, 1.0]], &Default::default());
+
+        let (output_x, output_y) = mixup(x.clone(), y.clone(), 0.01, 0);
+
+        assert_eq!(output_x.into_data(), x.into_data());
+        assert_eq!(output_y.into_data(), y.into_data());
+    }
+
+    #[test]
+    fn test_shuffle_batch_then_unshuffle() {
+        let x = Tensor::<TestBackend, 1, Int>::arange(0..8 * 3, &Default::default())
+            .float()
+            .reshape([8, 3]);
+
+        let (shuffled, permutation) = shuffle_batch(x.clone(), 42);
+
+        let mut indices: Vec<i64> = permutation.clone().into_data().convert().value;
+        indices.sort();
+        assert_eq!(indices, (0..8).collect::<Vec<_>>());
+        assert_eq!(
+            shuffled
+                .clone()
+                .select(1, Tensor::from_ints([0], &Default::default()))
+                .into_data(),
+            permutation
+                .clone()
+                .float()
+                .mul_scalar(3.0)
+                .unsqueeze_dim(1)
+                .into_data()
+        );
+
+        let unshuffled = shuffled.select(0, permutation.argsort(0));
+        assert_eq!(unshuffled.into_data(), x.into_data());
+    }
+
+    #[test]
+    fn test_mixup_and_cutmix_keep_labels_normalized() {
+        let device = Default::default();
+        let labels = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            &device,
+        );
+        let x = Tensor::<TestBackend, 4>::random([3, 2, 4, 4], Distribution::Default, &device);
+
+        let (x_mixup, y_mixup) = mixup(x.clone(), labels.clone(), 0.4, 42);
+        let (x_cutmix, y_cutmix) = cutmix(x, labels, 1.0, 42);
+
+        assert_eq!(x_mixup.dims(), [3, 2, 4, 4]);
+        assert_eq!(x_cutmix.dims(), [3, 2, 4, 4]);
+        for y in [y_mixup, y_cutmix] {
+            y.sum_dim(1)
+                .into_data()
+                .assert_approx_eq(&Data::from([[1.0], [1.0], [1.0]]), 5);
+        }
+    }
+
+    #[test]
+    fn test_shift_fill() {
+        let image = Tensor::<TestBackend, 4>::from_floats(
+            [[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]],
+            &Default::default(),
+        );
+
+        let zeros = shift(image.clone(), [1, -1], ShiftFill::Zeros, 1);
+        let reflect = shift(image, [0, 2], ShiftFill::Reflect, 1);
+
+        assert_eq!(
+            zeros.into_data(),
+            Data::from([[[[0.0, 0.0, 0.0], [2.0, 3.0, 0.0]]]])
+        );
+        assert_eq!(
+            reflect.into_data(),
+            Data::from([[[[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]]])
+        );
+    }
+}
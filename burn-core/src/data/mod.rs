@@ -1,3 +1,6 @@
+/// Augmentation module.
+pub mod augmentation;
+
 /// Dataloader module.
 #[cfg(feature = "dataset")]
 pub mod dataloader;
@@ -3,7 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
-use crate::nn::{Initializer, PaddingConfig1d};
+use crate::nn::{Initializer, PaddingConfig1d, PaddingMode};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::module::conv1d;
@@ -33,6 +33,9 @@ pub struct Conv1dConfig {
     /// The padding configuration.
     #[config(default = "PaddingConfig1d::Valid")]
     pub padding: PaddingConfig1d,
+    /// The values of the padding.
+    #[config(default = "PaddingMode::Zeros")]
+    pub padding_mode: PaddingMode,
     /// If bias should be added to the output.
     #[config(default = true)]
     pub bias: bool,
@@ -57,6 +60,7 @@ pub struct Conv1d<B: Backend> {
     dilation: usize,
     groups: usize,
     padding: PaddingConfig1d,
+    padding_mode: PaddingMode,
 }
 
 impl Conv1dConfig {
@@ -90,6 +94,7 @@ impl Conv1dConfig {
             stride: self.stride,
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
+            padding_mode: self.padding_mode.clone(),
             dilation: self.dilation,
             groups: self.groups,
         }
@@ -102,6 +107,7 @@ impl Conv1dConfig {
             stride: self.stride,
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
+            padding_mode: self.padding_mode.clone(),
             dilation: self.dilation,
             groups: self.groups,
         }
@@ -121,6 +127,12 @@ impl<B: Backend> Conv1d<B> {
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
+        // The zero padding is applied by the convolution, the other modes pad the input first
+        let (input, padding) = match self.padding_mode {
+            PaddingMode::Zeros => (input, padding),
+            _ => (self.padding_mode.pad(input, 2, padding), 0),
+        };
+
         conv1d(
             input,
             self.weight.val(),
@@ -160,4 +172,54 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    fn conv_with_padding_mode(padding_mode: PaddingMode) -> Conv1d<TestBackend> {
+        Conv1dConfig::new(1, 1, 3)
+            .with_padding(PaddingConfig1d::Explicit(1))
+            .with_padding_mode(padding_mode)
+            .with_bias(false)
+            .with_initializer(Initializer::Ones)
+            .init(&Default::default())
+    }
+
+    #[test]
+    fn padding_mode_circular_vs_zeros() {
+        let input =
+            Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0, 4.0]]], &Default::default());
+
+        let zeros = conv_with_padding_mode(PaddingMode::Zeros).forward(input.clone());
+        let circular = conv_with_padding_mode(PaddingMode::Circular).forward(input);
+
+        // Only the edges differ, where the circular padding wraps around the signal
+        zeros
+            .to_data()
+            .assert_approx_eq(&Data::from([[[3.0, 6.0, 9.0, 7.0]]]), 3);
+        circular
+            .to_data()
+            .assert_approx_eq(&Data::from([[[7.0, 6.0, 9.0, 8.0]]]), 3);
+    }
+
+    #[test]
+    fn padding_mode_reflect_and_replicate() {
+        let input =
+            Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0, 4.0]]], &Default::default());
+
+        let reflect = conv_with_padding_mode(PaddingMode::Reflect).forward(input.clone());
+        let replicate = conv_with_padding_mode(PaddingMode::Replicate).forward(input);
+
+        reflect
+            .to_data()
+            .assert_approx_eq(&Data::from([[[5.0, 6.0, 9.0, 10.0]]]), 3);
+        replicate
+            .to_data()
+            .assert_approx_eq(&Data::from([[[4.0, 6.0, 9.0, 11.0]]]), 3);
+    }
+
+    #[test]
+    #[should_panic = "Reflect padding"]
+    fn padding_mode_reflect_larger_than_input() {
+        let input = Tensor::<TestBackend, 3>::from_floats([[[1.0]]], &Default::default());
+
+        conv_with_padding_mode(PaddingMode::Reflect).forward(input);
+    }
 }
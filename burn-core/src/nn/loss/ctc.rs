@@ -0,0 +1,332 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Bool, Data, Int, Shape, Tensor};
+
+/// Log-probability of the impossible alignments.
+///
+/// A large finite value is used instead of negative infinity, so the stable log-sum-exp never
+/// computes `-inf - (-inf)` and the gradients of the unreachable states stay finite.
+const LOG_ZERO: f32 = -1e30;
+
+/// Compute the Connectionist Temporal Classification loss of each sample of the batch.
+///
+/// The loss is the negative log-likelihood of all the alignments of the input sequence that
+/// collapse to the target sequence, once the repeated symbols are merged and the blanks removed.
+/// It is computed with the CTC forward algorithm in log space.
+///
+/// # Arguments
+///
+/// * `log_probs` - The log-probabilities of each class at each timestep, e.g. the output of a
+///   [log_softmax](burn_tensor::activation::log_softmax) over the classes.
+/// * `targets` - The target sequences, padded to the same length. The padding can hold any value
+///   and is ignored.
+/// * `input_lengths` - The number of valid timesteps of each input sequence.
+/// * `target_lengths` - The number of valid symbols of each target sequence.
+/// * `blank` - The class of the blank symbol.
+///
+/// # Shapes
+///
+/// - log_probs: `[batch_size, seq_length, num_classes]`
+/// - targets: `[batch_size, max_target_length]`
+/// - input_lengths: `[batch_size]`
+/// - target_lengths: `[batch_size]`
+/// - output: `[batch_size]`
+///
+/// # Panics
+///
+/// - If an input length isn't between 1 and `seq_length`.
+/// - If a target length is greater than `max_target_length`.
+/// - If the blank or a target symbol isn't a valid class.
+///
+/// # Notes
+///
+/// The lengths and the targets are read back from the device to build the alignment states.
+/// A target that can't be aligned with its input, because it needs more timesteps than
+/// available, gets a very large loss instead of infinity.
+pub fn ctc_loss<B: Backend>(
+    log_probs: Tensor<B, 3>,
+    targets: Tensor<B, 2, Int>,
+    input_lengths: Tensor<B, 1, Int>,
+    target_lengths: Tensor<B, 1, Int>,
+    blank: usize,
+) -> Tensor<B, 1> {
+    let device = log_probs.device();
+    let [batch_size, seq_length, num_classes] = log_probs.dims();
+    let [targets_batch_size, max_target_length] = targets.dims();
+
+    assert_eq!(
+        targets_batch_size, batch_size,
+        "CTC loss: the targets batch size {targets_batch_size} doesn't match the batch size {batch_size}"
+    );
+    assert!(
+        blank < num_classes,
+        "CTC loss: the blank class {blank} must be lower than the number of classes {num_classes}"
+    );
+
+    let input_lengths = read_values(input_lengths, batch_size, "input length");
+    let target_lengths = read_values(target_lengths, batch_size, "target length");
+    let targets: Vec<i64> = targets.into_data().convert::<i64>().value;
+
+    for (i, (&input_length, &target_length)) in
+        input_lengths.iter().zip(target_lengths.iter()).enumerate()
+    {
+        assert!(
+            (1..=seq_length).contains(&input_length),
+            "CTC loss: the input length {input_length} of sample {i} must be between 1 and the sequence length {seq_length}"
+        );
+        assert!(
+            target_length <= max_target_length,
+            "CTC loss: the target length {target_length} of sample {i} exceeds the maximum target length {max_target_length}"
+        );
+
+        let start = i * max_target_length;
+        for &symbol in targets[start..start + target_length].iter() {
+            assert!(
+                (0..num_classes as i64).contains(&symbol) && symbol != blank as i64,
+                "CTC loss: the target symbol {symbol} of sample {i} must be a class other than the blank"
+            );
+        }
+    }
+
+    // The padding can hold any value, so it is replaced by blanks before gathering the classes.
+    let targets: Vec<usize> = targets
+        .into_iter()
+        .enumerate()
+        .map(|(j, symbol)| {
+            let (i, position) = (j / max_target_length, j % max_target_length);
+
+            if position < target_lengths[i] {
+                symbol as usize
+            } else {
+                blank
+            }
+        })
+        .collect();
+
+    // The alignment states are the targets with a blank before, between and after each symbol.
+    let num_states = 2 * max_target_length + 1;
+    let mut labels = Vec::with_capacity(batch_size * num_states);
+    let mut skips = Vec::with_capacity(batch_size * num_states);
+
+    for i in 0..batch_size {
+        let symbols = &targets[i * max_target_length..(i + 1) * max_target_length];
+
+        for s in 0..num_states {
+            let label = match s % 2 {
+                1 => symbols[s / 2],
+                _ => blank,
+            };
+            // A state can be reached from two states back when it skips a blank between two
+            // different symbols.
+            let skip = s % 2 == 1 && s >= 3 && symbols[s / 2] != symbols[s / 2 - 1];
+
+            labels.push(label as i64);
+            skips.push(skip);
+        }
+    }
+
+    let labels = Tensor::<B, 2, Int>::from_data(
+        Data::new(labels, Shape::new([batch_size, num_states])).convert(),
+        &device,
+    );
+    let no_skips = bool_tensor::<B>(skips, [batch_size, num_states], &device).bool_not();
+
+    // [batch_size, seq_length, num_states]
+    let log_probs = log_probs.gather(2, labels.unsqueeze_dim::<3>(1).repeat(1, seq_length));
+    let log_probs_at = |t: usize| log_probs.clone().narrow(1, t, 1).squeeze::<2>(1);
+
+    // Only the first blank and the first symbol can start an alignment.
+    let starts = (0..batch_size * num_states)
+        .map(|i| i % num_states >= 2)
+        .collect();
+    let mut alpha = log_probs_at(0).mask_fill(
+        bool_tensor::<B>(starts, [batch_size, num_states], &device),
+        LOG_ZERO,
+    );
+
+    for t in 1..seq_length {
+        let stay = alpha.clone();
+        let next = shift_states(alpha.clone(), 1);
+        let skip = shift_states(alpha.clone(), 2).mask_fill(no_skips.clone(), LOG_ZERO);
+
+        let updated = log_sum_exp(Tensor::stack::<3>(vec![stay, next, skip], 2)) + log_probs_at(t);
+
+        // The samples that are already complete keep their forward variables.
+        let active = (0..batch_size * num_states)
+            .map(|i| t < input_lengths[i / num_states])
+            .collect();
+        alpha = alpha.mask_where(
+            bool_tensor::<B>(active, [batch_size, num_states], &device),
+            updated,
+        );
+    }
+
+    // The valid alignments end on the last symbol or on the trailing blank.
+    let mut ends = Vec::with_capacity(batch_size * 2);
+    let mut no_symbol = Vec::with_capacity(batch_size * 2);
+    for &target_length in target_lengths.iter() {
+        ends.push((2 * target_length) as i64);
+        ends.push((2 * target_length).max(1) as i64 - 1);
+        no_symbol.push(false);
+        no_symbol.push(target_length == 0);
+    }
+
+    let ends = Tensor::<B, 2, Int>::from_data(
+        Data::new(ends, Shape::new([batch_size, 2])).convert(),
+        &device,
+    );
+    let alpha = alpha.gather(1, ends).mask_fill(
+        bool_tensor::<B>(no_symbol, [batch_size, 2], &device),
+        LOG_ZERO,
+    );
+
+    log_sum_exp(alpha).neg()
+}
+
+/// Read the integer values from the device, which must not be negative.
+fn read_values<B: Backend, const D: usize>(
+    tensor: Tensor<B, D, Int>,
+    expected: usize,
+    name: &str,
+) -> Vec<usize> {
+    let values: Vec<i64> = tensor.into_data().convert::<i64>().value;
+
+    assert_eq!(
+        values.len(),
+        expected,
+        "CTC loss: expected {expected} {name}s, got {}",
+        values.len()
+    );
+
+    values
+        .into_iter()
+        .map(|value| {
+            assert!(
+                value >= 0,
+                "CTC loss: a {name} can't be negative, got {value}"
+            );
+            value as usize
+        })
+        .collect()
+}
+
+fn bool_tensor<B: Backend>(
+    values: Vec<bool>,
+    shape: [usize; 2],
+    device: &B::Device,
+) -> Tensor<B, 2, Bool> {
+    Tensor::from_data(Data::new(values, Shape::new(shape)), device)
+}
+
+/// Shift the forward variables to the following states, the first states become impossible.
+fn shift_states<B: Backend>(alpha: Tensor<B, 2>, shift: usize) -> Tensor<B, 2> {
+    let [batch_size, num_states] = alpha.dims();
+    let device = alpha.device();
+
+    if shift >= num_states {
+        return Tensor::full([batch_size, num_states], LOG_ZERO, &device);
+    }
+
+    Tensor::cat(
+        vec![
+            Tensor::full([batch_size, shift], LOG_ZERO, &device),
+            alpha.narrow(1, 0, num_states - shift),
+        ],
+        1,
+    )
+}
+
+/// Stable log-sum-exp over the last dimension, which is the last value of the cumulative one.
+fn log_sum_exp<B: Backend, const D: usize, const D2: usize>(tensor: Tensor<B, D>) -> Tensor<B, D2> {
+    let size = tensor.dims()[D - 1];
+
+    tensor
+        .cumlogsumexp(D - 1)
+        .narrow(D - 1, size - 1, 1)
+        .squeeze(D - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn log_probs(probs: [[[f32; 2]; 2]; 2]) -> Tensor<TestBackend, 3> {
+        Tensor::from_floats(probs, &Default::default()).log()
+    }
+
+    #[test]
+    fn test_ctc_loss() {
+        let device = Default::default();
+        // classes: [blank, a]
+        let log_probs = log_probs([[[0.4, 0.6], [0.3, 0.7]], [[0.2, 0.8], [0.5, 0.5]]]);
+        let targets = Tensor::from_ints([[1], [1]], &device);
+        let input_lengths = Tensor::from_ints([2, 1], &device);
+        let target_lengths = Tensor::from_ints([1, 1], &device);
+
+        let loss = ctc_loss(log_probs, targets, input_lengths, target_lengths, 0);
+
+        // The first sample has 3 alignments for "a": (a, a), (a, -) and (-, a), so
+        // p = 0.6 * 0.7 + 0.6 * 0.3 + 0.4 * 0.7 = 0.88.
+        // The second sample only has one timestep, so p = 0.8.
+        loss.into_data()
+            .assert_approx_eq(&Data::from([-(0.88f32.ln()), -(0.8f32.ln())]), 5);
+    }
+
+    #[test]
+    fn test_ctc_loss_repeated_symbols_and_empty_target() {
+        let device = Default::default();
+        let log_probs = log_probs([[[0.4, 0.6], [0.3, 0.7]], [[0.2, 0.8], [0.5, 0.5]]]);
+        let targets = Tensor::from_ints([[1, 1], [1, 1]], &device);
+        let input_lengths = Tensor::from_ints([2, 2], &device);
+        let target_lengths = Tensor::from_ints([2, 0], &device);
+
+        let loss = ctc_loss(log_probs, targets, input_lengths, target_lengths, 0);
+        let loss = loss.into_data().value;
+
+        // "aa" needs a blank between the symbols, which doesn't fit in two timesteps.
+        assert!(loss[0] > 1e20);
+        // The empty target is only produced by blanks, so p = 0.2 * 0.5.
+        assert!((loss[1] + 0.1f32.ln()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_ctc_loss_negative_padding() {
+        let device = Default::default();
+        let log_probs = log_probs([[[0.4, 0.6], [0.3, 0.7]], [[0.2, 0.8], [0.5, 0.5]]]);
+        let targets = Tensor::from_ints([[1, -1], [1, -1]], &device);
+        let input_lengths = Tensor::from_ints([2, 1], &device);
+        let target_lengths = Tensor::from_ints([1, 1], &device);
+
+        let loss = ctc_loss(log_probs, targets, input_lengths, target_lengths, 0);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([-(0.88f32.ln()), -(0.8f32.ln())]), 5);
+    }
+
+    #[test]
+    fn test_ctc_loss_padding_out_of_classes() {
+        let device = Default::default();
+        let log_probs = log_probs([[[0.4, 0.6], [0.3, 0.7]], [[0.2, 0.8], [0.5, 0.5]]]);
+        let targets = Tensor::from_ints([[1, 2], [1, 100]], &device);
+        let input_lengths = Tensor::from_ints([2, 1], &device);
+        let target_lengths = Tensor::from_ints([1, 1], &device);
+
+        let loss = ctc_loss(log_probs, targets, input_lengths, target_lengths, 0);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([-(0.88f32.ln()), -(0.8f32.ln())]), 5);
+    }
+
+    #[test]
+    #[should_panic = "input length 3 of sample 0"]
+    fn test_ctc_loss_input_length_out_of_bounds() {
+        let device = Default::default();
+        let log_probs = log_probs([[[0.4, 0.6], [0.3, 0.7]], [[0.2, 0.8], [0.5, 0.5]]]);
+        let targets = Tensor::from_ints([[1], [1]], &device);
+        let input_lengths = Tensor::from_ints([3, 1], &device);
+        let target_lengths = Tensor::from_ints([1, 1], &device);
+
+        ctc_loss(log_probs, targets, input_lengths, target_lengths, 0);
+    }
+}
@@ -1,9 +1,13 @@
 mod binary_cross_entropy;
 mod cross_entropy;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod ctc;
 mod mse;
 mod reduction;
 
 pub use binary_cross_entropy::*;
 pub use cross_entropy::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use ctc::*;
 pub use mse::*;
 pub use reduction::*;
@@ -4,6 +4,8 @@ use burn_tensor::ops::conv::calculate_conv_padding;
 
 use crate::config::Config;
 use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Int, Shape, Tensor};
 
 /// Padding configuration for 1D operators.
 #[derive(Module, Config, Debug, PartialEq)]
@@ -67,3 +69,67 @@ impl PaddingConfig2d {
         }
     }
 }
+
+/// The values of the padding added by the convolutions, as the `padding_mode` of PyTorch.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum PaddingMode {
+    /// Pads with zeros.
+    Zeros,
+    /// Pads with the reflection of the input, without repeating the edge values.
+    Reflect,
+    /// Pads with the edge values of the input.
+    Replicate,
+    /// Pads with the values of the other side of the input, as if it was periodic.
+    Circular,
+}
+
+impl PaddingMode {
+    /// Pads both sides of the given dimension with `padding` elements.
+    ///
+    /// The non zero modes gather the padded tensor from the input, so the gradients flow back
+    /// to the copied values.
+    pub(crate) fn pad<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        dim: usize,
+        padding: usize,
+    ) -> Tensor<B, D> {
+        if padding == 0 {
+            return tensor;
+        }
+
+        let size = tensor.dims()[dim] as i64;
+        let index = |position: i64| match self {
+            Self::Zeros => unreachable!(),
+            Self::Reflect => {
+                let position = position.abs();
+                position.min(2 * (size - 1) - position)
+            }
+            Self::Replicate => position.clamp(0, size - 1),
+            Self::Circular => position.rem_euclid(size),
+        };
+
+        match self {
+            Self::Zeros => {
+                let mut paddings = [(0, 0); D];
+                paddings[dim] = (padding, padding);
+
+                return tensor.pad(paddings, 0.0);
+            }
+            Self::Reflect if padding as i64 >= size => panic!(
+                "Reflect padding ({padding}) must be smaller than the size of the dimension ({size})"
+            ),
+            _ => {}
+        }
+
+        let padding = padding as i64;
+        let indices = (-padding..size + padding).map(index).collect::<Vec<_>>();
+        let num_indices = indices.len();
+        let indices = Tensor::<B, 1, Int>::from_data(
+            Data::new(indices, Shape::new([num_indices])).convert(),
+            &tensor.device(),
+        );
+
+        tensor.select(dim, indices)
+    }
+}
@@ -8,7 +8,7 @@ use crate::tensor::Tensor;
 use burn_tensor::module::avg_pool1d;
 
 /// Configuration to create a [1D avg pooling](AvgPool1d) layer.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct AvgPool1dConfig {
     /// The size of the kernel.
     pub kernel_size: usize,
@@ -20,7 +20,10 @@ pub struct AvgPool1dConfig {
     pub padding: PaddingConfig1d,
     /// If the padding is counted in the denominator when computing the average.
     #[config(default = "true")]
-    count_include_pad: bool,
+    pub count_include_pad: bool,
+    /// If the output size is computed with a ceiling division instead of a floor division.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 1D avg pooling over input tensors.
@@ -29,21 +32,21 @@ pub struct AvgPool1dConfig {
 ///
 /// # Remarks
 ///
-/// The zero-padding values will be included in the calculation
+/// By default, the zero-padding values are included in the calculation
 /// of the average. This means that the zeros are counted as
 /// legitimate values, and they contribute to the denominator
 /// when calculating the average. This is equivalent to
-/// `torch.nn.AvgPool2d` with `count_include_pad=True`.
+/// `torch.nn.AvgPool1d` with `count_include_pad=True`.
 ///
-/// TODO: Add support for `count_include_pad=False`, see
-/// [Issue 636](https://github.com/tracel-ai/burn/issues/636)
-
+/// Set `count_include_pad` to `false` in the config to only divide by the number of
+/// non-padding elements of each window.
 #[derive(Module, Debug, Clone)]
 pub struct AvgPool1d {
     stride: usize,
     kernel_size: usize,
     padding: PaddingConfig1d,
     count_include_pad: bool,
+    ceil_mode: bool,
 }
 
 impl AvgPool1dConfig {
@@ -54,6 +57,7 @@ impl AvgPool1dConfig {
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
             count_include_pad: self.count_include_pad,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -77,6 +81,7 @@ impl AvgPool1d {
             self.stride,
             padding,
             self.count_include_pad,
+            self.ceil_mode,
         )
     }
 }
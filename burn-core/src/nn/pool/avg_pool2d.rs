@@ -21,6 +21,9 @@ pub struct AvgPool2dConfig {
     /// If the padding is counted in the denominator when computing the average.
     #[config(default = "true")]
     pub count_include_pad: bool,
+    /// If the output size is computed with a ceiling division instead of a floor division.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 2D avg pooling over input tensors.
@@ -29,20 +32,21 @@ pub struct AvgPool2dConfig {
 ///
 /// # Remarks
 ///
-/// The zero-padding values will be included in the calculation
+/// By default, the zero-padding values are included in the calculation
 /// of the average. This means that the zeros are counted as
 /// legitimate values, and they contribute to the denominator
 /// when calculating the average. This is equivalent to
 /// `torch.nn.AvgPool2d` with `count_include_pad=True`.
 ///
-/// TODO: Add support for `count_include_pad=False`, see
-/// [Issue 636](https://github.com/tracel-ai/burn/issues/636)
+/// Set `count_include_pad` to `false` in the config to only divide by the number of
+/// non-padding elements of each window.
 #[derive(Module, Debug, Clone)]
 pub struct AvgPool2d {
     stride: [usize; 2],
     kernel_size: [usize; 2],
     padding: PaddingConfig2d,
     count_include_pad: bool,
+    ceil_mode: bool,
 }
 
 impl AvgPool2dConfig {
@@ -53,6 +57,7 @@ impl AvgPool2dConfig {
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
             count_include_pad: self.count_include_pad,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -76,6 +81,7 @@ impl AvgPool2d {
             self.stride,
             padding,
             self.count_include_pad,
+            self.ceil_mode,
         )
     }
 }
@@ -21,6 +21,9 @@ pub struct MaxPool1dConfig {
     /// The dilation.
     #[config(default = "1")]
     pub dilation: usize,
+    /// If the output size is computed with a ceiling division instead of a floor division.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 1D max pooling over input tensors.
@@ -30,6 +33,7 @@ pub struct MaxPool1d {
     kernel_size: usize,
     padding: PaddingConfig1d,
     dilation: usize,
+    ceil_mode: bool,
 }
 
 impl MaxPool1dConfig {
@@ -40,6 +44,7 @@ impl MaxPool1dConfig {
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
             dilation: self.dilation,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -57,6 +62,13 @@ impl MaxPool1d {
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
-        max_pool1d(input, self.kernel_size, self.stride, padding, self.dilation)
+        max_pool1d(
+            input,
+            self.kernel_size,
+            self.stride,
+            padding,
+            self.dilation,
+            self.ceil_mode,
+        )
     }
 }
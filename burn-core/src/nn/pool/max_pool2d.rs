@@ -21,6 +21,9 @@ pub struct MaxPool2dConfig {
     /// The dilation.
     #[config(default = "[1, 1]")]
     pub dilation: [usize; 2],
+    /// If the output size is computed with a ceiling division instead of a floor division.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 2D max pooling over input tensors.
@@ -30,6 +33,7 @@ pub struct MaxPool2d {
     kernel_size: [usize; 2],
     padding: PaddingConfig2d,
     dilation: [usize; 2],
+    ceil_mode: bool,
 }
 
 impl MaxPool2dConfig {
@@ -40,6 +44,7 @@ impl MaxPool2dConfig {
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
             dilation: self.dilation,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -57,6 +62,13 @@ impl MaxPool2d {
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
 
-        max_pool2d(input, self.kernel_size, self.stride, padding, self.dilation)
+        max_pool2d(
+            input,
+            self.kernel_size,
+            self.stride,
+            padding,
+            self.dilation,
+            self.ceil_mode,
+        )
     }
 }
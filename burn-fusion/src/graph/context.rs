@@ -173,6 +173,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -183,6 +184,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -194,6 +196,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -205,6 +208,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -247,6 +251,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -257,6 +262,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                     out_indices: desc.out_indices.to_relative(converter),
                 })
@@ -271,6 +277,7 @@ impl ModuleOpsDescription {
                         stride: desc.stride,
                         padding: desc.padding,
                         dilation: desc.dilation,
+                        ceil_mode: desc.ceil_mode,
                         out: desc.out.to_relative(converter),
                     },
                 )
@@ -282,6 +289,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -292,6 +300,7 @@ impl ModuleOpsDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                     out_indices: desc.out_indices.to_relative(converter),
                 })
@@ -306,6 +315,7 @@ impl ModuleOpsDescription {
                         stride: desc.stride,
                         padding: desc.padding,
                         dilation: desc.dilation,
+                        ceil_mode: desc.ceil_mode,
                         out: desc.out.to_relative(converter),
                     },
                 )
@@ -377,6 +387,18 @@ impl FloatOpsDescription {
                 input: desc.input.to_relative(converter),
                 out: desc.out.to_relative(converter),
             }),
+            FloatOpsDescription::Floor(desc) => FloatOpsDescription::Floor(UnaryOpsDescription {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOpsDescription::Ceil(desc) => FloatOpsDescription::Ceil(UnaryOpsDescription {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOpsDescription::Round(desc) => FloatOpsDescription::Round(UnaryOpsDescription {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
         }
     }
 }
@@ -63,6 +63,12 @@ pub enum FloatOpsDescription {
     Random(RandomOpsDescription),
     /// Operation corresponding to [recip](burn_tensor::ops::TensorOps::recip).
     Recip(UnaryOpsDescription),
+    /// Operation corresponding to [floor](burn_tensor::ops::TensorOps::floor).
+    Floor(UnaryOpsDescription),
+    /// Operation corresponding to [ceil](burn_tensor::ops::TensorOps::ceil).
+    Ceil(UnaryOpsDescription),
+    /// Operation corresponding to [round](burn_tensor::ops::TensorOps::round).
+    Round(UnaryOpsDescription),
 }
 
 /// Operation description specific to module.
@@ -751,6 +757,7 @@ pub struct AvgPool1dDescription {
     pub stride: usize,
     pub padding: usize,
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -762,6 +769,7 @@ pub struct AvgPool2dDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -774,6 +782,7 @@ pub struct AvgPool1dBackwardDescription {
     pub stride: usize,
     pub padding: usize,
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -786,6 +795,7 @@ pub struct AvgPool2dBackwardDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -829,6 +839,7 @@ pub struct MaxPool1dDescription {
     pub stride: usize,
     pub padding: usize,
     pub dilation: usize,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -840,6 +851,7 @@ pub struct MaxPool1dWithIndicesDescription {
     pub stride: usize,
     pub padding: usize,
     pub dilation: usize,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
     pub out_indices: TensorDescription,
 }
@@ -854,6 +866,7 @@ pub struct MaxPool1dWithIndicesBackwardDescription {
     pub stride: usize,
     pub padding: usize,
     pub dilation: usize,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -865,6 +878,7 @@ pub struct MaxPool2dDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub dilation: [usize; 2],
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -876,6 +890,7 @@ pub struct MaxPool2dWithIndicesDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub dilation: [usize; 2],
+    pub ceil_mode: bool,
     pub out: TensorDescription,
     pub out_indices: TensorDescription,
 }
@@ -890,6 +905,7 @@ pub struct MaxPool2dWithIndicesBackwardDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub dilation: [usize; 2],
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -1070,6 +1086,9 @@ impl FloatOpsDescription {
             FloatOpsDescription::Log1p(desc) => vec![&desc.input, &desc.out],
             FloatOpsDescription::Erf(desc) => vec![&desc.input, &desc.out],
             FloatOpsDescription::Recip(desc) => vec![&desc.input, &desc.out],
+            FloatOpsDescription::Floor(desc) => vec![&desc.input, &desc.out],
+            FloatOpsDescription::Ceil(desc) => vec![&desc.input, &desc.out],
+            FloatOpsDescription::Round(desc) => vec![&desc.input, &desc.out],
             FloatOpsDescription::Powf(desc) => vec![&desc.lhs, &desc.out],
             FloatOpsDescription::Sqrt(desc) => vec![&desc.input, &desc.out],
             FloatOpsDescription::Cos(desc) => vec![&desc.input, &desc.out],
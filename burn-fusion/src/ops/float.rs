@@ -1329,6 +1329,54 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_float_ops!(Floor, B::floor);
+
+        let out = tensor.client.tensor_uninitialized(tensor.shape.clone());
+        let desc = UnaryOpsDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::FloatOps(FloatOpsDescription::Floor(desc.clone())),
+            Floor::<D>::new(desc),
+        );
+
+        out
+    }
+
+    fn ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_float_ops!(Ceil, B::ceil);
+
+        let out = tensor.client.tensor_uninitialized(tensor.shape.clone());
+        let desc = UnaryOpsDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::FloatOps(FloatOpsDescription::Ceil(desc.clone())),
+            Ceil::<D>::new(desc),
+        );
+
+        out
+    }
+
+    fn round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_float_ops!(Round, B::round);
+
+        let out = tensor.client.tensor_uninitialized(tensor.shape.clone());
+        let desc = UnaryOpsDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::FloatOps(FloatOpsDescription::Round(desc.clone())),
+            Round::<D>::new(desc),
+        );
+
+        out
+    }
+
     fn erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         unary_float_ops!(TanhOps, B::erf);
 
@@ -263,6 +263,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 3> {
         make_ops!(
             AvgPool1dOps,
@@ -275,13 +276,15 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output);
             }
         );
 
-        let size = calculate_pool_output_size(kernel_size, stride, padding, 1, x.shape[2]);
+        let size =
+            calculate_pool_output_size(kernel_size, stride, padding, 1, x.shape[2], ceil_mode);
         let shape = vec![x.shape[0], x.shape[1], size];
         let out = x.client.tensor_uninitialized(shape);
 
@@ -291,6 +294,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -309,6 +313,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         make_ops!(
             AvgPool2dOps,
@@ -321,16 +326,29 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output);
             }
         );
 
-        let size_0 =
-            calculate_pool_output_size(kernel_size[0], stride[0], padding[0], 1, x.shape[2]);
-        let size_1 =
-            calculate_pool_output_size(kernel_size[1], stride[1], padding[1], 1, x.shape[3]);
+        let size_0 = calculate_pool_output_size(
+            kernel_size[0],
+            stride[0],
+            padding[0],
+            1,
+            x.shape[2],
+            ceil_mode,
+        );
+        let size_1 = calculate_pool_output_size(
+            kernel_size[1],
+            stride[1],
+            padding[1],
+            1,
+            x.shape[3],
+            ceil_mode,
+        );
 
         let shape = vec![x.shape[0], x.shape[1], size_0, size_1];
         let out = x.client.tensor_uninitialized(shape);
@@ -341,6 +359,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -360,6 +379,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 3> {
         make_ops!(
             AvgPool1dBackwardOps,
@@ -374,6 +394,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output);
@@ -389,6 +410,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -408,6 +430,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         make_ops!(
             AvgPool2dBackwardOps,
@@ -422,6 +445,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output);
@@ -437,6 +461,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -455,6 +480,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 3> {
         make_ops!(
             MaxPool1dOps,
@@ -467,13 +493,21 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output);
             }
         );
 
-        let size = calculate_pool_output_size(kernel_size, stride, padding, dilation, x.shape[2]);
+        let size = calculate_pool_output_size(
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            x.shape[2],
+            ceil_mode,
+        );
 
         let shape = vec![x.shape[0], x.shape[1], size];
         let out = x.client.tensor_uninitialized(shape);
@@ -484,6 +518,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -502,6 +537,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         make_ops!(
             MaxPool2dOps,
@@ -514,6 +550,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output);
@@ -526,6 +563,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             padding[0],
             dilation[0],
             x.shape[2],
+            ceil_mode,
         );
         let size_1 = calculate_pool_output_size(
             kernel_size[1],
@@ -533,6 +571,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             padding[1],
             dilation[1],
             x.shape[3],
+            ceil_mode,
         );
 
         let shape = vec![x.shape[0], x.shape[1], size_0, size_1];
@@ -544,6 +583,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -562,6 +602,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<Self> {
         make_ops!(
             MaxPool1dWithIndicesOps,
@@ -574,6 +615,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output.output);
@@ -581,7 +623,14 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             }
         );
 
-        let size = calculate_pool_output_size(kernel_size, stride, padding, dilation, x.shape[2]);
+        let size = calculate_pool_output_size(
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            x.shape[2],
+            ceil_mode,
+        );
         let shape = vec![x.shape[0], x.shape[1], size];
         let out = x.client.tensor_uninitialized(shape.clone());
         let out_indices = x.client.tensor_uninitialized(shape);
@@ -592,6 +641,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
             out_indices: out_indices.to_description_out(),
         };
@@ -611,6 +661,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<Self> {
         make_ops!(
             MaxPool2dWithIndicesOps,
@@ -623,6 +674,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor(&args.out.id, output.output);
@@ -636,6 +688,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             padding[0],
             dilation[0],
             x.shape[2],
+            ceil_mode,
         );
         let size_1 = calculate_pool_output_size(
             kernel_size[1],
@@ -643,6 +696,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             padding[1],
             dilation[1],
             x.shape[3],
+            ceil_mode,
         );
 
         let shape = vec![x.shape[0], x.shape[1], size_0, size_1];
@@ -655,6 +709,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
             out_indices: out_indices.to_description_out(),
         };
@@ -674,6 +729,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
         output_grad: FloatTensor<Self, 3>,
         indices: IntTensor<Self, 3>,
     ) -> MaxPool1dBackward<Self> {
@@ -690,6 +746,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                     grad,
                     indices,
                 );
@@ -708,6 +765,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -726,6 +784,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: FloatTensor<Self, 4>,
         indices: IntTensor<Self, 4>,
     ) -> MaxPool2dBackward<Self> {
@@ -742,6 +801,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                     grad,
                     indices,
                 );
@@ -760,6 +820,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
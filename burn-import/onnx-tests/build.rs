@@ -7,15 +7,24 @@ fn main() {
     // Add onnx models.
     ModelGen::new()
         .input("tests/add/add_int.onnx")
+        .input("tests/add/add_int_scalar.onnx")
         .input("tests/add/add.onnx")
+        .input("tests/and/and.onnx")
+        .input("tests/avg_pool1d/avg_pool1d.onnx")
         .input("tests/avg_pool2d/avg_pool2d.onnx")
         .input("tests/batch_norm/batch_norm.onnx")
+        .input("tests/cast/cast_constant.onnx")
+        .input("tests/cast/cast.onnx")
+        .input("tests/ceil/ceil.onnx")
         .input("tests/clip/clip_opset16.onnx")
         .input("tests/clip/clip_opset7.onnx")
         .input("tests/concat/concat.onnx")
+        .input("tests/constant_of_shape/constant_of_shape.onnx")
         .input("tests/conv1d/conv1d.onnx")
         .input("tests/conv2d/conv2d.onnx")
+        .input("tests/conv2d_asym_pad/conv2d_asym_pad.onnx")
         .input("tests/cos/cos.onnx")
+        .input("tests/dead_nodes/dead_nodes.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout_opset16.onnx")
         .input("tests/dropout/dropout_opset7.onnx")
@@ -23,29 +32,61 @@ fn main() {
         .input("tests/erf/erf.onnx")
         .input("tests/exp/exp.onnx")
         .input("tests/flatten/flatten.onnx")
+        .input("tests/floor/floor.onnx")
         .input("tests/gather/gather.onnx")
+        .input("tests/gather_elements/gather_elements.onnx")
+        .input("tests/gather_nd/gather_nd.onnx")
         .input("tests/gelu/gelu.onnx")
+        .input("tests/gemm/gemm.onnx")
         .input("tests/global_avr_pool/global_avr_pool.onnx")
+        .input("tests/global_max_pool/global_max_pool.onnx")
+        .input("tests/glu/glu.onnx")
+        .input("tests/identity/identity.onnx")
+        .input("tests/leaky_relu/leaky_relu.onnx")
         .input("tests/linear/linear.onnx")
+        .input("tests/linear_fp16/linear_fp16.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
         .input("tests/log/log.onnx")
+        .input("tests/lrn/lrn.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
         .input("tests/mul/mul.onnx")
+        .input("tests/mul/mul_constants.onnx")
         .input("tests/neg/neg.onnx")
+        .input("tests/not/not.onnx")
+        .input("tests/or/or.onnx")
+        .input("tests/pad/pad.onnx")
         .input("tests/recip/recip.onnx")
+        .input("tests/reduce_mean/reduce_mean.onnx")
+        .input("tests/reduce_sum/reduce_sum.onnx")
         .input("tests/relu/relu.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/resize/resize.onnx")
+        .input("tests/round/round.onnx")
+        .input("tests/scatter_nd/scatter_nd.onnx")
+        .input("tests/shape/shape.onnx")
         .input("tests/sigmoid/sigmoid.onnx")
         .input("tests/softmax/softmax.onnx")
+        .input("tests/split/split.onnx")
         .input("tests/sqrt/sqrt.onnx")
+        .input("tests/squeeze/squeeze.onnx")
+        .input("tests/squeeze/squeeze_opset11.onnx")
         .input("tests/sub/sub_int.onnx")
         .input("tests/sub/sub.onnx")
         .input("tests/tanh/tanh.onnx")
         .input("tests/transpose/transpose.onnx")
+        .input("tests/unsqueeze/unsqueeze.onnx")
+        .input("tests/xor/xor.onnx")
         .input("tests/conv_transpose2d/conv_transpose2d.onnx")
         .out_dir("model/")
         .run_from_script();
 
+    // Generate a model without its dropout layers.
+    ModelGen::new()
+        .input("tests/identity/identity.onnx")
+        .out_dir("model/skip_dropout/")
+        .skip_dropout(true)
+        .run_from_script();
+
     // The following tests are used to generate the model with different record types.
     // (e.g. bincode, pretty_json, etc.) Do not need to add new tests here, just use the default
     // record type to the ModelGen::new() call above.
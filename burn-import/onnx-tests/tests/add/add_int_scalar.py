@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/add/add_int_scalar.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # A zero-dim integer tensor constant, as exported by PyTorch for `x + 5`.
+    constant = helper.make_node(
+        "Constant",
+        [],
+        ["constant"],
+        name="/Constant",
+        value=helper.make_tensor("value", TensorProto.INT64, [], [5]),
+    )
+    add = helper.make_node("Add", ["input", "constant"], ["output"], name="/Add")
+
+    graph = helper.make_graph(
+        [constant, add],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.INT64, [1, 4])],
+        [helper.make_tensor_value_info("output", TensorProto.INT64, [1, 4])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "add_int_scalar.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[1, 2, 3, 4]]
+    # output = [[6, 7, 8, 9]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/avg_pool1d/avg_pool1d.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # torch.onnx.export lowers nn.AvgPool1d with ceil_mode into a padded 2D pooling, so the
+    # AveragePool nodes are built directly with the ONNX helper functions.
+    pool1 = helper.make_node(
+        "AveragePool",
+        ["input1"],
+        ["output1"],
+        name="/AveragePool",
+        kernel_shape=[3],
+        strides=[1],
+    )
+    pool2 = helper.make_node(
+        "AveragePool",
+        ["input2"],
+        ["output2"],
+        name="/AveragePool_1",
+        kernel_shape=[3],
+        strides=[2],
+        pads=[1, 1],
+        count_include_pad=1,
+    )
+    pool3 = helper.make_node(
+        "AveragePool",
+        ["input3"],
+        ["output3"],
+        name="/AveragePool_2",
+        kernel_shape=[2],
+        strides=[2],
+        ceil_mode=1,
+    )
+
+    graph = helper.make_graph(
+        [pool1, pool2, pool3],
+        "main_graph",
+        [
+            helper.make_tensor_value_info("input1", TensorProto.FLOAT, [1, 2, 5]),
+            helper.make_tensor_value_info("input2", TensorProto.FLOAT, [1, 2, 5]),
+            helper.make_tensor_value_info("input3", TensorProto.FLOAT, [1, 2, 5]),
+        ],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [1, 2, 3]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [1, 2, 3]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, [1, 2, 3]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "avg_pool1d.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = arange(10).reshape(1, 2, 5)
+    # output1 = [[[1.0, 2.0, 3.0], [6.0, 7.0, 8.0]]]
+    # output2 = [[[0.3333, 2.0, 2.3333], [3.6667, 7.0, 5.6667]]]
+    # output3 = [[[0.5, 2.5, 4.0], [5.5, 7.5, 9.0]]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/cast/cast.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    to_int = helper.make_node(
+        "Cast", ["input"], ["output1"], name="/Cast", to=TensorProto.INT64
+    )
+    to_bool = helper.make_node(
+        "Cast", ["input"], ["output2"], name="/Cast_1", to=TensorProto.BOOL
+    )
+
+    graph = helper.make_graph(
+        [to_int, to_bool],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("output2", TensorProto.BOOL, [2, 3]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "cast.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[1.7, -2.3, 0.0], [0.4, 3.0, -0.9]]
+    # output1 = [[1, -2, 0], [0, 3, 0]]
+    # output2 = [[True, True, False], [True, True, True]]
+
+
+if __name__ == "__main__":
+    main()
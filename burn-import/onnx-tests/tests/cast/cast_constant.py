@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/cast/cast_constant.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Int constants casted to float, which are folded into float constants by the importer
+    bias = helper.make_node(
+        "Constant",
+        [],
+        ["bias"],
+        name="/Constant",
+        value=helper.make_tensor("value", TensorProto.INT64, [1, 3], [1, 2, 3]),
+    )
+    bias_cast = helper.make_node(
+        "Cast", ["bias"], ["bias_float"], name="/Cast", to=TensorProto.FLOAT
+    )
+    scale = helper.make_node(
+        "Constant",
+        [],
+        ["scale"],
+        name="/Constant_1",
+        value=helper.make_tensor("value", TensorProto.INT64, [], [2]),
+    )
+    scale_cast = helper.make_node(
+        "Cast", ["scale"], ["scale_float"], name="/Cast_1", to=TensorProto.FLOAT
+    )
+    add = helper.make_node("Add", ["input", "bias_float"], ["sum"], name="/Add")
+    mul = helper.make_node("Mul", ["sum", "scale_float"], ["output"], name="/Mul")
+
+    graph = helper.make_graph(
+        [bias, bias_cast, scale, scale_cast, add, mul],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "cast_constant.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]
+    # output = (input + [[1.0, 2.0, 3.0]]) * 2.0 = [[2.0, 6.0, 10.0], [8.0, 12.0, 16.0]]
+
+
+if __name__ == "__main__":
+    main()
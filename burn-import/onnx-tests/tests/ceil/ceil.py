@@ -0,0 +1,33 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/ceil/ceil.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    node = helper.make_node("Ceil", ["input"], ["output"], name="/Ceil")
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 1, 5])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 1, 1, 5])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "ceil.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[[[-1.5, -0.5, 0.5, 1.5, 2.5]]]]
+    # output = [[[[-1.0, -0.0, 1.0, 2.0, 3.0]]]]
+
+
+if __name__ == "__main__":
+    main()
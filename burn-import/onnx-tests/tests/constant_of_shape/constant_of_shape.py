@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/constant_of_shape/constant_of_shape.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The shape of the first constant is produced by a Constant node
+    shape = helper.make_node(
+        "Constant",
+        [],
+        ["shape"],
+        name="/Constant",
+        value=helper.make_tensor("value", TensorProto.INT64, [2], [2, 3]),
+    )
+    filled = helper.make_node(
+        "ConstantOfShape",
+        ["shape"],
+        ["filled"],
+        name="/ConstantOfShape",
+        value=helper.make_tensor("value", TensorProto.FLOAT, [1], [1.5]),
+    )
+    add = helper.make_node("Add", ["input", "filled"], ["output1"], name="/Add")
+
+    # Without a value attribute, the tensor is filled with float zeros
+    zeros = helper.make_node(
+        "ConstantOfShape", ["zeros_shape"], ["output2"], name="/ConstantOfShape_1"
+    )
+
+    ints = helper.make_node(
+        "ConstantOfShape",
+        ["ints_shape"],
+        ["output3"],
+        name="/ConstantOfShape_2",
+        value=helper.make_tensor("value", TensorProto.INT64, [1], [7]),
+    )
+
+    graph = helper.make_graph(
+        [shape, filled, add, zeros, ints],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info("output3", TensorProto.INT64, [2]),
+        ],
+        initializer=[
+            helper.make_tensor("zeros_shape", TensorProto.INT64, [1], [3]),
+            helper.make_tensor("ints_shape", TensorProto.INT64, [1], [2]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "constant_of_shape.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = arange(6).reshape(2, 3)
+    # output1 = input + 1.5
+    # output2 = [0.0, 0.0, 0.0]
+    # output3 = [7, 7]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,69 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/conv2d_asym_pad/conv2d_asym_pad.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+# The pads are [top, left, bottom, right]
+PADS = [1, 0, 0, 2]
+
+
+def conv2d_reference(image, kernel, pads):
+    """Plain python convolution of a single channel image, used to compute the test data."""
+    top, left, bottom, right = pads
+    width = len(image[0]) + left + right
+    padded = (
+        [[0.0] * width for _ in range(top)]
+        + [[0.0] * left + row + [0.0] * right for row in image]
+        + [[0.0] * width for _ in range(bottom)]
+    )
+    kh, kw = len(kernel), len(kernel[0])
+    return [
+        [
+            sum(padded[i + m][j + n] * kernel[m][n] for m in range(kh) for n in range(kw))
+            for j in range(width - kw + 1)
+        ]
+        for i in range(len(padded) - kh + 1)
+    ]
+
+
+def main():
+    kernel = [[1.0, 2.0], [3.0, 4.0]]
+
+    conv = helper.make_node(
+        "Conv",
+        ["input", "weight"],
+        ["output"],
+        name="/Conv",
+        kernel_shape=[2, 2],
+        pads=PADS,
+    )
+
+    graph = helper.make_graph(
+        [conv],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 3, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 1, 3, 4])],
+        initializer=[
+            helper.make_tensor(
+                "weight", TensorProto.FLOAT, [1, 1, 2, 2], sum(kernel, [])
+            )
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "conv2d_asym_pad.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    image = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]
+    print("Test input data: {}".format(image))
+    print("Test output data: {}".format(conv2d_reference(image, kernel, PADS)))
+
+
+if __name__ == "__main__":
+    main()
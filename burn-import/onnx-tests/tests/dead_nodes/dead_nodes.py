@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/dead_nodes/dead_nodes.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    relu = helper.make_node("Relu", ["input"], ["output"], name="/Relu")
+
+    # Shape computation whose result is never used, which must be removed by the importer
+    shape = helper.make_node("Shape", ["input"], ["shape"], name="/Shape")
+    index = helper.make_node(
+        "Constant",
+        [],
+        ["index"],
+        name="/Constant",
+        value=helper.make_tensor("value", TensorProto.INT64, [], [0]),
+    )
+    gather = helper.make_node(
+        "Gather", ["shape", "index"], ["batch_size"], name="/Gather", axis=0
+    )
+
+    graph = helper.make_graph(
+        [relu, shape, index, gather],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "dead_nodes.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[-1.0, 0.0, 1.0], [2.0, -3.0, 4.0]]
+    # output = [[0.0, 0.0, 1.0], [2.0, 0.0, 4.0]]
+
+
+if __name__ == "__main__":
+    main()
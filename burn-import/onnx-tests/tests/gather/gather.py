@@ -2,46 +2,54 @@
 
 # used to generate model: onnx-tests/tests/gather/gather.onnx
 
-import torch
-import torch.nn as nn
-
-
-class Model(nn.Module):
-    def __init__(self):
-        super(Model, self).__init__()
-
-    def forward(self, x, index):
-        x = torch.gather(x, 1, index)
-        return x
+import onnx
+from onnx import helper, TensorProto
 
 
 def main():
-    # Set random seed for reproducibility
-    torch.manual_seed(0)
+    # Gather with 1D, 2D and scalar indices. The ONNX helper functions are used so the scalar
+    # index is a graph input instead of being folded into a constant by torch.onnx.export.
+    gather1 = helper.make_node(
+        "Gather", ["input", "index1"], ["output1"], name="/Gather", axis=0
+    )
+    gather2 = helper.make_node(
+        "Gather", ["input", "index2"], ["output2"], name="/Gather_1", axis=-1
+    )
+    gather3 = helper.make_node(
+        "Gather", ["input", "index3"], ["output3"], name="/Gather_2", axis=1
+    )
+
+    graph = helper.make_graph(
+        [gather1, gather2, gather3],
+        "main_graph",
+        [
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [3, 4]),
+            helper.make_tensor_value_info("index1", TensorProto.INT64, [2]),
+            helper.make_tensor_value_info("index2", TensorProto.INT64, [2, 2]),
+            helper.make_tensor_value_info("index3", TensorProto.INT64, []),
+        ],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 4]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [3, 2, 2]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, [3]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
 
-    # Export to onnx
-    model = Model()
-    model.eval()
-    device = torch.device("cpu")
     onnx_name = "gather.onnx"
-    dummy_input = torch.randn(2, 2, device=device)
-    dummy_index = torch.randint(high=2, size=(2, 2), device=device, dtype=torch.int64)
-
-    torch.onnx.export(model, (dummy_input, dummy_index), onnx_name,
-                      verbose=False, opset_version=16)
+    onnx.save(model, onnx_name)
 
     print("Finished exporting model to {}".format(onnx_name))
 
-    # Output some test data for use in the test
-    test_input = torch.tensor([[1.0, 2.0],
-                               [3.0, 4.0]])
-    test_index = torch.tensor([[0, 0],
-                               [1, 0]])
-
-    print("Test input data: {}, {}".format(test_input, test_index))
-    output = model.forward(test_input, test_index)
-    print("Test output data: {}".format(output))
+    # Test data used in the test:
+    # input = arange(12).reshape(3, 4)
+    # index1 = [2, 0], index2 = [[3, 0], [1, 1]], index3 = 1
+    # output1 = input[[2, 0], :]
+    # output2 = input[:, [[3, 0], [1, 1]]]
+    # output3 = input[:, 1]
 
 
-if __name__ == '__main__':
+if __name__ == "__main__":
     main()
@@ -0,0 +1,61 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gather_nd/gather_nd.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # GatherND is not produced by torch.onnx.export for simple models, so the graph is built
+    # directly with the ONNX helper functions.
+    nodes = [
+        # Each index is a full coordinate into the data tensor
+        helper.make_node("GatherND", ["data", "indices"], ["output"], name="/GatherND"),
+        # The first dimension is a batch dimension shared by the data and the indices
+        helper.make_node(
+            "GatherND",
+            ["data", "batch_indices"],
+            ["batch_output"],
+            name="/GatherND_1",
+            batch_dims=1,
+        ),
+        # A single coordinate gathers a single element
+        helper.make_node(
+            "GatherND", ["data", "coordinate"], ["coordinate_output"], name="/GatherND_2"
+        ),
+    ]
+
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [
+            helper.make_tensor_value_info("data", TensorProto.FLOAT, [2, 3, 2]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("batch_indices", TensorProto.INT64, [2, 2]),
+            helper.make_tensor_value_info("coordinate", TensorProto.INT64, [3]),
+        ],
+        [
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [2]),
+            helper.make_tensor_value_info("batch_output", TensorProto.FLOAT, [2]),
+            helper.make_tensor_value_info("coordinate_output", TensorProto.FLOAT, []),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "gather_nd.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # data = [[[0, 1], [2, 3], [4, 5]], [[6, 7], [8, 9], [10, 11]]]
+    # indices = [[1, 2, 0], [0, 1, 1]] -> output = [10, 3]
+    # batch_indices = [[2, 1], [0, 0]] -> batch_output = [5, 6]
+    # coordinate = [1, 0, 1] -> coordinate_output = 7
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,58 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gemm/gemm.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The Gemm nodes are built directly with the ONNX helper functions to cover both weight
+    # layouts: the first one uses transB=1 with a bias (as exported by PyTorch for nn.Linear),
+    # the second one relies on the default attributes and has no bias.
+    weight1 = helper.make_tensor(
+        "weight1", TensorProto.FLOAT, [2, 3], [[0.1, 0.2, 0.3], [-0.5, 0.5, 1.0]]
+    )
+    bias1 = helper.make_tensor("bias1", TensorProto.FLOAT, [2], [1.0, -1.0])
+    weight2 = helper.make_tensor(
+        "weight2", TensorProto.FLOAT, [3, 2], [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]
+    )
+
+    gemm1 = helper.make_node(
+        "Gemm",
+        ["input", "weight1", "bias1"],
+        ["output1"],
+        name="/Gemm1",
+        alpha=1.0,
+        beta=1.0,
+        transB=1,
+    )
+    gemm2 = helper.make_node("Gemm", ["input", "weight2"], ["output2"], name="/Gemm2")
+
+    graph = helper.make_graph(
+        [gemm1, gemm2],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [2, 2]),
+        ],
+        initializer=[weight1, bias1, weight2],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "gemm.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[1, 2, 3], [4, 5, 6]]
+    # output1 = input @ weight1.T + bias1 = [[2.4, 2.5], [4.2, 5.5]]
+    # output2 = input @ weight2 = [[4, 5], [10, 11]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/global_max_pool/global_max_pool.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    nodes = [
+        helper.make_node("GlobalMaxPool", ["x_1d"], ["y_1d"], name="/GlobalMaxPool"),
+        helper.make_node("GlobalMaxPool", ["x_2d"], ["y_2d"], name="/GlobalMaxPool_1"),
+    ]
+
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [
+            helper.make_tensor_value_info("x_1d", TensorProto.FLOAT, [1, 2, 4]),
+            helper.make_tensor_value_info("x_2d", TensorProto.FLOAT, [1, 2, 2, 2]),
+        ],
+        [
+            helper.make_tensor_value_info("y_1d", TensorProto.FLOAT, [1, 2, 1]),
+            helper.make_tensor_value_info("y_2d", TensorProto.FLOAT, [1, 2, 1, 1]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "global_max_pool.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # x_1d = [[[1.0, -2.0, 4.0, 1.0], [0.0, 6.0, -3.0, 1.0]]]
+    # y_1d = [[[4.0], [6.0]]]
+    # x_2d = [[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 5.0], [0.0, 8.0]]]]
+    # y_2d = [[[[4.0]], [[8.0]]]]
+
+
+if __name__ == "__main__":
+    main()
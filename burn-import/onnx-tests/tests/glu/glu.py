@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/glu/glu.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The graph mirrors the Split -> Sigmoid -> Mul sequence exported for gated linear units.
+    # The first sequence is fused into a single glu call. The second one is kept as separate
+    # nodes because its first half is also returned by the model.
+    split_sizes = helper.make_tensor("split_sizes", TensorProto.INT64, [2], [2, 2])
+
+    nodes = [
+        helper.make_node(
+            "Split", ["input", "split_sizes"], ["a1", "b1"], name="/Split1", axis=-1
+        ),
+        helper.make_node("Sigmoid", ["b1"], ["gate1"], name="/Sigmoid1"),
+        helper.make_node("Mul", ["gate1", "a1"], ["output1"], name="/Mul1"),
+        helper.make_node("Split", ["input"], ["a2", "b2"], name="/Split2", axis=1),
+        helper.make_node("Sigmoid", ["b2"], ["gate2"], name="/Sigmoid2"),
+        helper.make_node("Mul", ["a2", "gate2"], ["output2"], name="/Mul2"),
+    ]
+
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 4])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("a2", TensorProto.FLOAT, [2, 2]),
+        ],
+        initializer=[split_sizes],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "glu.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[1, -2, 0.5, 3], [-1, 2, 0, -0.5]]
+    # output1 = output2 = [[0.6225, -1.9051], [-0.5, 0.7551]]
+    # a2 = [[1, -2], [-1, 2]]
+
+
+if __name__ == "__main__":
+    main()
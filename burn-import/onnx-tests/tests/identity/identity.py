@@ -0,0 +1,43 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/identity/identity.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The Identity nodes are removed by the importer, as well as the Dropout node when the
+    # dropout layers are skipped
+    ratio = helper.make_tensor("ratio", TensorProto.FLOAT, [], [0.25])
+    nodes = [
+        helper.make_node("Identity", ["input"], ["identity"], name="/Identity"),
+        helper.make_node("Add", ["identity", "identity"], ["sum"], name="/Add"),
+        helper.make_node("Dropout", ["sum", "ratio"], ["dropout"], name="/Dropout"),
+        helper.make_node("Relu", ["dropout"], ["relu"], name="/Relu"),
+        helper.make_node("Identity", ["relu"], ["output"], name="/Identity_1"),
+    ]
+
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+        initializer=[ratio],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "identity.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[-1.0, 0.0, 1.0], [2.0, -3.0, 4.0]]
+    # output = relu(input + input) = [[0.0, 0.0, 2.0], [4.0, 0.0, 8.0]]
+
+
+if __name__ == "__main__":
+    main()
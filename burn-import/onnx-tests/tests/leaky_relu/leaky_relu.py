@@ -0,0 +1,39 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/leaky_relu/leaky_relu.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    node = helper.make_node(
+        "LeakyRelu",
+        ["input"],
+        ["output"],
+        name="/LeakyRelu",
+        alpha=0.1,
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "leaky_relu.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[-2.0, -0.5, 0.0], [0.5, 1.0, 3.0]]
+    # output = [[-0.2, -0.05, 0.0], [0.5, 1.0, 3.0]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/linear_fp16/linear_fp16.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # A linear layer exported in half precision, the Gemm is converted to a Linear node.
+    weight = helper.make_tensor(
+        "weight", TensorProto.FLOAT16, [2, 3], [0.1, 0.2, -0.3, 0.4, 0.5, -0.6]
+    )
+    bias = helper.make_tensor("bias", TensorProto.FLOAT16, [2], [0.01, -0.02])
+    node = helper.make_node(
+        "Gemm",
+        ["input", "weight", "bias"],
+        ["output"],
+        name="/Gemm",
+        transB=1,
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT16, [3, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT16, [3, 2])],
+        initializer=[weight, bias],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "linear_fp16.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = eye(3)
+    # output = weight.T + bias = [[0.11, 0.38], [0.21, 0.48], [-0.29, -0.62]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/lrn/lrn.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # torch.onnx.export decomposes nn.LocalResponseNorm into primitive operators, so the LRN node
+    # is built directly with the ONNX helper functions.
+    node = helper.make_node(
+        "LRN",
+        ["input"],
+        ["output"],
+        name="/LRN",
+        size=3,
+        alpha=0.5,
+        beta=0.75,
+        bias=2.0,
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 4, 2, 2])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 4, 2, 2])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "lrn.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = arange(16).reshape(1, 4, 2, 2) / 4 - 2
+    # output = input / (2 + 0.5 / 3 * sum of the squares over 3 neighboring channels) ^ 0.75
+
+
+if __name__ == "__main__":
+    main()
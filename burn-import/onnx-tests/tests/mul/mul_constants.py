@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/mul/mul_constants.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Product of a constant and an initializer, which is folded into a single constant
+    scale = helper.make_node(
+        "Constant",
+        [],
+        ["scale"],
+        name="/Constant",
+        value=helper.make_tensor("value", TensorProto.FLOAT, [1, 3], [1.5, 2.0, 0.5]),
+    )
+    factor = helper.make_tensor("factor", TensorProto.FLOAT, [2, 1], [2.0, 4.0])
+    mul = helper.make_node("Mul", ["scale", "factor"], ["product"], name="/Mul")
+    add = helper.make_node("Add", ["input", "product"], ["output"], name="/Add")
+
+    graph = helper.make_graph(
+        [scale, mul, add],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+        initializer=[factor],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "mul_constants.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]
+    # output = input + [[3.0, 4.0, 1.0], [6.0, 8.0, 2.0]] = [[3.0, 5.0, 3.0], [9.0, 12.0, 7.0]]
+
+
+if __name__ == "__main__":
+    main()
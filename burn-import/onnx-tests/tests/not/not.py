@@ -0,0 +1,33 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/not/not.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    node = helper.make_node("Not", ["input"], ["output"], name="/Not")
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.BOOL, [1, 1, 1, 4])],
+        [helper.make_tensor_value_info("output", TensorProto.BOOL, [1, 1, 1, 4])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "not.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[[[True, False, True, False]]]]
+    # output = [[[[False, True, False, True]]]]
+
+
+if __name__ == "__main__":
+    main()
@@ -14,15 +14,24 @@ macro_rules! include_models {
 // ATTENTION: Modify this macro to include all models in the `model` directory.
 include_models!(
     add_int,
+    add_int_scalar,
     add,
+    and,
+    avg_pool1d,
     avg_pool2d,
     batch_norm,
+    cast,
+    cast_constant,
+    ceil,
     clip_opset16,
     clip_opset7,
     concat,
+    constant_of_shape,
     conv1d,
     conv2d,
+    conv2d_asym_pad,
     cos,
+    dead_nodes,
     div,
     dropout_opset16,
     dropout_opset7,
@@ -30,35 +39,65 @@ include_models!(
     erf,
     exp,
     flatten,
+    floor,
     gather,
+    gather_elements,
+    gather_nd,
     gelu,
+    gemm,
     global_avr_pool,
+    global_max_pool,
+    glu,
+    identity,
+    leaky_relu,
     linear,
+    linear_fp16,
     log_softmax,
     log,
+    lrn,
     maxpool2d,
     mul,
+    mul_constants,
     neg,
+    not,
+    or,
+    pad,
     recip,
+    reduce_mean,
+    reduce_sum,
     relu,
     reshape,
+    resize,
+    round,
+    scatter_nd,
+    shape,
     sigmoid,
     softmax,
+    split,
     sqrt,
+    squeeze,
+    squeeze_opset11,
     sub_int,
     sub,
     tanh,
     transpose,
+    unsqueeze,
+    xor,
     conv_transpose2d
 );
 
+// The same model as `identity`, generated without its dropout layers.
+pub mod identity_skip_dropout {
+    include!(concat!(env!("OUT_DIR"), "/model/skip_dropout/identity.rs"));
+}
+
 #[cfg(test)]
 mod tests {
     use core::f64::consts;
 
     use super::*;
 
-    use burn::tensor::{Data, Int, Shape, Tensor};
+    use burn::tensor::{Bool, Data, Int, Shape, Tensor};
 
     use float_cmp::ApproxEq;
 
@@ -94,6 +133,19 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn add_int_scalar_constant_to_int_tensor() {
+        let device = Default::default();
+        let model: add_int_scalar::Model<Backend> = add_int_scalar::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 2, Int>::from_ints([[1, 2, 3, 4]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[6, 7, 8, 9]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
     #[test]
     fn sub_scalar_from_tensor_and_tensor_from_tensor() {
         // Initialize the model with weights (loaded from the exported file)
@@ -138,6 +190,31 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn mul_constants() {
+        // Initialize the model with the folded constant (loaded from the exported file)
+        let model: mul_constants::Model<Backend> = mul_constants::Model::default();
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            &Default::default(),
+        );
+
+        let output = model.forward(input);
+        let expected = Data::from([[3.0, 5.0, 3.0], [9.0, 12.0, 7.0]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn mul_constants_is_folded() {
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/mul_constants.rs"));
+
+        // The product of the constants is computed at import time into a single constant
+        assert!(code.contains("constant1: burn::module::Param<Tensor<B, 2>>"));
+        assert!(!code.contains(".mul("));
+    }
+
     #[test]
     fn div_tensor_by_scalar_and_tensor_by_tensor() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -213,6 +290,27 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn conv2d_asym_pad() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: conv2d_asym_pad::Model<Backend> = conv2d_asym_pad::Model::default();
+
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]]],
+            &Default::default(),
+        );
+
+        let output = model.forward(input);
+
+        // The pads [top, left, bottom, right] = [1, 0, 0, 2] are applied before the convolution
+        let expected = Data::from([[[
+            [4.0, 11.0, 6.0, 0.0],
+            [27.0, 37.0, 17.0, 0.0],
+            [57.0, 67.0, 29.0, 0.0],
+        ]]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
     #[test]
     fn dropout_opset16() {
         let model: dropout_opset16::Model<Backend> = dropout_opset16::Model::default();
@@ -232,6 +330,47 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn identity() {
+        let device = Default::default();
+        let model: identity::Model<Backend> = identity::Model::default();
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-1.0, 0.0, 1.0], [2.0, -3.0, 4.0]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[0.0, 0.0, 2.0], [4.0, 0.0, 8.0]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn identity_is_removed() {
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/identity.rs"));
+
+        // The identity nodes are removed and their consumers use the identity inputs
+        assert!(!code.contains("identity1"));
+        assert!(code.contains("let add1_out1 = input1.clone().add(input1);"));
+        assert!(code.contains("dropout1: Dropout"));
+    }
+
+    #[test]
+    fn identity_skip_dropout() {
+        let device = Default::default();
+        let model: identity_skip_dropout::Model<Backend> = identity_skip_dropout::Model::default();
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-1.0, 0.0, 1.0], [2.0, -3.0, 4.0]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[0.0, 0.0, 2.0], [4.0, 0.0, 8.0]]);
+
+        assert_eq!(output.to_data(), expected);
+
+        // The dropout layer isn't generated
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/skip_dropout/identity.rs"));
+        assert!(!code.contains("dropout1"));
+        assert!(code.contains("let relu1_out1 = burn::tensor::activation::relu(add1_out1);"));
+    }
+
     #[test]
     fn dropout_opset7() {
         let model: dropout_opset7::Model<Backend> = dropout_opset7::Model::default();
@@ -266,8 +405,62 @@ mod tests {
 
     #[test]
     fn gather() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: gather::Model<Backend> = gather::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([3, 4]);
+        let index1 = Tensor::<Backend, 1, Int>::from_ints([2, 0], &device);
+        let index2 = Tensor::<Backend, 2, Int>::from_ints([[3, 0], [1, 1]], &device);
+        let index3 = 1;
+        let (output1, output2, output3) = model.forward(input, index1, index2, index3);
+
+        let expected1 = Data::from([[8., 9., 10., 11.], [0., 1., 2., 3.]]);
+        let expected2 = Data::from([
+            [[3., 0.], [1., 1.]],
+            [[7., 4.], [5., 5.]],
+            [[11., 8.], [9., 9.]],
+        ]);
+        let expected3 = Data::from([1., 5., 9.]);
+
+        assert_eq!(output1.to_data(), expected1);
+        assert_eq!(output2.to_data(), expected2);
+        assert_eq!(output3.to_data(), expected3);
+    }
+
+    #[test]
+    fn gather_negative_indices() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: gather::Model<Backend> = gather::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([3, 4]);
+        let index1 = Tensor::<Backend, 1, Int>::from_ints([-1, 0], &device);
+        let index2 = Tensor::<Backend, 2, Int>::from_ints([[-1, 0], [1, -3]], &device);
+        let (output1, output2, output3) = model.forward(input, index1, index2, -1);
+
+        let expected1 = Data::from([[8., 9., 10., 11.], [0., 1., 2., 3.]]);
+        let expected2 = Data::from([
+            [[3., 0.], [1., 1.]],
+            [[7., 4.], [5., 5.]],
+            [[11., 8.], [9., 9.]],
+        ]);
+
+        assert_eq!(output1.to_data(), expected1);
+        assert_eq!(output2.to_data(), expected2);
+        assert_eq!(output3.to_data(), Data::from([3., 7., 11.]));
+    }
+
+    #[test]
+    fn gather_elements() {
         // Initialize the model with weights (loaded from the exported file)
-        let model: gather::Model<Backend> = gather::Model::default();
+        let model: gather_elements::Model<Backend> = gather_elements::Model::default();
 
         let device = Default::default();
         // Run the model
@@ -279,6 +472,27 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn gather_nd() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: gather_nd::Model<Backend> = gather_nd::Model::default();
+
+        let device = Default::default();
+        // Run the model
+        let data = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([2, 3, 2]);
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[1, 2, 0], [0, 1, 1]], &device);
+        let batch_indices = Tensor::<Backend, 2, Int>::from_ints([[2, 1], [0, 0]], &device);
+        let coordinate = Tensor::<Backend, 1, Int>::from_ints([1, 0, 1], &device);
+        let (output, batch_output, coordinate_output) =
+            model.forward(data, indices, batch_indices, coordinate);
+
+        assert_eq!(output.to_data(), Data::from([10., 3.]));
+        assert_eq!(batch_output.to_data(), Data::from([5., 6.]));
+        assert_eq!(coordinate_output.to_data(), Data::from([7.]));
+    }
+
     #[test]
     fn globalavrpool_1d_2d() {
         // The model contains 1d and 2d global average pooling nodes
@@ -306,6 +520,28 @@ mod tests {
         assert!(expected_sum_2d.approx_eq(output_sum_2d, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn global_max_pool_1d_2d() {
+        let model: global_max_pool::Model<Backend> = global_max_pool::Model::default();
+
+        let device = Default::default();
+        let input_1d = Tensor::<Backend, 3>::from_floats(
+            [[[1.0, -2.0, 4.0, 1.0], [0.0, 6.0, -3.0, 1.0]]],
+            &device,
+        );
+        let input_2d = Tensor::<Backend, 4>::from_floats(
+            [[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 5.0], [0.0, 8.0]]]],
+            &device,
+        );
+
+        let (output_1d, output_2d) = model.forward(input_1d, input_2d);
+
+        let expected_1d = Data::from([[[4.0], [6.0]]]);
+        let expected_2d = Data::from([[[[4.0]], [[8.0]]]]);
+        assert_eq!(output_1d.to_data(), expected_1d);
+        assert_eq!(output_2d.to_data(), expected_2d);
+    }
+
     #[test]
     fn softmax() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -352,6 +588,268 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn squeeze() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: squeeze::Model<Backend> = squeeze::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([3, 1, 4, 1]);
+        let output = model.forward(input);
+        let expected = Data::from([
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+        ]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn squeeze_opset11() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: squeeze_opset11::Model<Backend> = squeeze_opset11::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([3, 1, 4, 1]);
+        let output = model.forward(input);
+        let expected = Data::from([
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+        ]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn squeeze_opset11_and_opset16_generate_the_same_forward() {
+        // The axes are an attribute in opset 11 and an input in opset 16
+        let forward = |code: &'static str| &code[code.find("pub fn forward").unwrap()..];
+        let opset11 = include_str!(concat!(env!("OUT_DIR"), "/model/squeeze_opset11.rs"));
+        let opset16 = include_str!(concat!(env!("OUT_DIR"), "/model/squeeze.rs"));
+
+        assert_eq!(forward(opset11), forward(opset16));
+    }
+
+    #[test]
+    fn reduce_mean() {
+        let device = Default::default();
+        let model: reduce_mean::Model<Backend> = reduce_mean::Model::new(&device);
+
+        // Run the model
+        let input =
+            Tensor::<Backend, 4>::from_floats([[[[1.0, 4.0, 7.0], [2.0, 5.0, 12.0]]]], &device);
+        let (output_keepdims, output_squeezed) = model.forward(input);
+        let expected_keepdims = Data::from([[[[4.0], [6.33333]]]]);
+        let expected_squeezed = Data::from([[4.0, 6.33333]]);
+
+        output_keepdims
+            .to_data()
+            .assert_approx_eq(&expected_keepdims, 3);
+        output_squeezed
+            .to_data()
+            .assert_approx_eq(&expected_squeezed, 3);
+    }
+
+    #[test]
+    fn reduce_sum() {
+        let device = Default::default();
+        let model: reduce_sum::Model<Backend> = reduce_sum::Model::new(&device);
+
+        // Run the model
+        let input =
+            Tensor::<Backend, 4>::from_floats([[[[1.0, 4.0, 7.0], [2.0, 5.0, 12.0]]]], &device);
+        let (output_keepdims, output_squeezed, output_full) = model.forward(input);
+
+        assert_eq!(output_keepdims.to_data(), Data::from([[[[12.0], [19.0]]]]));
+        assert_eq!(output_squeezed.to_data(), Data::from([[12.0, 19.0]]));
+        assert_eq!(output_full.to_data(), Data::from([31.0]));
+    }
+
+    #[test]
+    fn pad() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: pad::Model<Backend> = pad::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([
+            [1.5, 1.5, 1.5, 1.5, 1.5],
+            [0.0, 1.0, 2.0, 1.5, 1.5],
+            [3.0, 4.0, 5.0, 1.5, 1.5],
+        ]);
+
+        assert_eq!(output.dims(), [3, 5]);
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn resize() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: resize::Model<Backend> = resize::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+        let (output1, output2) = model.forward(input);
+        let expected1 = Data::from([[[
+            [1.0, 1.3333, 1.6667, 2.0],
+            [1.6667, 2.0, 2.3333, 2.6667],
+            [2.3333, 2.6667, 3.0, 3.3333],
+            [3.0, 3.3333, 3.6667, 4.0],
+        ]]]);
+        let expected2 = Data::from([[[
+            [1.0, 1.25, 1.75, 2.0],
+            [1.5, 1.75, 2.25, 2.5],
+            [2.5, 2.75, 3.25, 3.5],
+            [3.0, 3.25, 3.75, 4.0],
+        ]]]);
+
+        output1.to_data().assert_approx_eq(&expected1, 3);
+        output2.to_data().assert_approx_eq(&expected2, 3);
+    }
+
+    #[test]
+    fn linear_fp16() {
+        let device = Default::default();
+        // Initialize the model with the half precision weights (loaded from the exported file)
+        let model: linear_fp16::Model<Backend> = linear_fp16::Model::default();
+
+        // The identity input makes the output the transposed weights plus the bias
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = Data::from([[0.11, 0.38], [0.21, 0.48], [-0.29, -0.62]]);
+
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn cast_constant() {
+        // Initialize the model with the folded constants (loaded from the exported file)
+        let model: cast_constant::Model<Backend> = cast_constant::Model::default();
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            &Default::default(),
+        );
+
+        let output = model.forward(input);
+        let expected = Data::from([[2.0, 6.0, 10.0], [8.0, 12.0, 16.0]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn cast_constant_is_folded() {
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/cast_constant.rs"));
+
+        // The casts are computed at import time into a single float tensor and a float scalar
+        assert!(code.contains("constant1: burn::module::Param<Tensor<B, 2>>"));
+        assert!(code.contains("let constant2_out1: f32 = 2f32;"));
+        assert!(!code.contains(".float()"));
+        assert!(!code.contains("Int"));
+    }
+
+    #[test]
+    fn dead_nodes() {
+        let device = Default::default();
+        let model: dead_nodes::Model<Backend> = dead_nodes::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-1.0, 0.0, 1.0], [2.0, -3.0, 4.0]], &device);
+
+        let output = model.forward(input);
+        let expected = Data::from([[0.0, 0.0, 1.0], [2.0, 0.0, 4.0]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn dead_nodes_are_removed() {
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/dead_nodes.rs"));
+
+        // The unused Shape -> Gather branch is not generated
+        assert!(code.contains("let relu1_out1 = burn::tensor::activation::relu(input1);"));
+        assert!(!code.contains("gather"));
+        assert!(!code.contains("shape"));
+    }
+
+    #[test]
+    fn cast() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: cast::Model<Backend> = cast::Model::new(&device);
+
+        // Run the model
+        let input =
+            Tensor::<Backend, 2>::from_floats([[1.7, -2.3, 0.0], [0.4, 3.0, -0.9]], &device);
+        // The output types check that the casts change the tensor kinds
+        let (output1, output2): (Tensor<Backend, 2, Int>, Tensor<Backend, 2, Bool>) =
+            model.forward(input);
+
+        assert_eq!(output1.to_data(), Data::from([[1, -2, 0], [0, 3, 0]]));
+        assert_eq!(
+            output2.to_data(),
+            Data::from([[true, true, false], [true, true, true]])
+        );
+    }
+
+    #[test]
+    fn unsqueeze() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: unsqueeze::Model<Backend> = unsqueeze::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 2>::from_floats(
+            [
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+            ],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = Data::from([[
+            [[0.0], [1.0], [2.0], [3.0]],
+            [[4.0], [5.0], [6.0], [7.0]],
+            [[8.0], [9.0], [10.0], [11.0]],
+        ]]);
+
+        assert_eq!(output.shape(), Shape::from([1, 3, 4, 1]));
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn split() {
+        let device = Default::default();
+        let model: split::Model<Backend> = split::Model::new(&device);
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..14, &device)
+            .float()
+            .reshape([2, 7]);
+        let (output1, output2, output3) = model.forward(input);
+
+        assert_eq!(output1.to_data(), Data::from([[0., 1., 2.], [7., 8., 9.]]));
+        assert_eq!(
+            output2.to_data(),
+            Data::from([[3., 4., 5.], [10., 11., 12.]])
+        );
+        assert_eq!(output3.to_data(), Data::from([[6.], [13.]]));
+    }
+
     #[test]
     fn sqrt() {
         let device = Default::default();
@@ -395,6 +893,27 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn avg_pool1d() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: avg_pool1d::Model<Backend> = avg_pool1d::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [[[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]]],
+            &device,
+        );
+        let (output1, output2, output3) = model.forward(input.clone(), input.clone(), input);
+        let expected1 = Data::from([[[1.0, 2.0, 3.0], [6.0, 7.0, 8.0]]]);
+        let expected2 = Data::from([[[0.3333, 2.0, 2.3333], [3.6667, 7.0, 5.6667]]]);
+        let expected3 = Data::from([[[0.5, 2.5, 4.0], [5.5, 7.5, 9.0]]]);
+
+        output1.to_data().assert_approx_eq(&expected1, 3);
+        output2.to_data().assert_approx_eq(&expected2, 3);
+        output3.to_data().assert_approx_eq(&expected3, 3);
+    }
+
     #[test]
     fn avg_pool2d() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -438,6 +957,26 @@ mod tests {
         output3.to_data().assert_approx_eq(&expected3, 3);
     }
 
+    #[test]
+    fn constant_of_shape() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: constant_of_shape::Model<Backend> = constant_of_shape::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..6, &device)
+            .float()
+            .reshape([2, 3]);
+        let (output1, output2, output3) = model.forward(input);
+
+        assert_eq!(
+            output1.to_data(),
+            Data::from([[1.5, 2.5, 3.5], [4.5, 5.5, 6.5]])
+        );
+        assert_eq!(output2.to_data(), Data::from([0.0, 0.0, 0.0]));
+        assert_eq!(output3.to_data(), Data::from([7, 7]));
+    }
+
     #[test]
     fn reshape() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -452,6 +991,81 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn reshape_resolves_the_inferred_size() {
+        // The input shape is known, so the `-1` of `reshape(1, -1)` is resolved at codegen time
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/reshape.rs"));
+
+        assert!(code.contains(".reshape([1, 4])"));
+        assert!(!code.contains(".reshape([1, -1])"));
+    }
+
+    #[test]
+    fn shape() {
+        let device = Default::default();
+        let model: shape::Model<Backend> = shape::Model::new(&device);
+
+        // Run the model
+        let input1 = Tensor::<Backend, 2>::ones([2, 3], &device);
+        let input2 = Tensor::<Backend, 1>::from_floats([0., 1., 2., 3., 4., 5.], &device);
+        let output = model.forward(input1, input2);
+        let expected = Data::from([[1., 2., 3.], [4., 5., 6.]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn shape_is_resolved_at_import() {
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/shape.rs"));
+
+        // The shape of `input1` is baked into the reshape instead of computed at runtime
+        assert!(code.contains(".reshape([2, 3])"));
+        assert!(!code.contains("let shape1"));
+    }
+
+    #[test]
+    fn lrn() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: lrn::Model<Backend> = lrn::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[
+                [[-2.0, -1.75], [-1.5, -1.25]],
+                [[-1.0, -0.75], [-0.5, -0.25]],
+                [[0.0, 0.25], [0.5, 0.75]],
+                [[1.0, 1.25], [1.5, 1.75]],
+            ]],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = Data::from([[
+            [[-0.915813, -0.853663], [-0.773889, -0.675727]],
+            [[-0.457906, -0.364762], [-0.254677, -0.131106]],
+            [[0.0, 0.131106], [0.254677, 0.364762]],
+            [[0.559959, 0.675727], [0.773889, 0.853663]],
+        ]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn scatter_nd() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: scatter_nd::Model<Backend> = scatter_nd::Model::default();
+
+        let device = Default::default();
+        // Run the model
+        let data = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[0, 1], [1, 2]], &device);
+        let updates = Tensor::<Backend, 1>::from_floats([10., 20.], &device);
+        let output = model.forward(data, indices, updates);
+        let expected = Data::from([[1., 10., 3.], [4., 5., 20.]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
     #[test]
     fn flatten() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -482,6 +1096,21 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-8, 2)));
     }
 
+    #[test]
+    fn leaky_relu() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: leaky_relu::Model<Backend> = leaky_relu::Model::new(&device);
+
+        // Run the model
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-2.0, -0.5, 0.0], [0.5, 1.0, 3.0]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[-0.2, -0.05, 0.0], [0.5, 1.0, 3.0]]);
+
+        output.to_data().assert_approx_eq(&expected, 5);
+    }
+
     #[test]
     fn relu() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -639,6 +1268,29 @@ mod tests {
         assert_eq!(output3.to_data(), expected3);
     }
 
+    #[test]
+    fn clip_clamps_values_below_min() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model_opset16: clip_opset16::Model<Backend> = clip_opset16::Model::new(&device);
+        let model_opset7: clip_opset7::Model<Backend> = clip_opset7::Model::new(&device);
+
+        // Run the models
+        let input = Tensor::<Backend, 1>::from_floats([-1.0, 0.1, 0.3, 0.45, 0.75, 0.9], &device);
+        let expected1 = Data::from([0.3, 0.3, 0.3, 0.45, 0.75, 0.9]);
+        let expected2 = Data::from([0.5, 0.5, 0.5, 0.5, 0.7, 0.7]);
+        let expected3 = Data::from([-1.0, 0.1, 0.3, 0.45, 0.75, 0.8]);
+
+        for (output1, output2, output3) in [
+            model_opset16.forward(input.clone()),
+            model_opset7.forward(input),
+        ] {
+            assert_eq!(output1.to_data(), expected1);
+            assert_eq!(output2.to_data(), expected2);
+            assert_eq!(output3.to_data(), expected3);
+        }
+    }
+
     #[test]
     fn linear() {
         let device = Default::default();
@@ -676,6 +1328,42 @@ mod tests {
         assert!(expected_sum3.approx_eq(output_sum3, (1.0e-6, 2)));
     }
 
+    #[test]
+    fn gemm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: gemm::Model<Backend> = gemm::Model::default();
+
+        let device = Default::default();
+        // Run the model
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let (output1, output2) = model.forward(input);
+        let expected1 = Data::from([[2.4, 2.5], [4.2, 5.5]]);
+        let expected2 = Data::from([[4., 5.], [10., 11.]]);
+
+        output1.to_data().assert_approx_eq(&expected1, 4);
+        output2.to_data().assert_approx_eq(&expected2, 4);
+    }
+
+    #[test]
+    fn glu() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: glu::Model<Backend> = glu::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[1.0, -2.0, 0.5, 3.0], [-1.0, 2.0, 0.0, -0.5]],
+            &device,
+        );
+        let (output1, output2, first_half) = model.forward(input);
+        let expected = Data::from([[0.6225, -1.9051], [-0.5, 0.7551]]);
+        let expected_first_half = Data::from([[1.0, -2.0], [-1.0, 2.0]]);
+
+        output1.to_data().assert_approx_eq(&expected, 4);
+        output2.to_data().assert_approx_eq(&expected, 4);
+        assert_eq!(first_half.to_data(), expected_first_half);
+    }
+
     #[test]
     fn tanh() {
         // Initialize the model
@@ -704,6 +1392,45 @@ mod tests {
         output.to_data().assert_approx_eq(&expected, 4);
     }
 
+    #[test]
+    fn floor() {
+        let device = Default::default();
+        let model: floor::Model<Backend> = floor::Model::new(&device);
+
+        let input = Tensor::<Backend, 4>::from_floats([[[[-1.5, -0.5, 0.5, 1.5, 2.5]]]], &device);
+
+        let output = model.forward(input);
+        let expected = Data::from([[[[-2.0, -1.0, 0.0, 1.0, 2.0]]]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn ceil() {
+        let device = Default::default();
+        let model: ceil::Model<Backend> = ceil::Model::new(&device);
+
+        let input = Tensor::<Backend, 4>::from_floats([[[[-1.5, -0.5, 0.5, 1.5, 2.5]]]], &device);
+
+        let output = model.forward(input);
+        let expected = Data::from([[[[-1.0, 0.0, 1.0, 2.0, 3.0]]]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn round() {
+        let device = Default::default();
+        let model: round::Model<Backend> = round::Model::new(&device);
+
+        let input = Tensor::<Backend, 4>::from_floats([[[[-1.5, -0.5, 0.5, 1.5, 2.5]]]], &device);
+
+        let output = model.forward(input);
+        let expected = Data::from([[[[-2.0, 0.0, 0.0, 2.0, 2.0]]]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
     #[test]
     fn conv_transpose2d() {
         // Initialize the model with weights (loaded from the exported file)
@@ -796,6 +1523,73 @@ mod tests {
         assert_eq!(output2, expected2);
     }
 
+    #[test]
+    fn not() {
+        let device = Default::default();
+        let model: not::Model<Backend> = not::Model::new(&device);
+
+        let input = Tensor::<Backend, 4, Bool>::from_bool(
+            Data::from([[[[true, false, true, false]]]]),
+            &device,
+        );
+
+        let output = model.forward(input).to_data();
+        let expected = Data::from([[[[false, true, false, true]]]]);
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn and() {
+        let device = Default::default();
+        let model: and::Model<Backend> = and::Model::new(&device);
+
+        let input1 = Tensor::<Backend, 2, Bool>::from_bool(
+            Data::from([[false, false], [true, true]]),
+            &device,
+        );
+        let input2 = Tensor::<Backend, 2, Bool>::from_bool(Data::from([[false, true]]), &device);
+
+        let output = model.forward(input1, input2).to_data();
+        let expected = Data::from([[false, false], [false, true]]);
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn or() {
+        let device = Default::default();
+        let model: or::Model<Backend> = or::Model::new(&device);
+
+        let input1 = Tensor::<Backend, 2, Bool>::from_bool(
+            Data::from([[false, false], [true, true]]),
+            &device,
+        );
+        let input2 = Tensor::<Backend, 2, Bool>::from_bool(Data::from([[false, true]]), &device);
+
+        let output = model.forward(input1, input2).to_data();
+        let expected = Data::from([[false, true], [true, true]]);
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn xor() {
+        let device = Default::default();
+        let model: xor::Model<Backend> = xor::Model::new(&device);
+
+        let input1 = Tensor::<Backend, 2, Bool>::from_bool(
+            Data::from([[false, false], [true, true]]),
+            &device,
+        );
+        let input2 = Tensor::<Backend, 2, Bool>::from_bool(Data::from([[false, true]]), &device);
+
+        let output = model.forward(input1, input2).to_data();
+        let expected = Data::from([[false, true], [true, false]]);
+
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_model_creation_with_a_default_device() {
         let device = Default::default();
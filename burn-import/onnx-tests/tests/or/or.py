@@ -0,0 +1,38 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/or/or.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # The second input is broadcasted along the first dimension
+    node = helper.make_node("Or", ["input1", "input2"], ["output"], name="/Or")
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [
+            helper.make_tensor_value_info("input1", TensorProto.BOOL, [2, 2]),
+            helper.make_tensor_value_info("input2", TensorProto.BOOL, [1, 2]),
+        ],
+        [helper.make_tensor_value_info("output", TensorProto.BOOL, [2, 2])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "or.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input1 = [[False, False], [True, True]]
+    # input2 = [[False, True]]
+    # output = [[False, True], [True, True]]
+
+
+if __name__ == "__main__":
+    main()
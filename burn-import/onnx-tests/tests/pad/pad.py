@@ -0,0 +1,47 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/pad/pad.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Since opset 11, the pads and the constant value are provided as inputs.
+    pads = helper.make_tensor("pads", TensorProto.INT64, [4], [1, 0, 0, 2])
+    value = helper.make_tensor("constant_value", TensorProto.FLOAT, [], [1.5])
+    node = helper.make_node(
+        "Pad",
+        ["input", "pads", "constant_value"],
+        ["output"],
+        name="/Pad",
+        mode="constant",
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 5])],
+        initializer=[pads, value],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "pad.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]
+    # output = [
+    #     [1.5, 1.5, 1.5, 1.5, 1.5],
+    #     [0.0, 1.0, 2.0, 1.5, 1.5],
+    #     [3.0, 4.0, 5.0, 1.5, 1.5],
+    # ]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/reduce_mean/reduce_mean.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Before opset 18, the axes are provided as an attribute.
+    keep = helper.make_node(
+        "ReduceMean",
+        ["input"],
+        ["output1"],
+        name="/ReduceMean",
+        axes=[-1],
+        keepdims=1,
+    )
+    squeeze = helper.make_node(
+        "ReduceMean",
+        ["input"],
+        ["output2"],
+        name="/ReduceMean_1",
+        axes=[1, 3],
+        keepdims=0,
+    )
+
+    graph = helper.make_graph(
+        [keep, squeeze],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 2, 3])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [1, 1, 2, 1]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [1, 2]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 13)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "reduce_mean.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[[[1.0, 4.0, 7.0], [2.0, 5.0, 12.0]]]]
+    # output1 = [[[[4.0], [6.33333]]]]
+    # output2 = [[4.0, 6.33333]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/reduce_sum/reduce_sum.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Since opset 18, the axes are provided as an input instead of an attribute.
+    last_axis = helper.make_tensor("last_axis", TensorProto.INT64, [1], [-1])
+    axes = helper.make_tensor("axes", TensorProto.INT64, [2], [1, 3])
+    keep = helper.make_node(
+        "ReduceSum",
+        ["input", "last_axis"],
+        ["output1"],
+        name="/ReduceSum",
+        keepdims=1,
+    )
+    squeeze = helper.make_node(
+        "ReduceSum",
+        ["input", "axes"],
+        ["output2"],
+        name="/ReduceSum_1",
+        keepdims=0,
+    )
+    # Without axes, all the dimensions are reduced.
+    full = helper.make_node(
+        "ReduceSum",
+        ["input"],
+        ["output3"],
+        name="/ReduceSum_2",
+        keepdims=0,
+    )
+
+    graph = helper.make_graph(
+        [keep, squeeze, full],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 2, 3])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [1, 1, 2, 1]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [1, 2]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, []),
+        ],
+        initializer=[last_axis, axes],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 18)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "reduce_sum.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[[[1.0, 4.0, 7.0], [2.0, 5.0, 12.0]]]]
+    # output1 = [[[[12.0], [19.0]]]]
+    # output2 = [[12.0, 19.0]]
+    # output3 = [31.0]
+
+
+if __name__ == "__main__":
+    main()
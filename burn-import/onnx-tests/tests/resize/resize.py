@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/resize/resize.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Since opset 11, the inputs are [X, roi, scales, sizes], the omitted ones are empty.
+    sizes = helper.make_tensor("sizes", TensorProto.INT64, [4], [1, 1, 4, 4])
+    align_corners = helper.make_node(
+        "Resize",
+        ["input", "", "", "sizes"],
+        ["output1"],
+        name="/Resize",
+        mode="linear",
+        coordinate_transformation_mode="align_corners",
+    )
+    half_pixel = helper.make_node(
+        "Resize",
+        ["input", "", "", "sizes"],
+        ["output2"],
+        name="/Resize_1",
+        mode="linear",
+        coordinate_transformation_mode="half_pixel",
+    )
+
+    graph = helper.make_graph(
+        [align_corners, half_pixel],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 2, 2])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [1, 1, 4, 4]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [1, 1, 4, 4]),
+        ],
+        initializer=[sizes],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 13)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "resize.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[[[1.0, 2.0], [3.0, 4.0]]]]
+    # output1 = [[[[1.0, 1.3333, 1.6667, 2.0], ..., [3.0, 3.3333, 3.6667, 4.0]]]]
+    # output2 = [[[[1.0, 1.25, 1.75, 2.0], ..., [3.0, 3.25, 3.75, 4.0]]]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,43 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/scatter_nd/scatter_nd.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # ScatterND is not produced by torch.onnx.export for simple models, so the graph is built
+    # directly with the ONNX helper functions.
+    node = helper.make_node(
+        "ScatterND", ["data", "indices", "updates"], ["output"], name="/ScatterND"
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [
+            helper.make_tensor_value_info("data", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 2]),
+            helper.make_tensor_value_info("updates", TensorProto.FLOAT, [2]),
+        ],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "scatter_nd.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # data = [[1, 2, 3], [4, 5, 6]]
+    # indices = [[0, 1], [1, 2]]
+    # updates = [10, 20]
+    # output = [[1, 10, 3], [4, 5, 20]]
+
+
+if __name__ == "__main__":
+    main()
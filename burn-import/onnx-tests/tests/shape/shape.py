@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/shape/shape.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Reshapes `input2` to the shape of `input1`, as the dynamic reshapes exported by PyTorch
+    nodes = [
+        helper.make_node("Shape", ["input1"], ["shape"], name="/Shape"),
+        helper.make_node("Reshape", ["input2", "shape"], ["reshaped"], name="/Reshape"),
+        helper.make_node("Add", ["input1", "reshaped"], ["output"], name="/Add"),
+    ]
+
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [
+            helper.make_tensor_value_info("input1", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("input2", TensorProto.FLOAT, [6]),
+        ],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "shape.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input1 = [[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]
+    # input2 = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]
+    # output = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]
+
+
+if __name__ == "__main__":
+    main()
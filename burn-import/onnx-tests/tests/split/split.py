@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/split/split.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Without split sizes, the outputs have the same size except the last one, which holds the
+    # remaining elements when the axis isn't evenly divisible.
+    nodes = [
+        helper.make_node(
+            "Split", ["input"], ["output1", "output2", "output3"], name="/Split", axis=1
+        ),
+    ]
+
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 7])],
+        [
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, [2, 1]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "split.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = [[0, 1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12, 13]]
+    # output1 = [[0, 1, 2], [7, 8, 9]]
+    # output2 = [[3, 4, 5], [10, 11, 12]]
+    # output3 = [[6], [13]]
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/squeeze/squeeze.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Since opset 13, the axes are provided as an input instead of an attribute.
+    axes = helper.make_tensor("axes", TensorProto.INT64, [2], [1, -1])
+    node = helper.make_node(
+        "Squeeze",
+        ["input", "axes"],
+        ["output"],
+        name="/Squeeze",
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [3, 1, 4, 1])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 4])],
+        initializer=[axes],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "squeeze.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = arange(12).reshape(3, 1, 4, 1)
+    # output = arange(12).reshape(3, 4)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/squeeze/squeeze_opset11.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Before opset 13, the axes are provided as an attribute. The model is otherwise the same as
+    # squeeze.onnx, which provides them as an input.
+    node = helper.make_node(
+        "Squeeze",
+        ["input"],
+        ["output"],
+        name="/Squeeze",
+        axes=[1, -1],
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [3, 1, 4, 1])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 4])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 11)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "squeeze_opset11.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = arange(12).reshape(3, 1, 4, 1)
+    # output = arange(12).reshape(3, 4)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,40 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/unsqueeze/unsqueeze.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Before opset 13, the axes are provided as an attribute.
+    node = helper.make_node(
+        "Unsqueeze",
+        ["input"],
+        ["output"],
+        name="/Unsqueeze",
+        axes=[0, -1],
+    )
+
+    graph = helper.make_graph(
+        [node],
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [3, 4])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 3, 4, 1])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 11)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "unsqueeze.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Test data used in the test:
+    # input = arange(12).reshape(3, 4)
+    # output = arange(12).reshape(1, 3, 4, 1)
+
+
+if __name__ == "__main__":
+    main()
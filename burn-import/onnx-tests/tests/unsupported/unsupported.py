@@ -0,0 +1,37 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/unsupported/unsupported.onnx
+#
+# The model isn't generated by the build script, it is used to check that every unsupported node
+# type is reported by burn-import.
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    nodes = [
+        helper.make_node("Abs", ["input"], ["abs"], name="/Abs"),
+        helper.make_node("Relu", ["abs"], ["relu"], name="/Relu"),
+        helper.make_node("Sin", ["relu"], ["sin"], name="/Sin"),
+        helper.make_node("Abs", ["sin"], ["output"], name="/Abs_1"),
+    ]
+
+    graph = helper.make_graph(
+        nodes,
+        "main_graph",
+        [helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    onnx_name = "unsupported.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
 use burn::nn::PaddingConfig1d;
@@ -56,6 +56,13 @@ impl ToTokens for i64 {
     }
 }
 
+/// Prettier output for `i32`
+impl ToTokens for i32 {
+    fn to_tokens(&self) -> TokenStream {
+        convert_primitive(self)
+    }
+}
+
 /// Prettier output for `f64`
 impl ToTokens for f64 {
     fn to_tokens(&self) -> TokenStream {
@@ -63,6 +70,38 @@ impl ToTokens for f64 {
     }
 }
 
+/// Suffixed output for `f32`, so the type of the literal is never ambiguous
+impl ToTokens for f32 {
+    fn to_tokens(&self) -> TokenStream {
+        if self.is_nan() {
+            quote! { f32::NAN }
+        } else if self.is_infinite() && self.is_sign_positive() {
+            quote! { f32::INFINITY }
+        } else if self.is_infinite() {
+            quote! { f32::NEG_INFINITY }
+        } else {
+            let literal = Literal::f32_suffixed(*self);
+            quote! { #literal }
+        }
+    }
+}
+
+/// Prettier output for `bool`
+impl ToTokens for bool {
+    fn to_tokens(&self) -> TokenStream {
+        convert_primitive(self)
+    }
+}
+
+/// Tuple of two items
+impl<A: ToTokens, B: ToTokens> ToTokens for (A, B) {
+    fn to_tokens(&self) -> TokenStream {
+        let first = self.0.to_tokens();
+        let second = self.1.to_tokens();
+        quote! { (#first, #second) }
+    }
+}
+
 /// Padding configuration
 impl ToTokens for PaddingConfig1d {
     fn to_tokens(&self) -> TokenStream {
@@ -91,3 +130,57 @@ impl ToTokens for PaddingConfig2d {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives_to_tokens() {
+        assert_eq!(3i32.to_tokens().to_string(), "3");
+        assert_eq!((-3i32).to_tokens().to_string(), "- 3");
+        assert_eq!(true.to_tokens().to_string(), "true");
+        assert_eq!(false.to_tokens().to_string(), "false");
+    }
+
+    #[test]
+    fn test_f32_to_tokens_is_suffixed() {
+        assert_eq!(1.5f32.to_tokens().to_string(), "1.5f32");
+        assert_eq!(2f32.to_tokens().to_string(), "2f32");
+        assert_eq!((-0.25f32).to_tokens().to_string(), "- 0.25f32");
+        assert_eq!(f32::INFINITY.to_tokens().to_string(), "f32 :: INFINITY");
+        assert_eq!(
+            f32::NEG_INFINITY.to_tokens().to_string(),
+            "f32 :: NEG_INFINITY"
+        );
+        assert_eq!(f32::NAN.to_tokens().to_string(), "f32 :: NAN");
+    }
+
+    #[test]
+    fn test_tuple_to_tokens() {
+        assert_eq!((1usize, 2.5f32).to_tokens().to_string(), "(1 , 2.5f32)");
+        assert_eq!(
+            ([1usize, 2], false).to_tokens().to_string(),
+            "([1 , 2 ,] , false)"
+        );
+    }
+
+    #[test]
+    fn test_padding_config_1d_to_tokens() {
+        let cases = [
+            (PaddingConfig1d::Same, "PaddingConfig1d :: Same"),
+            (PaddingConfig1d::Valid, "PaddingConfig1d :: Valid"),
+            (
+                PaddingConfig1d::Explicit(3),
+                "PaddingConfig1d :: Explicit (3)",
+            ),
+        ];
+
+        for (padding, expected) in cases {
+            let tokens = padding.to_tokens();
+
+            assert_eq!(tokens.to_string(), expected);
+            syn::parse2::<syn::Expr>(tokens).expect("The tokens should be a valid expression");
+        }
+    }
+}
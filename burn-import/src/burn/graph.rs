@@ -8,7 +8,7 @@ use burn::record::{
     PrecisionSettings, PrettyJsonFileRecorder, Recorder,
 };
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use serde::{
     ser::{SerializeMap, SerializeTuple},
     Serialize,
@@ -45,6 +45,7 @@ pub struct BurnGraph<PS: PrecisionSettings> {
     default: Option<TokenStream>,
     blank_spaces: bool,
     gen_new_fn: bool,
+    visibility: Option<TokenStream>,
     graph_input_types: Vec<Type>,
     graph_output_types: Vec<Type>,
 }
@@ -190,6 +191,22 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         self
     }
 
+    /// Set the visibility of the generated model struct and of its fields, e.g. `pub(crate)`.
+    ///
+    /// By default, the struct is public and its fields are private.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the visibility is not a valid Rust visibility.
+    pub fn with_visibility(mut self, visibility: Option<&str>) -> Self {
+        self.visibility = visibility.map(|visibility| {
+            syn::parse_str::<syn::Visibility>(visibility)
+                .unwrap_or_else(|_| panic!("Invalid visibility: {visibility:?}"))
+                .to_token_stream()
+        });
+        self
+    }
+
     /// Add a comment at the top of the generated file.
     pub fn with_top_comment(mut self, top_comment: Option<String>) -> Self {
         self.top_comment = top_comment;
@@ -336,6 +353,17 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                             .tensor_register_future_use(&tensor, node_position)
                     })
             });
+
+        // Register graph tensor output as a use after the last node, so that a tensor returned by
+        // the model is cloned when it is also consumed by a node
+        self.graph_output_types
+            .clone()
+            .into_iter()
+            .flat_map(to_tensor)
+            .for_each(|tensor| {
+                self.scope
+                    .tensor_register_future_use(&tensor, self.nodes.len())
+            });
     }
 
     fn register_record_file(&mut self, file: PathBuf, recorder_str: &str) {
@@ -399,6 +427,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     }
 
     fn codegen_struct(&self) -> TokenStream {
+        let (struct_visibility, field_visibility) = match &self.visibility {
+            Some(visibility) => (visibility.clone(), visibility.clone()),
+            None => (quote! { pub }, quote! {}),
+        };
+
         let mut body = quote! {};
         self.nodes
             .iter()
@@ -409,11 +442,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
                 if matches!(&field, Type::Tensor(_)) {
                     quote! {
-                        #name: burn::module::Param<#ty>,
+                        #field_visibility #name: burn::module::Param<#ty>,
                     }
                 } else {
                     quote! {
-                        #name: #ty,
+                        #field_visibility #name: #ty,
                     }
                 }
             })
@@ -426,7 +459,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
         quote! {
             #[derive(Module, Debug)]
-            pub struct Model<B: Backend> {
+            #struct_visibility struct Model<B: Backend> {
                 #body
             }
         }
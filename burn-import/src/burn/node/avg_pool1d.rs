@@ -0,0 +1,164 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::{nn::pool::AvgPool1dConfig, record::PrecisionSettings};
+
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+#[derive(Debug, Clone)]
+pub struct AvgPool1dNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: AvgPool1dConfig,
+}
+
+impl AvgPool1dNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        config: AvgPool1dConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    AvgPool1d
+                },
+            ),
+            input,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for AvgPool1dNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, _with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let kernel_size = self.config.kernel_size.to_tokens();
+        let stride = self.config.stride.to_tokens();
+        let padding = self.config.padding.to_tokens();
+        let count_include_pad = self.config.count_include_pad;
+        let ceil_mode = self.config.ceil_mode;
+
+        let init_line = quote! {
+            init();
+        };
+
+        let tokens = quote! {
+            let #name = AvgPool1dConfig::new(#kernel_size)
+                .with_stride(#stride)
+                .with_padding(#padding)
+                .with_count_include_pad(#count_include_pad)
+                .with_ceil_mode(#ceil_mode)
+                .#init_line
+        };
+
+        Some(tokens)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        quote! {
+            let #output = self.#field.forward(#input);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::PaddingConfig1d");
+        imports.register("burn::nn::pool::AvgPool1d");
+        imports.register("burn::nn::pool::AvgPool1dConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::AvgPool1d(self)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        S::serialize_none(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{avg_pool1d::AvgPool1dNode, test::assert_tokens},
+        TensorType,
+    };
+    use burn::{nn::pool::AvgPool1dConfig, nn::PaddingConfig1d, record::FullPrecisionSettings};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(AvgPool1dNode::new(
+            "avg_pool1d",
+            TensorType::new_float("input", 3),
+            TensorType::new_float("output", 3),
+            AvgPool1dConfig::new(3)
+                .with_stride(1)
+                .with_padding(PaddingConfig1d::Valid),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::PaddingConfig1d;
+            use burn::nn::pool::AvgPool1d;
+            use burn::nn::pool::AvgPool1dConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                avg_pool1d: AvgPool1d,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let avg_pool1d = AvgPool1dConfig::new(3)
+                        .with_stride(1)
+                        .with_padding(PaddingConfig1d::Valid)
+                        .with_count_include_pad(true)
+                        .with_ceil_mode(false)
+                        .init();
+
+                    Self {
+                        avg_pool1d,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let output = self.avg_pool1d.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
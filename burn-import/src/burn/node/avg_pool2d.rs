@@ -52,6 +52,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for AvgPool2dNode {
         let strides = self.config.strides.to_tokens();
         let padding = self.config.padding.to_tokens();
         let count_include_pad = self.config.count_include_pad;
+        let ceil_mode = self.config.ceil_mode;
 
         let init_line = quote! {
             init();
@@ -62,6 +63,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for AvgPool2dNode {
                 .with_strides(#strides)
                 .with_padding(#padding)
                 .with_count_include_pad(#count_include_pad)
+                .with_ceil_mode(#ceil_mode)
                 .#init_line
         };
 
@@ -140,6 +142,7 @@ mod tests {
                         .with_strides([1, 1])
                         .with_padding(PaddingConfig2d::Valid)
                         .with_count_include_pad(true)
+                        .with_ceil_mode(false)
                         .init();
 
                     Self {
@@ -1,9 +1,11 @@
 use super::{
-    avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode,
-    concat::ConcatNode, constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
+    avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode,
+    binary::BinaryNode, clip::ClipNode, concat::ConcatNode, constant::ConstantNode,
+    constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
     conv_transpose_2d::ConvTranspose2dNode, dropout::DropoutNode, gather::GatherNode,
-    global_avg_pool::GlobalAvgPoolNode, linear::LinearNode, matmul::MatmulNode,
-    max_pool2d::MaxPool2dNode, reshape::ReshapeNode, unary::UnaryNode,
+    gather_elements::GatherElementsNode, gather_nd::GatherNdNode, linear::LinearNode,
+    matmul::MatmulNode, max_pool2d::MaxPool2dNode, pad::PadNode, reshape::ReshapeNode,
+    resize::ResizeNode, scatter_nd::ScatterNdNode, split::SplitNode, unary::UnaryNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
@@ -73,22 +75,29 @@ pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
 
 #[derive(Debug, Clone)]
 pub enum Node<PS: PrecisionSettings> {
+    AvgPool1d(AvgPool1dNode),
     AvgPool2d(AvgPool2dNode),
     BatchNorm(BatchNormNode<PS>),
     Binary(BinaryNode),
     Clip(ClipNode),
     Concat(ConcatNode),
     Constant(ConstantNode<PS>),
+    ConstantOfShape(ConstantOfShapeNode),
     Conv1d(Conv1dNode<PS>),
     Conv2d(Conv2dNode<PS>),
     ConvTranspose2d(ConvTranspose2dNode<PS>),
     Dropout(DropoutNode),
     Gather(GatherNode),
-    GlobalAvgPool(GlobalAvgPoolNode),
+    GatherElements(GatherElementsNode),
+    GatherNd(GatherNdNode),
     Linear(LinearNode<PS>),
     Matmul(MatmulNode),
     MaxPool2d(MaxPool2dNode),
+    Pad(PadNode),
     Reshape(ReshapeNode),
+    Resize(ResizeNode),
+    ScatterNd(ScatterNdNode),
+    Split(SplitNode),
     Unary(UnaryNode),
 }
 
@@ -96,22 +105,29 @@ macro_rules! match_all {
     ($self:expr, $func:expr) => {{
         #[allow(clippy::redundant_closure_call)]
         match $self {
+            Node::AvgPool1d(node) => $func(node),
             Node::AvgPool2d(node) => $func(node),
             Node::BatchNorm(node) => $func(node),
             Node::Binary(node) => $func(node),
             Node::Clip(node) => $func(node),
             Node::Concat(node) => $func(node),
             Node::Constant(node) => $func(node),
+            Node::ConstantOfShape(node) => $func(node),
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::Dropout(node) => $func(node),
             Node::Gather(node) => $func(node),
-            Node::GlobalAvgPool(node) => $func(node),
+            Node::GatherElements(node) => $func(node),
+            Node::GatherNd(node) => $func(node),
             Node::Linear(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::Pad(node) => $func(node),
             Node::Reshape(node) => $func(node),
+            Node::Resize(node) => $func(node),
+            Node::ScatterNd(node) => $func(node),
+            Node::Split(node) => $func(node),
             Node::Unary(node) => $func(node),
         }
     }};
@@ -129,22 +145,29 @@ impl<PS: PrecisionSettings> Serialize for Node<PS> {
 impl<PS: PrecisionSettings> Node<PS> {
     pub fn name(&self) -> &str {
         match self {
+            Node::AvgPool1d(_) => "avg_pool1d",
             Node::AvgPool2d(_) => "avg_pool2d",
             Node::BatchNorm(_) => "batch_norm",
             Node::Binary(binary) => binary.binary_type.as_str(),
             Node::Concat(_) => "concat",
             Node::Clip(_) => "clip",
             Node::Constant(_) => "constant",
+            Node::ConstantOfShape(_) => "constant_of_shape",
             Node::Conv1d(_) => "conv1d",
             Node::Conv2d(_) => "conv2d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::Dropout(_) => "dropout",
             Node::Gather(_) => "gather",
-            Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GatherElements(_) => "gather_elements",
+            Node::GatherNd(_) => "gather_nd",
             Node::Linear(_) => "linear",
             Node::Matmul(_) => "matmul",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::Pad(_) => "pad",
             Node::Reshape(_) => "reshape",
+            Node::Resize(_) => "resize",
+            Node::ScatterNd(_) => "scatter_nd",
+            Node::Split(_) => "split",
             Node::Unary(unary) => unary.kind.as_str(),
         }
     }
@@ -320,6 +343,73 @@ pub(crate) mod tests {
         assert_tokens(graph.codegen(), expected);
     }
 
+    #[test]
+    fn test_codegen_visibility() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(Conv2dNode::new(
+            "conv2d",
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            Data::from([2.]).serialize(),
+            None,
+            Conv2dConfig::new([3, 3], [3, 3]).with_padding(PaddingConfig2d::Valid),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::conv::Conv2dConfig;
+            use burn::nn::conv::Conv2d;
+            use burn::nn::PaddingConfig2d;
+
+            #[derive(Module, Debug)]
+            pub(crate) struct Model <B: Backend> {
+                pub(crate) conv2d: Conv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let conv2d = Conv2dConfig::new([3, 3], [3, 3])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_groups(1)
+                        .with_bias(true)
+                        .init_with(record.conv2d);
+
+                    Self {
+                        conv2d,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = self.conv2d.forward(tensor1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(
+            graph.with_visibility(Some("pub(crate)")).codegen(),
+            expected,
+        );
+    }
+
+    #[test]
+    #[should_panic = "Invalid visibility"]
+    fn test_codegen_invalid_visibility() {
+        BurnGraph::<FullPrecisionSettings>::default().with_visibility(Some("public"));
+    }
+
     #[test]
     fn test_codegen_clone_tensor() {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
@@ -396,4 +486,64 @@ pub(crate) mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_clone_graph_output() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            TensorType::new_float("tensor3", 4),
+        ));
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor3", 4),
+            TensorType::new_float("tensor4", 4),
+            TensorType::new_float("output", 4),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor4".to_string(),
+            ],
+            vec!["tensor3".to_string(), "output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    tensor2: Tensor<B, 4>,
+                    tensor4: Tensor<B, 4>
+                ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+                    let tensor3 = tensor1.matmul(tensor2);
+                    let output = tensor3.clone().matmul(tensor4);
+
+                    (tensor3, output)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -12,6 +12,9 @@ pub enum BinaryType {
     Mul,
     Div,
     Equal,
+    And,
+    Or,
+    Xor,
 }
 
 impl BinaryType {
@@ -22,6 +25,9 @@ impl BinaryType {
             BinaryType::Mul => "mul",
             BinaryType::Div => "div",
             BinaryType::Equal => "equal",
+            BinaryType::And => "and",
+            BinaryType::Or => "or",
+            BinaryType::Xor => "xor",
         }
     }
 }
@@ -153,6 +159,36 @@ impl BinaryNode {
 
         Self::new(lhs, rhs, output, BinaryType::Equal, Arc::new(function))
     }
+
+    pub(crate) fn and(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_and(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs && #rhs },
+            _ => panic!("And is supported for tensor to tensor and scalar to scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::And, Arc::new(function))
+    }
+
+    pub(crate) fn or(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_or(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs || #rhs },
+            _ => panic!("Or is supported for tensor to tensor and scalar to scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::Or, Arc::new(function))
+    }
+
+    pub(crate) fn xor(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_xor(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs ^ #rhs },
+            _ => panic!("Xor is supported for tensor to tensor and scalar to scalar only"),
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::Xor, Arc::new(function))
+    }
 }
 
 #[cfg(test)]
@@ -229,6 +265,56 @@ mod tests {
         }};
     }
 
+    macro_rules! test_binary_operator_on_bool_tensors {
+        ($operator:ident, $burn_operator:ident) => {{
+            let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+            graph.register(BinaryNode::$operator(
+                Type::Tensor(TensorType::new_bool("tensor1", 4)),
+                Type::Tensor(TensorType::new_bool("tensor2", 4)),
+                Type::Tensor(TensorType::new_bool("tensor3", 4)),
+            ));
+            graph.register_input_output(
+                vec!["tensor1".to_string(), "tensor2".to_string()],
+                vec!["tensor3".to_string()],
+            );
+
+            let expected = quote! {
+                use burn::tensor::Bool;
+                use burn::{
+                    module::Module,
+                    tensor::{backend::Backend, Tensor},
+                };
+
+                #[derive(Module, Debug)]
+                pub struct Model<B: Backend> {
+                    phantom: core::marker::PhantomData<B>,
+                }
+
+                impl<B: Backend> Model <B> {
+                    #[allow(unused_variables)]
+                    pub fn new_with(record: ModelRecord<B>) -> Self {
+                        Self {
+                            phantom: core::marker::PhantomData,
+                        }
+                    }
+
+                    #[allow(clippy::let_and_return, clippy::approx_constant)]
+                    pub fn forward(
+                        &self,
+                        tensor1: Tensor<B, 4, Bool>,
+                        tensor2: Tensor<B, 4, Bool>
+                    ) -> Tensor<B, 4, Bool> {
+                        let tensor3 = tensor1.$burn_operator(tensor2);
+
+                        tensor3
+                    }
+                }
+            };
+
+            assert_tokens(graph.codegen(), expected);
+        }};
+    }
+
     #[test]
     fn test_binary_codegen_add() {
         test_binary_operator_on_tensors!(add);
@@ -345,4 +431,19 @@ mod tests {
     fn test_binary_codegen_equal_scalars() {
         test_binary_operator_on_scalar_and_scalar!(equal, ==);
     }
+
+    #[test]
+    fn test_binary_codegen_and() {
+        test_binary_operator_on_bool_tensors!(and, bool_and);
+    }
+
+    #[test]
+    fn test_binary_codegen_or() {
+        test_binary_operator_on_bool_tensors!(or, bool_or);
+    }
+
+    #[test]
+    fn test_binary_codegen_xor() {
+        test_binary_operator_on_bool_tensors!(xor, bool_xor);
+    }
 }
@@ -0,0 +1,189 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::{
+    module::ParamId,
+    record::{ParamSerde, PrecisionSettings},
+    tensor::DataSerialize,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use serde::Serialize;
+
+/// Node for the ONNX `ConstantOfShape` operation, which creates a tensor of a static shape filled
+/// with a single value.
+///
+/// Like the constant tensors, the output is stored as a field of the model. It is created with
+/// [full](burn::tensor::Tensor::full) when the model is initialized without a record.
+#[derive(Debug, Clone, new)]
+pub struct ConstantOfShapeNode {
+    pub name: String,
+    pub output: TensorType,
+    pub value: f64,
+}
+
+impl ConstantOfShapeNode {
+    fn shape(&self) -> Vec<usize> {
+        self.output
+            .shape
+            .clone()
+            .expect("ConstantOfShape: the output shape must be known")
+    }
+
+    fn num_elements(&self) -> usize {
+        self.shape().iter().product()
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantOfShapeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        let name = Ident::new(self.name.as_ref(), Span::call_site());
+
+        Some(Type::Tensor(TensorType {
+            name,
+            ..self.output.clone()
+        }))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = Ident::new(self.name.as_ref(), Span::call_site());
+        let ty = self.output.ty();
+        let dim = self.output.dim.to_tokens();
+        let shape = self.shape().to_tokens();
+
+        let tokens = match (self.output.kind, with_record) {
+            (TensorKind::Float, true) => quote! {
+                let #name = record.#name.map(|tensor| tensor.set_require_grad(false));
+            },
+            (TensorKind::Int, true) => quote! {
+                let #name = record.#name;
+            },
+            (TensorKind::Float, false) => {
+                let value = self.value;
+
+                quote! {
+                    let #name: burn::module::Param<#ty> = burn::module::Param::new(
+                        burn::module::ParamId::new(),
+                        Tensor::<B, #dim>::full(#shape, #value, device).set_require_grad(false),
+                    );
+                }
+            }
+            (TensorKind::Int, false) => {
+                let value = self.value as i64;
+
+                quote! {
+                    let #name: burn::module::Param<#ty> = burn::module::Param::new(
+                        burn::module::ParamId::new(),
+                        Tensor::<B, #dim, Int>::full(#shape, #value, device),
+                    );
+                }
+            }
+            (TensorKind::Bool, _) => panic!("ConstantOfShape: bool tensors are not supported"),
+        };
+
+        Some(tokens)
+    }
+
+    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+        let name = Ident::new(self.name.as_ref(), Span::call_site());
+        let output = &self.output.name;
+
+        quote! {
+            let #output = self.#name.val();
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let TensorKind::Int = self.output.kind {
+            imports.register("burn::tensor::Int");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ConstantOfShape(self)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let id = ParamId::new().into_string();
+        let shape = self.shape();
+        let num_elements = self.num_elements();
+
+        match self.output.kind {
+            TensorKind::Float => {
+                let data = DataSerialize::new(vec![self.value; num_elements], shape);
+                let data: DataSerialize<PS::FloatElem> = data.convert();
+                ParamSerde::new(id, data).serialize(serializer)
+            }
+            TensorKind::Int => {
+                let data = DataSerialize::new(vec![self.value as i64; num_elements], shape);
+                let data: DataSerialize<PS::IntElem> = data.convert();
+                ParamSerde::new(id, data).serialize(serializer)
+            }
+            TensorKind::Bool => panic!("ConstantOfShape: bool tensors are not supported"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+
+    #[test]
+    fn test_codegen_constant_of_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConstantOfShapeNode::new(
+            "constantofshape1".to_string(),
+            TensorType::new("tensor1", 2, TensorKind::Float, Some(vec![2, 3])),
+            1.5,
+        ));
+
+        graph.register_input_output(vec![], vec!["tensor1".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                constantofshape1: burn::module::Param<Tensor<B, 2>>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let constantofshape1 = record
+                        .constantofshape1
+                        .map(|tensor| tensor.set_require_grad(false));
+
+                    Self {
+                        constantofshape1,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self) -> Tensor<B, 2> {
+                    let tensor1 = self.constantofshape1.val();
+
+                    tensor1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
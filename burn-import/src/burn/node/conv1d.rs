@@ -107,6 +107,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv1dNode<PS> {
             dilation: ConstantRecord::new(),
             groups: ConstantRecord::new(),
             padding: ConstantRecord::new(),
+            padding_mode: ConstantRecord::new(),
         };
 
         let item = Record::into_item::<PS>(record);
@@ -1,13 +1,19 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{TensorType, ToTokens, Type};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
 use quote::quote;
 
+/// Node for the ONNX `Gather` operation, which selects the slices of the input at the given
+/// indices along a dimension.
+///
+/// The output has the dimensions of the input where the gathered dimension is replaced by the
+/// dimensions of the indices, so a scalar index removes the dimension.
 #[derive(Debug, Clone, new)]
 pub struct GatherNode {
     pub input: TensorType,
-    pub index: TensorType,
+    pub index: Type,
     pub output: TensorType,
     pub dim: usize,
 }
@@ -17,29 +23,93 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
         vec![Type::Tensor(self.output.clone())]
     }
 
-    fn input_types(&self) -> Vec<crate::burn::Type> {
-        vec![
-            Type::Tensor(self.input.clone()),
-            Type::Tensor(self.index.clone()),
-        ]
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone()), self.index.clone()]
     }
 
-    fn forward(
-        &self,
-        scope: &mut crate::burn::Scope,
-        node_position: usize,
-    ) -> proc_macro2::TokenStream {
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let dim = self.dim.to_tokens();
         let input = scope.tensor_use_owned(&self.input, node_position);
-        let index = scope.tensor_use_owned(&self.index, node_position);
         let output = &self.output.name;
 
-        quote! {
-            let #output = #input.gather(#dim, #index);
+        // negative indices are counted from the end of the dimension
+        let normalize_index = quote! {
+            let index = index.clone().mask_where(
+                index.clone().lower_elem(0),
+                index.add_scalar(input.dims()[#dim] as i64),
+            );
+        };
+
+        match &self.index {
+            Type::Scalar(scalar) => {
+                let index = &scalar.name;
+                let output_rank = self.output.dim.to_tokens();
+
+                quote! {
+                    let #output = {
+                        let input = #input;
+                        let index = #index as i64;
+                        let index = if index < 0 {
+                            index + input.dims()[#dim] as i64
+                        } else {
+                            index
+                        };
+
+                        input.narrow(#dim, index as usize, 1).squeeze::<#output_rank>(#dim)
+                    };
+                }
+            }
+            Type::Tensor(tensor) if tensor.dim == 1 => {
+                let index = scope.tensor_use_owned(tensor, node_position);
+
+                quote! {
+                    let #output = {
+                        let input = #input;
+                        let index = #index;
+                        #normalize_index
+
+                        input.select(#dim, index)
+                    };
+                }
+            }
+            Type::Tensor(tensor) => {
+                let index = scope.tensor_use_owned(tensor, node_position);
+                let index_last_dim = (tensor.dim - 1).to_tokens();
+
+                // the gathered dimension is replaced by the dimensions of the indices
+                let input_dims = |range: core::ops::Range<usize>| {
+                    range.map(|i| {
+                        let i = i.to_tokens();
+                        quote! { input_dims[#i] }
+                    })
+                };
+                let index_dims = (0..tensor.dim).map(|i| {
+                    let i = i.to_tokens();
+                    quote! { index_dims[#i] }
+                });
+                let shape = input_dims(0..self.dim)
+                    .chain(index_dims)
+                    .chain(input_dims(self.dim + 1..self.input.dim));
+
+                quote! {
+                    let #output = {
+                        let input = #input;
+                        let index = #index;
+                        let input_dims = input.dims();
+                        let index_dims = index.dims();
+                        #normalize_index
+
+                        input
+                            .select(#dim, index.flatten::<1>(0, #index_last_dim))
+                            .reshape([#(#shape),*])
+                    };
+                }
+            }
+            _ => panic!("Gather: the index must be a tensor or a scalar"),
         }
     }
 
-    fn into_node(self) -> super::Node<PS> {
+    fn into_node(self) -> Node<PS> {
         Node::Gather(self)
     }
 }
@@ -53,18 +123,18 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{gather::GatherNode, test::assert_tokens},
-        TensorType,
+        ScalarKind, ScalarType, TensorType,
     };
 
     #[test]
-    fn test_codegen_gather() {
+    fn test_codegen_gather_1d_index() {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
 
         graph.register(GatherNode::new(
             TensorType::new_float("tensor1", 2),
-            TensorType::new_int("tensor2", 2),
+            Type::Tensor(TensorType::new_int("tensor2", 1)),
             TensorType::new_float("tensor3", 2),
-            1,
+            0,
         ));
 
         graph.register_input_output(
@@ -96,9 +166,83 @@ mod tests {
                 pub fn forward(
                     &self,
                     tensor1: Tensor<B, 2>,
-                    tensor2: Tensor<B, 2, Int>
+                    tensor2: Tensor<B, 1, Int>
                 ) -> Tensor<B, 2> {
-                    let tensor3 = tensor1.gather(1, tensor2);
+                    let tensor3 = {
+                        let input = tensor1;
+                        let index = tensor2;
+                        let index = index.clone().mask_where(
+                            index.clone().lower_elem(0),
+                            index.add_scalar(input.dims()[0] as i64),
+                        );
+
+                        input.select(0, index)
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_gather_2d_index() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor1", 3),
+            Type::Tensor(TensorType::new_int("tensor2", 2)),
+            TensorType::new_float("tensor3", 4),
+            1,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    tensor2: Tensor<B, 2, Int>
+                ) -> Tensor<B, 4> {
+                    let tensor3 = {
+                        let input = tensor1;
+                        let index = tensor2;
+                        let input_dims = input.dims();
+                        let index_dims = index.dims();
+                        let index = index.clone().mask_where(
+                            index.clone().lower_elem(0),
+                            index.add_scalar(input.dims()[1] as i64),
+                        );
+
+                        input
+                            .select(1, index.flatten::<1>(0, 1))
+                            .reshape([input_dims[0], index_dims[0], index_dims[1], input_dims[2]])
+                    };
 
                     tensor3
                 }
@@ -107,4 +251,61 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_gather_scalar_index() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor1", 2),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            TensorType::new_float("tensor2", 1),
+            1,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "scalar1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, scalar1: i64) -> Tensor<B, 1> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let index = scalar1 as i64;
+                        let index = if index < 0 {
+                            index + input.dims()[1] as i64
+                        } else {
+                            index
+                        };
+
+                        input.narrow(1, index as usize, 1).squeeze::<1>(1)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
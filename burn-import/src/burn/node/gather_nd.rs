@@ -0,0 +1,169 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct GatherNdNode {
+    pub input: TensorType,
+    pub index: TensorType,
+    pub output: TensorType,
+    pub batch_dims: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNdNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<crate::burn::Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.index.clone()),
+        ]
+    }
+
+    fn forward(
+        &self,
+        scope: &mut crate::burn::Scope,
+        node_position: usize,
+    ) -> proc_macro2::TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let index = scope.tensor_use_owned(&self.index, node_position);
+        let output = &self.output.name;
+
+        let rank = self.index.dim;
+        let coordinate_dim = (rank - 1).to_tokens();
+        let coordinates_shape = [-1, self.input.dim as i64].to_tokens();
+        // A single coordinate gathers a tensor with a single element
+        let output_shape = match rank {
+            1 => vec![quote! { 1 }],
+            _ => (0..rank - 1)
+                .map(|i| {
+                    let i = i.to_tokens();
+                    quote! { dims[#i] }
+                })
+                .collect(),
+        };
+
+        // The coordinates of the batch dimensions are prepended to each index, since the
+        // tensor gather only supports full coordinates.
+        let batch_coordinates = (0..self.batch_dims).map(|dim| {
+            let dim_tokens = dim.to_tokens();
+            let rank_tokens = rank.to_tokens();
+            let batch_shape = (0..rank - 1).map(|i| {
+                let i = i.to_tokens();
+                quote! { dims[#i] }
+            });
+            let arange_shape = (0..rank).map(|i| match i == dim {
+                true => quote! { dims[#dim_tokens] },
+                false => quote! { 1 },
+            });
+
+            quote! {
+                Tensor::<B, #rank_tokens, Int>::zeros([#(#batch_shape,)* 1], &device)
+                    + Tensor::arange(0..dims[#dim_tokens], &device).reshape([#(#arange_shape),*])
+            }
+        });
+
+        let coordinates = match self.batch_dims {
+            0 => quote! { indices },
+            _ => quote! {
+                {
+                    let device = indices.device();
+                    Tensor::cat([#(#batch_coordinates,)* indices].into(), #coordinate_dim)
+                }
+            },
+        };
+
+        quote! {
+            let #output = {
+                let indices = #index;
+                let dims = indices.dims();
+                let coordinates = #coordinates;
+
+                #input
+                    .gather_nd(coordinates.reshape(#coordinates_shape))
+                    .reshape([#(#output_shape),*])
+            };
+        }
+    }
+
+    fn into_node(self) -> super::Node<PS> {
+        Node::GatherNd(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{gather_nd::GatherNdNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_gather_nd() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNdNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 3),
+            TensorType::new_float("tensor3", 2),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 3, Int>
+                ) -> Tensor<B, 2> {
+                    let tensor3 = {
+                        let indices = tensor2;
+                        let dims = indices.dims();
+                        let coordinates = indices;
+
+                        tensor1
+                            .gather_nd(coordinates.reshape([-1, 2]))
+                            .reshape([dims[0], dims[1]])
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,21 +1,28 @@
 mod base;
 
+pub(crate) mod avg_pool1d;
 pub(crate) mod avg_pool2d;
 pub(crate) mod batch_norm;
 pub(crate) mod binary;
 pub(crate) mod clip;
 pub(crate) mod concat;
 pub(crate) mod constant;
+pub(crate) mod constant_of_shape;
 pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod dropout;
 pub(crate) mod gather;
-pub(crate) mod global_avg_pool;
+pub(crate) mod gather_elements;
+pub(crate) mod gather_nd;
 pub(crate) mod linear;
 pub(crate) mod matmul;
 pub(crate) mod max_pool2d;
+pub(crate) mod pad;
 pub(crate) mod reshape;
+pub(crate) mod resize;
+pub(crate) mod scatter_nd;
+pub(crate) mod split;
 pub(crate) mod unary;
 
 pub(crate) use base::*;
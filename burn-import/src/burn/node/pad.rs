@@ -0,0 +1,95 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct PadNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    /// The `(before, after)` padding of each dimension.
+    pub padding: Vec<(usize, usize)>,
+    pub value: f64,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let padding = self.padding.iter().map(|(before, after)| {
+            let before = before.to_tokens();
+            let after = after.to_tokens();
+            quote! { (#before, #after) }
+        });
+        let value = self.value;
+
+        quote! {
+            let #output = #input.pad([#(#padding),*], #value);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Pad(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_pad() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PadNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            vec![(1, 0), (0, 2)],
+            1.5,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.pad([(1, 0), (0, 2)], 1.5f64);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
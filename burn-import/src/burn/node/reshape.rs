@@ -23,7 +23,11 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let shape_values = &self.shape.to_tokens();
+        // The static shape of the output resolves the copied and inferred sizes when it is known
+        let shape_values = match &self.output.shape {
+            Some(shape) => shape.to_tokens(),
+            None => self.shape.to_tokens(),
+        };
 
         quote! {
             let #output = #input.reshape(#shape_values);
@@ -43,7 +47,7 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{reshape::ReshapeNode, test::assert_tokens},
-        TensorType,
+        TensorKind, TensorType,
     };
 
     #[test]
@@ -87,4 +91,46 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_static_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReshapeNode::new(
+            TensorType::new("tensor1", 3, TensorKind::Float, Some(vec![2, 3, 4])),
+            TensorType::new("tensor2", 2, TensorKind::Float, Some(vec![6, 4])),
+            [-1, 4].into(),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.reshape([6, 4]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
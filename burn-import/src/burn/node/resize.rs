@@ -0,0 +1,114 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::{
+    record::PrecisionSettings,
+    tensor::ops::{InterpolateMode, InterpolateOptions},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ResizeNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub output_size: [usize; 2],
+    pub options: InterpolateOptions,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let output_size = self.output_size.to_tokens();
+        let mode = match self.options.mode {
+            InterpolateMode::Nearest => quote! { InterpolateMode::Nearest },
+            InterpolateMode::Bilinear => quote! { InterpolateMode::Bilinear },
+        };
+        let align_corners = self.options.align_corners;
+
+        quote! {
+            let #output = interpolate(
+                #input,
+                #output_size,
+                InterpolateOptions::new(#mode, #align_corners),
+            );
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::module::interpolate");
+        imports.register("burn::tensor::ops::InterpolateMode");
+        imports.register("burn::tensor::ops::InterpolateOptions");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Resize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_resize() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            [4, 4],
+            InterpolateOptions::new(InterpolateMode::Bilinear, true),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::module::interpolate;
+            use burn::tensor::ops::InterpolateMode;
+            use burn::tensor::ops::InterpolateOptions;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = interpolate(
+                        tensor1,
+                        [4, 4],
+                        InterpolateOptions::new(InterpolateMode::Bilinear, true),
+                    );
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
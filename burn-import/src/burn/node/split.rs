@@ -0,0 +1,198 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct SplitNode {
+    pub input: TensorType,
+    pub outputs: Vec<TensorType>,
+    pub dim: usize,
+    pub split_sizes: Option<Vec<usize>>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SplitNode {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs
+            .iter()
+            .map(|t| Type::Tensor(t.clone()))
+            .collect()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let dim = self.dim.to_tokens();
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let outputs = self.outputs.iter().map(|t| &t.name);
+
+        match &self.split_sizes {
+            Some(sizes) => {
+                let num_outputs = sizes.len();
+                let mut start = 0;
+                let parts = sizes.iter().enumerate().map(|(i, size)| {
+                    let part_start = start.to_tokens();
+                    let part_size = size.to_tokens();
+                    start += size;
+
+                    if i + 1 < num_outputs {
+                        quote! { split_input.clone().narrow(#dim, #part_start, #part_size) }
+                    } else {
+                        quote! { split_input.narrow(#dim, #part_start, #part_size) }
+                    }
+                });
+
+                quote! {
+                    let (#(#outputs),*) = {
+                        let split_input = #input;
+                        (#(#parts),*)
+                    };
+                }
+            }
+            None => {
+                let num_outputs = self.outputs.len().to_tokens();
+                let parts = self
+                    .outputs
+                    .iter()
+                    .map(|_| quote! { split_tensors.next().unwrap() });
+
+                quote! {
+                    let (#(#outputs),*) = {
+                        let mut split_tensors = #input.chunk(#num_outputs, #dim).into_iter();
+                        (#(#parts),*)
+                    };
+                }
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Split(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{split::SplitNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_split_sizes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 2),
+            vec![
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+            ],
+            1,
+            Some(vec![1, 3]),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let (tensor2, tensor3) = {
+                        let split_input = tensor1;
+                        (
+                            split_input.clone().narrow(1, 0, 1),
+                            split_input.narrow(1, 1, 3)
+                        )
+                    };
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_split_equal() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 2),
+            vec![
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+            ],
+            0,
+            None,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let (tensor2, tensor3) = {
+                        let mut split_tensors = tensor1.chunk(2, 0).into_iter();
+                        (split_tensors.next().unwrap(), split_tensors.next().unwrap())
+                    };
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
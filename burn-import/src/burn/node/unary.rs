@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, Scope, ToTokens, Type};
+use crate::burn::{BurnImports, Scope, TensorKind, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -21,42 +21,70 @@ pub struct UnaryNode {
 #[derive(Clone)]
 pub enum UnaryNodeKind {
     Cast,
+    Ceil,
     Cos,
     Erf,
     Exp,
     Flatten,
+    Floor,
     Gelu,
+    GlobalAvgPool,
+    GlobalMaxPool,
+    Glu,
+    Identity,
+    LeakyRelu,
+    LocalResponseNorm,
     Log,
     LogSoftmax,
     Neg,
+    Not,
     Reciprocal,
+    ReduceMean,
+    ReduceSum,
     Relu,
+    Round,
     Sigmoid,
     Softmax,
     Sqrt,
+    Squeeze,
     Tanh,
     Transpose,
+    Unsqueeze,
 }
 
 impl UnaryNodeKind {
     pub fn as_str(&self) -> &str {
         match self {
             Self::Cast => "cast",
+            Self::Ceil => "ceil",
             Self::Cos => "cos",
             Self::Erf => "erf",
             Self::Exp => "exp",
             Self::Flatten => "flatten",
+            Self::Floor => "floor",
             Self::Gelu => "gelu",
+            Self::GlobalAvgPool => "global_avg_pool",
+            Self::GlobalMaxPool => "global_max_pool",
+            Self::Glu => "glu",
+            Self::Identity => "identity",
+            Self::LeakyRelu => "leaky_relu",
+            Self::LocalResponseNorm => "local_response_norm",
             Self::Log => "log",
             Self::LogSoftmax => "log_softmax",
             Self::Neg => "neg",
+            Self::Not => "not",
             Self::Reciprocal => "reciprocal",
+            Self::ReduceMean => "reduce_mean",
+            Self::ReduceSum => "reduce_sum",
             Self::Relu => "relu",
+            Self::Round => "round",
             Self::Sigmoid => "sigmoid",
             Self::Softmax => "softmax",
             Self::Sqrt => "sqrt",
+            Self::Squeeze => "squeeze",
             Self::Tanh => "tanh",
             Self::Transpose => "transpose",
+            Self::Unsqueeze => "unsqueeze",
         }
     }
 }
@@ -149,12 +177,182 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::LogSoftmax, Rc::new(function))
     }
 
+    pub(crate) fn leaky_relu(input: Type, output: Type, alpha: f64) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::leaky_relu(#input, #alpha) };
+        Self::new(input, output, UnaryNodeKind::LeakyRelu, Rc::new(function))
+    }
+
     pub(crate) fn softmax(input: Type, output: Type, dim: usize) -> Self {
         let dim = dim.to_tokens();
         let function = move |input| quote! { burn::tensor::activation::softmax(#input, #dim) };
         Self::new(input, output, UnaryNodeKind::Softmax, Rc::new(function))
     }
 
+    pub(crate) fn glu(input: Type, output: Type, dim: usize) -> Self {
+        let dim = dim.to_tokens();
+        let function = move |input| quote! { burn::tensor::activation::glu(#input, #dim) };
+        Self::new(input, output, UnaryNodeKind::Glu, Rc::new(function))
+    }
+
+    pub(crate) fn local_response_norm(
+        input: Type,
+        output: Type,
+        size: usize,
+        alpha: f64,
+        beta: f64,
+        bias: f64,
+    ) -> Self {
+        let size = size.to_tokens();
+        let function = move |input| {
+            quote! {
+                burn::tensor::module::local_response_norm(#input, #size, #alpha, #beta, #bias)
+            }
+        };
+        Self::new(
+            input,
+            output,
+            UnaryNodeKind::LocalResponseNorm,
+            Rc::new(function),
+        )
+    }
+
+    /// Average each channel over its spatial dimensions, for 1D (rank 3) or 2D (rank 4) inputs.
+    pub(crate) fn global_avg_pool(input: Type, output: Type) -> Self {
+        let rank = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("GlobalAvgPool: only tensor input is valid"),
+        };
+        let pool = match rank {
+            3 => quote! { global_avg_pool1d },
+            4 => quote! { global_avg_pool2d },
+            dim => panic!("GlobalAvgPool: unsupported input dim ({dim})"),
+        };
+        let function = move |input| quote! { burn::tensor::module::#pool(#input) };
+        Self::new(
+            input,
+            output,
+            UnaryNodeKind::GlobalAvgPool,
+            Rc::new(function),
+        )
+    }
+
+    /// Take the maximum of each channel over its spatial dimensions, for 1D (rank 3) or 2D
+    /// (rank 4) inputs.
+    pub(crate) fn global_max_pool(input: Type, output: Type) -> Self {
+        let rank = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("GlobalMaxPool: only tensor input is valid"),
+        };
+        let pool = match rank {
+            3 => quote! { global_max_pool1d },
+            4 => quote! { global_max_pool2d },
+            dim => panic!("GlobalMaxPool: unsupported input dim ({dim})"),
+        };
+        let function = move |input| quote! { burn::tensor::module::#pool(#input) };
+        Self::new(
+            input,
+            output,
+            UnaryNodeKind::GlobalMaxPool,
+            Rc::new(function),
+        )
+    }
+
+    /// Squeeze the given dimensions, which must be sorted in ascending order.
+    pub(crate) fn squeeze(input: Type, output: Type, dims: Vec<usize>) -> Self {
+        let rank = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("Squeeze: only tensor input is valid"),
+        };
+
+        // squeeze from the last dimension so the remaining indices are not shifted
+        let function = move |input| {
+            dims.iter()
+                .rev()
+                .enumerate()
+                .fold(quote! { #input }, |tokens, (i, dim)| {
+                    let rank = (rank - i - 1).to_tokens();
+                    let dim = dim.to_tokens();
+                    quote! { #tokens.squeeze::<#rank>(#dim) }
+                })
+        };
+        Self::new(input, output, UnaryNodeKind::Squeeze, Rc::new(function))
+    }
+
+    /// Average over the given dimensions, sorted in ascending order.
+    pub(crate) fn reduce_mean(input: Type, output: Type, dims: Vec<usize>, keepdims: bool) -> Self {
+        Self::reduce(input, output, dims, keepdims, UnaryNodeKind::ReduceMean)
+    }
+
+    /// Sum over the given dimensions, sorted in ascending order.
+    pub(crate) fn reduce_sum(input: Type, output: Type, dims: Vec<usize>, keepdims: bool) -> Self {
+        Self::reduce(input, output, dims, keepdims, UnaryNodeKind::ReduceSum)
+    }
+
+    fn reduce(
+        input: Type,
+        output: Type,
+        dims: Vec<usize>,
+        keepdims: bool,
+        kind: UnaryNodeKind,
+    ) -> Self {
+        let rank = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("{}: only tensor input is valid", kind.as_str()),
+        };
+        let (op_all, op_dim) = match kind {
+            UnaryNodeKind::ReduceMean => (quote! { mean }, quote! { mean_dim }),
+            UnaryNodeKind::ReduceSum => (quote! { sum }, quote! { sum_dim }),
+            _ => unreachable!(),
+        };
+
+        let function = move |input| {
+            // a tensor can't have a rank of 0, so the full reduction keeps a single dimension
+            if !keepdims && dims.len() == rank {
+                return quote! { #input.#op_all() };
+            }
+
+            let reduced = dims.iter().fold(quote! { #input }, |tokens, dim| {
+                let dim = dim.to_tokens();
+                quote! { #tokens.#op_dim(#dim) }
+            });
+
+            if keepdims {
+                return reduced;
+            }
+
+            // squeeze from the last dimension so the remaining indices are not shifted
+            dims.iter()
+                .rev()
+                .enumerate()
+                .fold(reduced, |tokens, (i, dim)| {
+                    let rank = (rank - i - 1).to_tokens();
+                    let dim = dim.to_tokens();
+                    quote! { #tokens.squeeze::<#rank>(#dim) }
+                })
+        };
+        Self::new(input, output, kind, Rc::new(function))
+    }
+
+    /// Insert dimensions of size 1 at the given output positions, sorted in ascending order.
+    pub(crate) fn unsqueeze(input: Type, output: Type, dims: Vec<usize>) -> Self {
+        let rank = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("Unsqueeze: only tensor input is valid"),
+        };
+
+        // insert from the first position so the following indices are already in place
+        let function = move |input| {
+            dims.iter()
+                .enumerate()
+                .fold(quote! { #input }, |tokens, (i, dim)| {
+                    let rank = (rank + i + 1).to_tokens();
+                    let dim = dim.to_tokens();
+                    quote! { #tokens.unsqueeze_dim::<#rank>(#dim) }
+                })
+        };
+        Self::new(input, output, UnaryNodeKind::Unsqueeze, Rc::new(function))
+    }
+
     pub(crate) fn sqrt(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.sqrt()};
         Self::new(input, output, UnaryNodeKind::Sqrt, Rc::new(function))
@@ -175,11 +373,33 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Reciprocal, Rc::new(function))
     }
 
+    pub(crate) fn floor(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.floor() };
+        Self::new(input, output, UnaryNodeKind::Floor, Rc::new(function))
+    }
+
+    pub(crate) fn ceil(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.ceil() };
+        Self::new(input, output, UnaryNodeKind::Ceil, Rc::new(function))
+    }
+
+    pub(crate) fn round(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.round() };
+        Self::new(input, output, UnaryNodeKind::Round, Rc::new(function))
+    }
+
     pub(crate) fn cos(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.cos()};
         Self::new(input, output, UnaryNodeKind::Cos, Rc::new(function))
     }
 
+    /// The output is bound to the input, for the pass-through nodes that can't be removed from the
+    /// graph, e.g. a graph input returned as a graph output.
+    pub(crate) fn identity(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input };
+        Self::new(input, output, UnaryNodeKind::Identity, Rc::new(function))
+    }
+
     pub(crate) fn exp(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.exp()};
         Self::new(input, output, UnaryNodeKind::Exp, Rc::new(function))
@@ -200,16 +420,24 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Neg, Rc::new(function))
     }
 
+    pub(crate) fn not(input: Type, output: Type) -> Self {
+        let function = match &input {
+            Type::Tensor(_) => move |input| quote! { #input.bool_not() },
+            Type::Scalar(_) => move |input| quote! { !#input },
+            _ => panic!("Not is supported for tensor and scalar only"),
+        };
+        Self::new(input, output, UnaryNodeKind::Not, Rc::new(function))
+    }
+
     /// Casts the input to the output type.
     ///
     /// Currently this function only supports the following conversions:
     /// 1) scalar -> scalar
+    /// 2) tensor -> tensor of any kind
     ///
     /// TODO: Implement the following conversions:
-    /// 2) tensor int -> tensor float
-    /// 3) tensor float -> tensor int
-    /// 4) tensor -> scalar
-    /// 5) scalar -> tensor
+    /// 3) tensor -> scalar
+    /// 4) scalar -> tensor
     pub(crate) fn cast(input: Type, output: Type) -> Self {
         match (input.clone(), output.clone()) {
             (Type::Scalar(input_scalar), Type::Scalar(output_scalar)) => {
@@ -227,15 +455,23 @@ impl UnaryNode {
                     )
                 }
             }
-            (Type::Tensor(_input_tensor), Type::Tensor(_output_tensor)) => {
-                // TODO: Implement this after tensor Int is implemented (@antimora 8/2/2023)
-                // TODO: If the input is scalar and the output type is a tensor,
-                // we should generate another code block. (@antimora 8/4/2023)
-                // Tensor::from_data(Data::from([#input]).convert()).unsqueeze();
-                todo!()
+            (Type::Tensor(input_tensor), Type::Tensor(output_tensor)) => {
+                let function: FnPointer = match (input_tensor.kind, output_tensor.kind) {
+                    (TensorKind::Int, TensorKind::Int)
+                    | (TensorKind::Float, TensorKind::Float)
+                    | (TensorKind::Bool, TensorKind::Bool) => Rc::new(|input| input),
+                    (_, TensorKind::Int) => Rc::new(|input| quote! { #input.int() }),
+                    (_, TensorKind::Float) => Rc::new(|input| quote! { #input.float() }),
+                    // Any non-zero value is true
+                    (_, TensorKind::Bool) => {
+                        Rc::new(|input| quote! { #input.equal_elem(0).bool_not() })
+                    }
+                };
+
+                Self::new(input, output, UnaryNodeKind::Cast, function)
             }
 
-            _ => panic!("output must be a tensor"),
+            _ => panic!("Cast: the input and the output must both be tensors or scalars"),
         }
     }
 }
@@ -243,8 +479,11 @@ impl UnaryNode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::burn::graph::BurnGraph;
+    use crate::burn::node::test::assert_tokens;
     use crate::burn::node::tests::one_node_graph;
     use crate::burn::{ScalarKind, ScalarType, TensorType};
+    use burn::record::FullPrecisionSettings;
 
     #[test]
     fn test_unary_codegen_flatten() {
@@ -305,6 +544,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_leaky_relu() {
+        one_node_graph(
+            UnaryNode::leaky_relu(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                0.1,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::leaky_relu(tensor1, 0.1f64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_sigmoid() {
         one_node_graph(
@@ -364,6 +623,207 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_global_avg_pool_1d() {
+        one_node_graph(
+            UnaryNode::global_avg_pool(
+                Type::Tensor(TensorType::new_float("tensor1", 3)),
+                Type::Tensor(TensorType::new_float("tensor2", 3)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = burn::tensor::module::global_avg_pool1d(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_global_avg_pool_2d() {
+        one_node_graph(
+            UnaryNode::global_avg_pool(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::module::global_avg_pool2d(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_global_max_pool_1d() {
+        one_node_graph(
+            UnaryNode::global_max_pool(
+                Type::Tensor(TensorType::new_float("tensor1", 3)),
+                Type::Tensor(TensorType::new_float("tensor2", 3)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = burn::tensor::module::global_max_pool1d(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_global_max_pool_2d() {
+        one_node_graph(
+            UnaryNode::global_max_pool(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::module::global_max_pool2d(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_glu() {
+        one_node_graph(
+            UnaryNode::glu(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                1,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = burn::tensor::activation::glu(tensor1, 1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_local_response_norm() {
+        one_node_graph(
+            UnaryNode::local_response_norm(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                3,
+                0.0001,
+                0.75,
+                1.0,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::module::local_response_norm(tensor1, 3, 0.0001f64, 0.75f64, 1f64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_squeeze() {
+        one_node_graph(
+            UnaryNode::squeeze(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                vec![1, 3],
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.squeeze::<3>(3).squeeze::<2>(1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_mean() {
+        one_node_graph(
+            UnaryNode::reduce_mean(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                vec![1, 3],
+                true,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.mean_dim(1).mean_dim(3);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_sum() {
+        one_node_graph(
+            UnaryNode::reduce_sum(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                vec![1, 3],
+                false,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.sum_dim(1).sum_dim(3).squeeze::<3>(3).squeeze::<2>(1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_unsqueeze() {
+        one_node_graph(
+            UnaryNode::unsqueeze(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                vec![0, 3],
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.unsqueeze_dim::<3>(0).unsqueeze_dim::<4>(3);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_tanh() {
         one_node_graph(
@@ -421,6 +881,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_floor() {
+        one_node_graph(
+            UnaryNode::floor(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.floor();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_ceil() {
+        one_node_graph(
+            UnaryNode::ceil(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.ceil();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_round() {
+        one_node_graph(
+            UnaryNode::round(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.round();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_cast() {
         one_node_graph(
@@ -455,6 +972,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_cast_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnaryNode::cast(
+            Type::Tensor(TensorType::new_float("tensor1", 2)),
+            Type::Tensor(TensorType::new_int("tensor2", 2)),
+        ));
+        graph.register(UnaryNode::cast(
+            Type::Tensor(TensorType::new_int("tensor2", 2)),
+            Type::Tensor(TensorType::new_bool("tensor3", 2)),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor3".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Bool;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2, Bool> {
+                    let tensor2 = tensor1.int();
+                    let tensor3 = tensor2.equal_elem(0).bool_not();
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
     #[test]
     fn test_unary_codegen_cos() {
         one_node_graph(
@@ -474,6 +1039,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_identity() {
+        one_node_graph(
+            UnaryNode::identity(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_exp() {
         one_node_graph(
@@ -568,4 +1152,47 @@ mod tests {
             vec!["tensor2".to_string()],
         );
     }
+
+    #[test]
+    fn test_unary_not_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnaryNode::not(
+            Type::Tensor(TensorType::new_bool("tensor1", 4)),
+            Type::Tensor(TensorType::new_bool("tensor2", 4)),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Bool;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4, Bool>) -> Tensor<B, 4, Bool> {
+                    let tensor2 = tensor1.bool_not();
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
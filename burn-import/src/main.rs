@@ -13,5 +13,9 @@ fn main() {
         .development(true)
         .record_type(RecordType::PrettyJson)
         .out_dir(output_dir.as_str())
-        .run_from_cli();
+        .run_from_cli()
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            std::process::exit(1);
+        });
 }
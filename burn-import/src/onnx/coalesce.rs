@@ -1,10 +1,16 @@
 use std::{iter::Peekable, slice::IterMut};
 
-use super::ir::{AttributeValue, Node, NodeType};
+use super::ir::{Argument, AttributeValue, Node, NodeType};
 use crate::onnx::ir::{ArgType, Data, TensorType};
 
 /// The function transforms the graph into a new one where the nodes are coalesced into a single node.
-pub fn coalesce(nodes: &mut Vec<Node>) {
+///
+/// The graph outputs are required to leave untouched the nodes whose intermediate results are
+/// returned by the model.
+pub fn coalesce(nodes: &mut Vec<Node>, graph_outputs: &[String]) {
+    fuse_glu(nodes, graph_outputs);
+    pad_asymmetric_conv2d(nodes);
+
     let mut iter_mut = nodes.iter_mut().peekable();
     let mut nodes_to_remove: Vec<String> = vec![];
     while let Some(node) = iter_mut.next() {
@@ -30,29 +36,44 @@ fn convert_gemm_to_linear(node: &mut Node) {
     if node.outputs.len() != 1 {
         panic!("Gemm node must have 1 output");
     }
-    let straight_linear = match (
-        node.attrs.get("alpha"),
-        node.attrs.get("beta"),
-        node.attrs.get("transB"),
-    ) {
-        (
-            Some(AttributeValue::Float32(alpha)),
-            Some(AttributeValue::Float32(beta)),
-            Some(AttributeValue::Int64(trans_b)),
-        ) => *alpha == 1.0 && *beta == 1.0 && *trans_b == 1,
-        _ => false,
+
+    // Missing attributes take their default values per ONNX spec
+    let alpha = match node.attrs.get("alpha") {
+        Some(AttributeValue::Float32(alpha)) => *alpha,
+        _ => 1.0,
+    };
+    let beta = match node.attrs.get("beta") {
+        Some(AttributeValue::Float32(beta)) => *beta,
+        _ => 1.0,
+    };
+    let trans_a = match node.attrs.get("transA") {
+        Some(AttributeValue::Int64(trans_a)) => *trans_a,
+        _ => 0,
+    };
+    let trans_b = match node.attrs.get("transB") {
+        Some(AttributeValue::Int64(trans_b)) => *trans_b,
+        _ => 0,
     };
 
-    if straight_linear {
-        node.node_type = NodeType::Linear;
-        node.attrs.remove("alpha");
-        node.attrs.remove("beta");
-        node.attrs.remove("transB");
+    let straight_linear = alpha == 1.0 && beta == 1.0 && trans_a == 0;
 
-        // Transpose the weights
+    if !straight_linear || node.inputs[1].value.is_none() {
+        panic!(
+            "Gemm: only the fully-connected pattern with constant weights is supported \
+             (alpha=1, beta=1, transA=0), got alpha={alpha}, beta={beta}, transA={trans_a}, \
+             transB={trans_b}"
+        );
+    }
+
+    node.node_type = NodeType::Linear;
+    node.attrs.remove("alpha");
+    node.attrs.remove("beta");
+    node.attrs.remove("transA");
+    node.attrs.remove("transB");
+
+    // Burn stores the weights as [in, out], which is the Gemm layout when transB=0
+    if trans_b != 0 {
         transpose_linear_node_weights(node);
-    } else {
-        panic!("Full Gemm node not supported yet.");
     }
 }
 
@@ -180,3 +201,134 @@ fn convert_and_remove_add_node(
     // Remove the Add node
     nodes_to_remove.push(bias_node.name.clone());
 }
+
+/// This function fuses the `Split -> Sigmoid -> Mul` sequence into a single Glu node.
+///
+/// PyTorch exports gated linear units by splitting the input in two halves, applying a Sigmoid on
+/// the second half and multiplying it with the first half. The nodes are kept as they are when the
+/// intermediate results are used by other nodes or returned by the model.
+fn fuse_glu(nodes: &mut Vec<Node>, graph_outputs: &[String]) {
+    let mut position = 0;
+
+    while position + 2 < nodes.len() {
+        if is_glu_pattern(nodes, position, graph_outputs) {
+            let mul_node = nodes.remove(position + 2);
+            nodes.remove(position + 1);
+
+            let split_node = &mut nodes[position];
+            split_node.node_type = NodeType::Glu;
+            split_node.inputs.truncate(1);
+            split_node.outputs = mul_node.outputs;
+            split_node.attrs.retain(|key, _| key == "axis");
+        }
+
+        position += 1;
+    }
+}
+
+/// Helper function to check if the nodes starting at the given position form a gated linear unit
+fn is_glu_pattern(nodes: &[Node], position: usize, graph_outputs: &[String]) -> bool {
+    let (split_node, sigmoid_node, mul_node) =
+        (&nodes[position], &nodes[position + 1], &nodes[position + 2]);
+
+    if split_node.node_type != NodeType::Split
+        || split_node.outputs.len() != 2
+        || sigmoid_node.node_type != NodeType::Sigmoid
+        || mul_node.node_type != NodeType::Mul
+        || mul_node.inputs.len() != 2
+    {
+        return false;
+    }
+
+    // The two halves must have the same size
+    let split_sizes = match (split_node.attrs.get("split"), split_node.inputs.get(1)) {
+        (Some(AttributeValue::Int64s(sizes)), _) => Some(sizes.clone()),
+        (_, Some(input)) => match &input.value {
+            Some(Data::Int64s(sizes)) => Some(sizes.clone()),
+            _ => return false,
+        },
+        _ => None,
+    };
+    if matches!(split_sizes, Some(sizes) if sizes[0] != sizes[1]) {
+        return false;
+    }
+
+    let value = &split_node.outputs[0].name;
+    let gate_input = &split_node.outputs[1].name;
+    let gate = &sigmoid_node.outputs[0].name;
+    let mul_inputs = (&mul_node.inputs[0].name, &mul_node.inputs[1].name);
+
+    if sigmoid_node.inputs[0].name != *gate_input
+        || (mul_inputs != (value, gate) && mul_inputs != (gate, value))
+    {
+        return false;
+    }
+
+    // The intermediate results must not be used anywhere else
+    let intermediates = [value, gate_input, gate];
+    let used_by_other_nodes = nodes
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != position + 1 && *i != position + 2)
+        .any(|(_, node)| {
+            node.inputs
+                .iter()
+                .any(|input| intermediates.contains(&&input.name))
+        });
+    let used_as_output = graph_outputs
+        .iter()
+        .any(|output| intermediates.contains(&output));
+
+    !used_by_other_nodes && !used_as_output
+}
+
+/// Moves the asymmetric padding of the Conv2d nodes into a preceding Pad node.
+///
+/// The explicit padding of burn's convolutions is symmetric, while the ONNX pads
+/// `[top, left, bottom, right]` can differ at the begin and the end of a spatial dimension.
+fn pad_asymmetric_conv2d(nodes: &mut Vec<Node>) {
+    let mut position = 0;
+
+    while position < nodes.len() {
+        let node = &mut nodes[position];
+        position += 1;
+
+        if node.node_type != NodeType::Conv2d {
+            continue;
+        }
+
+        let pads = match node.attrs.get("pads") {
+            Some(AttributeValue::Int64s(pads)) if pads[0] != pads[2] || pads[1] != pads[3] => {
+                pads.clone()
+            }
+            _ => continue,
+        };
+
+        let input = node.inputs[0].clone();
+        let padded = Argument::new(format!("{}_padded", node.name));
+
+        node.inputs[0].name = padded.name.clone();
+        node.attrs
+            .insert("pads".to_string(), AttributeValue::Int64s(vec![0; 4]));
+
+        // The pads of the batch and channel dimensions are zeros
+        let pad_node = Node {
+            node_type: NodeType::Pad,
+            name: format!("{}_pad", node.name),
+            inputs: vec![input],
+            outputs: vec![padded],
+            attrs: [
+                (
+                    "pads".to_string(),
+                    AttributeValue::Int64s(vec![0, 0, pads[0], pads[1], 0, 0, pads[2], pads[3]]),
+                ),
+                ("value".to_string(), AttributeValue::Float32(0.0)),
+            ]
+            .into(),
+            opset_version: node.opset_version,
+        };
+
+        nodes.insert(position - 1, pad_node);
+        position += 1;
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet};
+
+use half::f16;
+
+use super::from_onnx::convert_constant_value;
+use super::ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, Tensor};
+use super::op_configuration::cast_config;
+
+/// The function folds the nodes whose inputs are all constants into a single constant node, so
+/// the generated model doesn't compute them at runtime.
+///
+/// The graph outputs are required to keep the constants returned by the model.
+pub fn fold_constants(nodes: &mut Vec<Node>, graph_outputs: &[String]) {
+    log::info!("Folding constants");
+
+    let mut constants = nodes
+        .iter()
+        .filter(|node| node.node_type == NodeType::Constant)
+        .map(|node| (node.outputs[0].name.clone(), node.clone()))
+        .collect::<HashMap<String, Node>>();
+
+    let mut folded = HashSet::<String>::new();
+
+    for node in nodes.iter_mut() {
+        let folded_value = match node.node_type {
+            NodeType::Cast => fold_cast(node, &constants),
+            NodeType::Add => fold_binary(node, &constants, BinaryOp::Add),
+            NodeType::Sub => fold_binary(node, &constants, BinaryOp::Sub),
+            NodeType::Mul => fold_binary(node, &constants, BinaryOp::Mul),
+            NodeType::Div => fold_binary(node, &constants, BinaryOp::Div),
+            _ => None,
+        };
+
+        if let Some(value) = folded_value {
+            log::debug!(
+                "Folding {:?} node {} into a constant",
+                node.node_type,
+                node.name
+            );
+
+            folded.extend(node.inputs.iter().map(|input| input.name.clone()));
+            node.node_type = NodeType::Constant;
+            node.inputs.clear();
+            node.attrs = [("value".to_string(), AttributeValue::Tensor(value))].into();
+
+            constants.insert(node.outputs[0].name.clone(), node.clone());
+        }
+    }
+
+    // Remove the folded constants that are neither used by other nodes nor returned by the model
+    let used = nodes
+        .iter()
+        .flat_map(|node| node.inputs.iter().map(|input| input.name.clone()))
+        .chain(graph_outputs.iter().cloned())
+        .collect::<HashSet<String>>();
+
+    nodes.retain(|node| {
+        node.node_type != NodeType::Constant
+            || !folded.contains(&node.outputs[0].name)
+            || used.contains(&node.outputs[0].name)
+    });
+}
+
+/// Returns the value of an input known at import time, either the output of a constant node or
+/// an initializer moved to the input value.
+fn constant_input(input: &Argument, constants: &HashMap<String, Node>) -> Option<Argument> {
+    match constants.get(&input.name) {
+        Some(constant) => Some(convert_constant_value(constant)),
+        None if input.value.is_some() => Some(input.clone()),
+        None => None,
+    }
+}
+
+/// Casts the value of a constant input at import time.
+fn fold_cast(node: &Node, constants: &HashMap<String, Node>) -> Option<Tensor> {
+    let argument = constant_input(&node.inputs[0], constants)?;
+    let elem_type = cast_config(node);
+
+    let (dim, shape) = match argument.ty {
+        ArgType::Tensor(tensor) => (tensor.dim, tensor.shape),
+        ArgType::Scalar(_) => (0, Some(vec![])),
+        ArgType::Shape(_) => return None,
+    };
+    let data = cast_data(argument.value?, &elem_type)?;
+
+    Some(Tensor {
+        elem_type,
+        dim,
+        data: Some(data),
+        shape,
+    })
+}
+
+/// The element-wise arithmetic operators that can be folded.
+#[derive(Debug, Clone, Copy)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinaryOp {
+    /// The float values are computed in double precision, which rounds to the same result as the
+    /// single and half precision operators.
+    fn floats(self, lhs: f64, rhs: f64) -> f64 {
+        match self {
+            Self::Add => lhs + rhs,
+            Self::Sub => lhs - rhs,
+            Self::Mul => lhs * rhs,
+            Self::Div => lhs / rhs,
+        }
+    }
+
+    /// The integer division truncates toward zero, and the division by zero isn't folded.
+    fn ints(self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            Self::Add => Some(lhs.wrapping_add(rhs)),
+            Self::Sub => Some(lhs.wrapping_sub(rhs)),
+            Self::Mul => Some(lhs.wrapping_mul(rhs)),
+            Self::Div => lhs.checked_div(rhs),
+        }
+    }
+}
+
+/// Computes an element-wise arithmetic operator between two constant inputs at import time, with
+/// the multidirectional broadcasting of ONNX.
+fn fold_binary(node: &Node, constants: &HashMap<String, Node>, op: BinaryOp) -> Option<Tensor> {
+    let lhs = constant_input(&node.inputs[0], constants)?;
+    let rhs = constant_input(&node.inputs[1], constants)?;
+
+    let (elem_type, lhs_shape) = constant_shape(&lhs.ty)?;
+    let (rhs_elem_type, rhs_shape) = constant_shape(&rhs.ty)?;
+    if elem_type != rhs_elem_type {
+        return None;
+    }
+
+    let shape = broadcast_shape(&lhs_shape, &rhs_shape)?;
+    let lhs_indices = broadcast_indices(&lhs_shape, &shape);
+    let rhs_indices = broadcast_indices(&rhs_shape, &shape);
+    let pairs = lhs_indices.into_iter().zip(rhs_indices);
+
+    let (lhs, _) = into_values(lhs.value?)?;
+    let (rhs, _) = into_values(rhs.value?)?;
+
+    let data = match (lhs, rhs) {
+        (Values::Floats(lhs), Values::Floats(rhs)) => Data::Float64s(
+            pairs
+                .map(|(i, j)| op.floats(lhs[i], rhs[j]))
+                .collect::<Vec<_>>(),
+        ),
+        (Values::Ints(lhs), Values::Ints(rhs)) => Data::Int64s(
+            pairs
+                .map(|(i, j)| op.ints(lhs[i], rhs[j]))
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        _ => return None,
+    };
+    let data = cast_data(data, &elem_type)?;
+
+    Some(Tensor {
+        elem_type,
+        dim: shape.len(),
+        data: Some(if shape.is_empty() {
+            data.into_scalar()
+        } else {
+            data
+        }),
+        shape: Some(shape),
+    })
+}
+
+/// The element type and static shape of a constant, where the scalars have an empty shape.
+fn constant_shape(ty: &ArgType) -> Option<(ElementType, Vec<usize>)> {
+    match ty {
+        ArgType::Tensor(tensor) => Some((tensor.elem_type.clone(), tensor.shape.clone()?)),
+        ArgType::Scalar(elem_type) => Some((elem_type.clone(), vec![])),
+        ArgType::Shape(_) => None,
+    }
+}
+
+/// The shape of the result of a broadcast operation, or `None` if the shapes are incompatible.
+fn broadcast_shape(lhs: &[usize], rhs: &[usize]) -> Option<Vec<usize>> {
+    let rank = lhs.len().max(rhs.len());
+    let size = |shape: &[usize], axis: usize| {
+        (axis + shape.len())
+            .checked_sub(rank)
+            .map_or(1, |axis| shape[axis])
+    };
+
+    (0..rank)
+        .map(|axis| match (size(lhs, axis), size(rhs, axis)) {
+            (lhs, rhs) if lhs == rhs || rhs == 1 => Some(lhs),
+            (1, rhs) => Some(rhs),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The position in the row-major values of `shape` of each element of the broadcast `output`.
+fn broadcast_indices(shape: &[usize], output: &[usize]) -> Vec<usize> {
+    let offset = output.len() - shape.len();
+
+    // The strides of the broadcast dimensions are zero
+    let mut strides = vec![0; output.len()];
+    let mut stride = 1;
+    for (axis, &size) in shape.iter().enumerate().rev() {
+        if size != 1 {
+            strides[axis + offset] = stride;
+        }
+        stride *= size;
+    }
+
+    let num_elements = output.iter().product::<usize>();
+    (0..num_elements)
+        .map(|mut position| {
+            let mut index = 0;
+            for (&size, &stride) in output.iter().zip(strides.iter()).rev() {
+                index += (position % size) * stride;
+                position /= size;
+            }
+            index
+        })
+        .collect()
+}
+
+/// The values of a constant, grouped by the kind of their element type.
+enum Values {
+    Floats(Vec<f64>),
+    Ints(Vec<i64>),
+    Bools(Vec<bool>),
+}
+
+/// Groups the data by the kind of its element type, and returns whether it is a scalar. Returns
+/// `None` for string data.
+fn into_values(data: Data) -> Option<(Values, bool)> {
+    let values = match data {
+        Data::Float16(value) => (Values::Floats(vec![value.to_f64()]), true),
+        Data::Float32(value) => (Values::Floats(vec![value as f64]), true),
+        Data::Float64(value) => (Values::Floats(vec![value]), true),
+        Data::Int32(value) => (Values::Ints(vec![value as i64]), true),
+        Data::Int64(value) => (Values::Ints(vec![value]), true),
+        Data::Bool(value) => (Values::Bools(vec![value]), true),
+        Data::Float16s(values) => (
+            Values::Floats(values.into_iter().map(f16::to_f64).collect()),
+            false,
+        ),
+        Data::Float32s(values) => (
+            Values::Floats(values.into_iter().map(|v| v as f64).collect()),
+            false,
+        ),
+        Data::Float64s(values) => (Values::Floats(values), false),
+        Data::Int32s(values) => (
+            Values::Ints(values.into_iter().map(|v| v as i64).collect()),
+            false,
+        ),
+        Data::Int64s(values) => (Values::Ints(values), false),
+        Data::Bools(values) => (Values::Bools(values), false),
+        Data::String(_) | Data::Strings(_) => return None,
+    };
+
+    Some(values)
+}
+
+/// Converts the data to the given element type with the ONNX `Cast` semantics, where floats are
+/// truncated toward zero when converted to integers. Returns `None` for string data.
+fn cast_data(data: Data, elem_type: &ElementType) -> Option<Data> {
+    let (values, scalar) = into_values(data)?;
+
+    let floats = || match &values {
+        Values::Floats(values) => values.clone(),
+        Values::Ints(values) => values.iter().map(|&v| v as f64).collect(),
+        Values::Bools(values) => values.iter().map(|&v| v as i64 as f64).collect(),
+    };
+    let ints = || match &values {
+        Values::Floats(values) => values.iter().map(|&v| v as i64).collect(),
+        Values::Ints(values) => values.clone(),
+        Values::Bools(values) => values.iter().map(|&v| v as i64).collect::<Vec<_>>(),
+    };
+
+    let data = match elem_type {
+        ElementType::Float16 => Data::Float16s(floats().into_iter().map(f16::from_f64).collect()),
+        ElementType::Float32 => Data::Float32s(floats().into_iter().map(|v| v as f32).collect()),
+        ElementType::Float64 => Data::Float64s(floats()),
+        ElementType::Int32 => Data::Int32s(ints().into_iter().map(|v| v as i32).collect()),
+        ElementType::Int64 => Data::Int64s(ints()),
+        ElementType::Bool => Data::Bools(match &values {
+            Values::Floats(values) => values.iter().map(|&v| v != 0.0).collect(),
+            Values::Ints(values) => values.iter().map(|&v| v != 0).collect(),
+            Values::Bools(values) => values.clone(),
+        }),
+        ElementType::String => return None,
+    };
+
+    Some(if scalar { data.into_scalar() } else { data })
+}
@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
-use protobuf::Enum;
-
 use super::{
     ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, TensorType},
-    op_configuration::flatten_config,
-    protos::tensor_proto::DataType,
+    op_configuration::{
+        cast_config, concat_config, constant_of_shape_config, flatten_config, gather_config,
+        glu_config, pad_config, reduce_config, resize_config, shape_config, split_config,
+        squeeze_config, unsqueeze_config,
+    },
 };
 
 struct TensorDimUpdater {
@@ -45,6 +46,11 @@ impl TensorDimUpdater {
             .filter_map(|input| self.arguments.get(&input.name).map(|arg| (arg, input)))
             .map(|(arg, input)| {
                 input.ty = arg.ty.clone();
+
+                // Propagate the values known at import time, e.g. the static shapes
+                if arg.value.is_some() {
+                    input.value = arg.value.clone();
+                }
             })
             .count()
     }
@@ -62,44 +68,65 @@ pub fn dim_inference(
         updater.update_tensor_inputs(node);
 
         match node.node_type {
-            NodeType::Add => same_as_input(node),
-            NodeType::AveragePool2d => same_as_input(node),
+            NodeType::Add => elementwise_update_outputs(node),
+            NodeType::And => elementwise_update_outputs(node),
+            NodeType::AveragePool1d => same_rank_as_input(node),
+            NodeType::AveragePool2d => same_rank_as_input(node),
             NodeType::BatchNormalization => same_as_input(node),
             NodeType::Cast => cast_update_outputs(node),
+            NodeType::Ceil => same_as_input(node),
             NodeType::Clip => same_as_input(node),
             NodeType::Concat => concat_update_outputs(node),
             NodeType::Constant => constant_update_outputs(node),
-            NodeType::Conv1d => conv1d_update_outputs(node),
-            NodeType::Conv2d => conv2d_update_outputs(node),
+            NodeType::ConstantOfShape => constant_of_shape_update_outputs(node),
+            NodeType::Conv1d => same_rank_as_input(node),
+            NodeType::Conv2d => same_rank_as_input(node),
             NodeType::Cos => same_as_input(node),
-            NodeType::Div => same_as_input(node),
+            NodeType::Div => elementwise_update_outputs(node),
             NodeType::Dropout => same_as_input(node),
             NodeType::Equal => equal_update_outputs(node),
             NodeType::Erf => same_as_input(node),
             NodeType::Exp => same_as_input(node),
             NodeType::Flatten => flatten_update_outputs(node),
+            NodeType::Floor => same_as_input(node),
             NodeType::Gelu => same_as_input(node),
-            NodeType::GatherElements => same_as_input(node),
-            NodeType::GlobalAveragePool => same_as_input(node),
-            NodeType::ConvTranspose2d => conv_transpose2d_update_outputs(node),
+            NodeType::Gather => gather_update_outputs(node),
+            NodeType::GatherElements => gather_elements_update_outputs(node),
+            NodeType::GatherND => gather_nd_update_outputs(node),
+            NodeType::GlobalAveragePool => same_rank_as_input(node),
+            NodeType::GlobalMaxPool => same_rank_as_input(node),
+            NodeType::Glu => glu_update_outputs(node),
+            NodeType::ConvTranspose2d => same_rank_as_input(node),
             NodeType::Linear => linear_update_outputs(node),
             NodeType::Log => same_as_input(node),
+            NodeType::LRN => same_as_input(node),
             NodeType::LogSoftmax => same_as_input(node),
-            NodeType::MaxPool2d => same_as_input(node),
-            NodeType::Mul => same_as_input(node),
+            NodeType::MaxPool2d => same_rank_as_input(node),
+            NodeType::Mul => elementwise_update_outputs(node),
             NodeType::Neg => same_as_input(node),
+            NodeType::Not => same_as_input(node),
+            NodeType::Or => elementwise_update_outputs(node),
+            NodeType::Pad => pad_update_outputs(node),
             NodeType::Reciprocal => same_as_input(node),
-            NodeType::ReduceMean => mean_update_outputs(node),
+            NodeType::ReduceMean => reduce_update_outputs(node),
+            NodeType::ReduceSum => reduce_update_outputs(node),
             NodeType::Relu => same_as_input(node),
+            NodeType::LeakyRelu => same_as_input(node),
             NodeType::Reshape => reshape_update_outputs(node),
+            NodeType::Resize => resize_update_outputs(node),
+            NodeType::Round => same_as_input(node),
+            NodeType::ScatterND => same_as_input(node),
             NodeType::Shape => shape_update_outputs(node),
             NodeType::Sigmoid => same_as_input(node),
             NodeType::Softmax => same_as_input(node),
+            NodeType::Split => split_update_outputs(node),
             NodeType::Sqrt => same_as_input(node),
-            NodeType::Sub => same_as_input(node),
+            NodeType::Sub => elementwise_update_outputs(node),
             NodeType::Tanh => same_as_input(node),
-            NodeType::Transpose => same_as_input(node),
+            NodeType::Transpose => same_rank_as_input(node),
+            NodeType::Squeeze => squeeze_update_outputs(node),
             NodeType::Unsqueeze => unsqueeze_update_outputs(node),
+            NodeType::Xor => elementwise_update_outputs(node),
             // Intentionally letting outputs leave unchanged but issue a warning so IR file can be generated.
             _ => temporary_pass_through_stub(node),
         }
@@ -158,66 +185,148 @@ fn linear_update_outputs(node: &mut Node) {
     let node_input = &node.inputs[0];
     let weight = &node.inputs[1];
 
-    // Calculate the output shape. Usually we do not use shapes, but since the input shape is
-    // known, we can calculate the output shape.
+    // The last dimension of the output is the number of output features, when the input shape
+    // is known
     if let ArgType::Tensor(tensor) = node_input.clone().ty {
-        let mut tensor = tensor.clone();
-        let mut shape = tensor.shape.clone().unwrap();
-
-        if let ArgType::Tensor(weight_tensor) = weight.clone().ty {
-            let last = shape.last_mut().unwrap();
-            *last = *weight_tensor.shape.unwrap().first().unwrap();
-        } else {
-            panic!("Weight must be a tensor");
-        }
-
-        tensor.shape = Some(shape);
+        let out_features = match weight.clone().ty {
+            ArgType::Tensor(weight_tensor) => *weight_tensor.shape.unwrap().first().unwrap(),
+            _ => panic!("Weight must be a tensor"),
+        };
+
+        let shape = tensor.shape.clone().map(|mut shape| {
+            *shape.last_mut().unwrap() = out_features;
+            shape
+        });
 
         // Update the output tensor
-        node.outputs[0].ty = ArgType::Tensor(tensor);
+        node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
     } else {
         panic!("Only tensor input is valid");
     }
 }
 
-/// Update the output type using "to" attribute
-fn cast_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Cast: multiple inputs are not supported");
+/// Infers the output of a ConstantOfShape node, whose shape is the value of its input and whose
+/// type is the type of its fill value.
+fn constant_of_shape_update_outputs(node: &mut Node) {
+    let (shape, value) = constant_of_shape_config(node);
+
+    let elem_type = match value {
+        Data::Bool(_) => ElementType::Bool,
+        Data::Float16(_) => ElementType::Float16,
+        Data::Float32(_) => ElementType::Float32,
+        Data::Float64(_) => ElementType::Float64,
+        Data::Int32(_) => ElementType::Int32,
+        Data::Int64(_) => ElementType::Int64,
+        _ => panic!("ConstantOfShape: the value must be a scalar"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim: shape.len(),
+        shape: Some(shape),
+    });
+}
+
+/// Infer the shape of each output of a split node
+fn split_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Split: only tensor input is valid"),
+    };
+
+    let (axis, split) = split_config(node);
+    let num_outputs = node.outputs.len();
+
+    for (i, output) in node.outputs.iter_mut().enumerate() {
+        let shape = tensor.shape.clone().map(|mut shape| {
+            // `usize::div_ceil` is more recent than the minimum supported Rust version
+            #[allow(clippy::manual_div_ceil)]
+            let even_size = (shape[axis] + num_outputs - 1) / num_outputs;
+
+            // the last output holds the remaining elements
+            shape[axis] = match &split {
+                Some(sizes) => sizes[i],
+                None if i + 1 == num_outputs => shape[axis] - even_size * (num_outputs - 1),
+                None => even_size,
+            };
+            shape
+        });
+
+        output.ty = ArgType::Tensor(TensorType {
+            shape,
+            ..tensor.clone()
+        });
     }
-    let output = &mut node.outputs[0];
+}
 
-    // Extract cast type and update the output tensor
-    let elem_type = match node.attrs.get("to") {
-        Some(value) => match &value {
-            AttributeValue::Int64(type_id) => match DataType::from_i32(*type_id as i32).unwrap() {
-                DataType::FLOAT => ElementType::Float32,
-                DataType::INT32 => ElementType::Int32,
-                DataType::INT64 => ElementType::Int64,
-                DataType::DOUBLE => ElementType::Float64,
-                _ => panic!("Cast: unsupported type"),
-            },
-            _ => panic!("'to' attribute must be an Int64"),
-        },
-        None => panic!("Constant node must have a value attribute"),
+/// Infer the shape of the output of a glu node, which halves the size of the split axis
+fn glu_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Glu: only tensor input is valid"),
     };
 
-    match output.ty.clone() {
-        ArgType::Tensor(tensor) => {
-            if tensor.dim == 0 {
-                // treat 0-dim tensor as scalar
-                output.ty = ArgType::Scalar(elem_type);
-            } else {
-                todo!("Cast: support casting from different tensor types");
-            }
-        }
-        ArgType::Scalar(_scalar) => {
-            output.ty = ArgType::Scalar(elem_type);
+    let axis = glu_config(node);
+    let shape = tensor.shape.clone().map(|mut shape| {
+        shape[axis] /= 2;
+        shape
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
+}
+
+/// Infer the shape of the output of a pad node, which grows each dimension by its padding
+fn pad_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Pad: only tensor input is valid"),
+    };
+
+    let (padding, _) = pad_config(node);
+    let shape = tensor.shape.clone().map(|mut shape| {
+        for (size, (before, after)) in shape.iter_mut().zip(padding) {
+            *size += before + after;
         }
-        _ => panic!("Cast: only scalar input is valid"),
-    }
+        shape
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
 }
 
+/// Infer the shape of the output of a resize node, where only the spatial dimensions change
+fn resize_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Resize: only tensor input is valid"),
+    };
+
+    let (output_size, _) = resize_config(node);
+    let shape = tensor
+        .shape
+        .clone()
+        .map(|shape| vec![shape[0], shape[1], output_size[0], output_size[1]]);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
+}
+
+/// Update the output type using "to" attribute
+fn cast_update_outputs(node: &mut Node) {
+    let elem_type = cast_config(node);
+
+    node.outputs[0].ty = match node.inputs[0].ty.clone() {
+        // treat 0-dim tensor as scalar
+        ArgType::Tensor(tensor) if tensor.dim == 0 => ArgType::Scalar(elem_type),
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorType {
+            elem_type,
+            ..tensor
+        }),
+        ArgType::Scalar(_) => ArgType::Scalar(elem_type),
+        ArgType::Shape(_) => panic!("Cast: shape input is not supported"),
+    };
+}
+
+/// Infers the output of a concat node, whose static shape is known when the shapes of all the
+/// inputs are.
 fn concat_update_outputs(node: &mut Node) {
     let tensor = node
         .inputs
@@ -228,7 +337,26 @@ fn concat_update_outputs(node: &mut Node) {
         })
         .unwrap();
 
-    node.outputs[0].ty = ArgType::Tensor(tensor.clone());
+    let axis = concat_config(node);
+    let shape = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.shape.clone(),
+            _ => None,
+        })
+        .reduce(|lhs, rhs| {
+            lhs.zip(rhs).map(|(mut lhs, rhs)| {
+                lhs[axis] += rhs[axis];
+                lhs
+            })
+        })
+        .flatten();
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        shape,
+        ..tensor.clone()
+    });
 }
 
 fn reshape_update_outputs(node: &mut Node) {
@@ -242,79 +370,252 @@ fn reshape_update_outputs(node: &mut Node) {
 
     // The output dimension is the same as the shape length
     let dim = shape.len();
-    let elem_type = match node.inputs[0].ty.clone() {
-        ArgType::Tensor(tensor) => tensor.elem_type,
+    let tensor = match node.inputs[0].ty.clone() {
+        ArgType::Tensor(tensor) => tensor,
         _ => panic!("Reshape: invalid input type"),
     };
 
-    let shape = shape.iter().map(|&dim| dim as usize).collect();
+    let shape = reshape_static_shape(shape, tensor.shape.as_deref());
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
-        elem_type,
+        elem_type: tensor.elem_type,
         dim,
-        shape: Some(shape),
+        shape,
     });
 }
 
-fn mean_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Mean: multiple inputs are not supported");
+/// Resolves the copied (`0`) and inferred (`-1`) sizes of a reshape, which is only possible when
+/// the shape of the input is known.
+fn reshape_static_shape(shape: &[i64], input_shape: Option<&[usize]>) -> Option<Vec<usize>> {
+    let mut inferred = None;
+    let mut output_shape = Vec::with_capacity(shape.len());
+
+    for (i, &size) in shape.iter().enumerate() {
+        match size {
+            -1 => {
+                inferred = Some(i);
+                output_shape.push(1);
+            }
+            0 => output_shape.push(*input_shape?.get(i)?),
+            size => output_shape.push(size as usize),
+        }
     }
 
-    // Extract the configuration of the linear layer (inputs are known)
-    let node_input = &mut node.inputs[0];
-    let tensor = match node_input.clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
-    };
+    if let Some(i) = inferred {
+        // the inferred size is ambiguous when another size is zero
+        let num_elements: usize = input_shape?.iter().product();
+        output_shape[i] = num_elements.checked_div(output_shape.iter().product())?;
+    }
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
+    Some(output_shape)
+}
+
+/// Infers the shape of a ReduceMean or ReduceSum node.
+fn reduce_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("{:?}: only tensor input is valid", node.node_type),
     };
 
-    if dim_only {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
-    } else {
-        node.outputs[0].ty = ArgType::Tensor(TensorType { dim: 1, ..tensor });
+    let (dims, keepdims) = reduce_config(node);
+
+    // the full reduction without kept dimensions produces a tensor with a single element
+    if !keepdims && dims.len() == tensor.dim {
+        node.outputs[0].ty = ArgType::Tensor(TensorType {
+            dim: 1,
+            shape: Some(vec![1]),
+            ..tensor
+        });
+        return;
     }
+
+    let shape = tensor.shape.clone().map(|shape| {
+        shape
+            .into_iter()
+            .enumerate()
+            .filter_map(|(dim, size)| match dims.contains(&dim) {
+                true if keepdims => Some(1),
+                true => None,
+                false => Some(size),
+            })
+            .collect::<Vec<_>>()
+    });
+    let dim = match keepdims {
+        true => tensor.dim,
+        false => tensor.dim - dims.len(),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim,
+        shape,
+        ..tensor
+    });
+}
+
+/// Infers the shape of a Squeeze node, which removes the squeezed dimensions.
+fn squeeze_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Squeeze: only tensor input is valid"),
+    };
+
+    let dims = squeeze_config(node);
+    let shape = tensor.shape.clone().map(|shape| {
+        shape
+            .into_iter()
+            .enumerate()
+            .filter(|(dim, _)| !dims.contains(dim))
+            .map(|(_, size)| size)
+            .collect()
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: tensor.dim - dims.len(),
+        shape,
+        ..tensor
+    });
 }
-/// Infers the shape of a Unsqueeze node and replaces the shape of the output tensor.
-///
-/// # Remarks
-///
-/// Unsqueeze is not implemented fully. This is left WIP from the past.
+
+/// Infers the shape of a Unsqueeze node, which inserts dimensions of size 1.
 fn unsqueeze_update_outputs(node: &mut Node) {
-    let node_input = node
-        .inputs
-        .first_mut()
-        .expect("Unsqueeze: an input is required");
-
-    if let ArgType::Tensor(tensor) = &mut node_input.ty {
-        tensor.dim += 1;
-
-        // add a new dimension to the input tensor by extending the shape
-        // TODO: support unsqueezing configurations
-        if let Some(shape) = &mut tensor.shape {
-            shape.insert(0, 1);
-        } else {
-            todo!("Unsqueeze: support unsqueezing a tensor without shape");
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Unsqueeze: only tensor input is valid"),
+    };
+
+    let dims = unsqueeze_config(node);
+    let shape = tensor.shape.clone().map(|mut shape| {
+        for &dim in dims.iter() {
+            shape.insert(dim, 1);
         }
+        shape
+    });
 
-        node.outputs[0].ty = ArgType::Tensor(tensor.clone());
-    } else {
-        panic!("Only tensor input is valid");
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: tensor.dim + dims.len(),
+        shape,
+        ..tensor
+    });
+}
+
+/// Infers the shape of a Gather node, where the gathered dimension of the input is replaced by
+/// the dimensions of the indices.
+fn gather_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Gather: only tensor input is valid"),
+    };
+
+    let (indices_dim, indices_shape) = match &node.inputs[1].ty {
+        ArgType::Tensor(indices) => (indices.dim, indices.shape.clone()),
+        ArgType::Scalar(_) => (0, Some(vec![])),
+        _ => panic!("Gather: only tensor or scalar indices are valid"),
+    };
+
+    let dim = gather_config(node);
+    let shape = match (tensor.shape.clone(), indices_shape) {
+        (Some(shape), Some(indices_shape)) => {
+            Some([&shape[..dim], &indices_shape[..], &shape[dim + 1..]].concat())
+        }
+        _ => None,
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: tensor.dim + indices_dim - 1,
+        shape,
+        ..tensor
+    });
+}
+
+/// Infers the rank of a GatherND node, which is the rank of the indices without the coordinates.
+fn gather_nd_update_outputs(node: &mut Node) {
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    let indices_dim = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("GatherND: only tensor indices are valid"),
+    };
+
+    if indices_dim < 1 {
+        panic!("GatherND: indices of rank {indices_dim} are not supported");
+    }
+
+    // a single coordinate gathers one element, which is kept as a tensor with a single element
+    if indices_dim == 1 {
+        node.outputs[0].ty = ArgType::Tensor(TensorType {
+            elem_type,
+            dim: 1,
+            shape: Some(vec![1]),
+        });
+        return;
     }
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim: indices_dim - 1,
+        shape: None,
+    });
+}
+
+/// Infers the output of a GatherElements node, which has the type of the data and the shape of
+/// the indices.
+fn gather_elements_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("{:?}: only tensor input is valid", node.node_type),
+    };
+
+    let shape = match &node.inputs[1].ty {
+        ArgType::Tensor(indices) => indices.shape.clone(),
+        _ => panic!("{:?}: only tensor indices are valid", node.node_type),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
 }
 
 fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
 
+/// Infers the output of a node with the rank and type of its input, but whose static shape isn't
+/// inferred because it depends on the configuration of the node.
+fn same_rank_as_input(node: &mut Node) {
+    node.outputs[0].ty = match node.inputs[0].ty.clone() {
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorType {
+            shape: None,
+            ..tensor
+        }),
+        _ => panic!("{:?}: only tensor input is valid", node.node_type),
+    };
+}
+
+/// Infers the output of an element-wise binary node, whose static shape is the broadcasted shape
+/// of the tensor inputs when they are all known.
+fn elementwise_update_outputs(node: &mut Node) {
+    same_as_input(node);
+
+    if let ArgType::Tensor(tensor) = &mut node.outputs[0].ty {
+        let shapes = node.inputs.iter().filter_map(|input| match &input.ty {
+            ArgType::Tensor(input) => Some(input.shape.clone().filter(|_| input.dim == tensor.dim)),
+            _ => None,
+        });
+
+        tensor.shape = shapes
+            .reduce(|lhs, rhs| {
+                lhs.zip(rhs).map(|(lhs, rhs)| {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(&lhs, &rhs)| lhs.max(rhs))
+                        .collect()
+                })
+            })
+            .flatten();
+    }
+}
+
 /// Temporary pass-through stub for dimension inference so that we can export the IR model.
 fn temporary_pass_through_stub(node: &Node) {
     log::warn!(
@@ -327,12 +628,14 @@ fn equal_update_outputs(node: &mut Node) {
     let input1_type = node.inputs[0].ty.clone();
 
     match input1_type {
-        ArgType::Tensor(tensor) => {
-            // if the input is a tensor, the output is a tensor of bool
-            node.outputs[0].ty = ArgType::Tensor(TensorType {
-                elem_type: ElementType::Bool,
-                ..tensor
-            });
+        ArgType::Tensor(_) => {
+            // if the input is a tensor, the output is a tensor of bool with the broadcasted shape
+            // of the inputs
+            elementwise_update_outputs(node);
+
+            if let ArgType::Tensor(tensor) = &mut node.outputs[0].ty {
+                tensor.elem_type = ElementType::Bool;
+            }
         }
         ArgType::Scalar(_) => {
             node.outputs[0].ty = ArgType::Scalar(ElementType::Bool);
@@ -342,17 +645,26 @@ fn equal_update_outputs(node: &mut Node) {
 }
 
 fn shape_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Gather: multiple inputs are not supported: {:?}", node);
-    }
+    let (start, end) = shape_config(node);
 
-    // Extract the configuration of the linear layer (inputs are known)
-    let node_input = &mut node.inputs[0];
-    if let ArgType::Tensor(tensor) = node_input.clone().ty {
-        // Update the output tensor
-        node.outputs[0].ty = ArgType::Shape(tensor.dim);
-    } else {
-        panic!("Only tensor input is valid");
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Shape: only tensor input is valid"),
+    };
+
+    match tensor.shape {
+        // The shape is known at import time, so the output is a constant int64 tensor
+        Some(shape) => {
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                elem_type: ElementType::Int64,
+                dim: 1,
+                shape: Some(vec![end - start]),
+            });
+            node.outputs[0].value = Some(Data::Int64s(
+                shape[start..end].iter().map(|&size| size as i64).collect(),
+            ));
+        }
+        None => node.outputs[0].ty = ArgType::Shape(end - start),
     }
 }
 
@@ -377,38 +689,48 @@ fn flatten_update_outputs(node: &mut Node) {
     let collapsed_dims = end_dim - start_dim;
     let output_dim = input_dim - collapsed_dims;
 
+    // the collapsed dimensions are replaced by their product
+    let shape = tensor.shape.clone().map(|shape| {
+        let collapsed = shape[start_dim..=end_dim].iter().product();
+
+        [&shape[..start_dim], &[collapsed], &shape[end_dim + 1..]].concat()
+    });
+
     node.outputs[0].ty = ArgType::Tensor(TensorType {
         dim: output_dim,
+        shape,
         ..tensor.clone()
     });
 }
 
-/// Infers the shape of a Conv1d node and replaces the shape of the output tensor.
-fn conv1d_update_outputs(node: &mut Node) {
-    // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
-    if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
-    } else {
-        panic!("Only tensor input is valid");
-    }
-}
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
 
-/// Infers the shape of a Conv2d node and replaces the shape of the output tensor.
-fn conv2d_update_outputs(node: &mut Node) {
-    // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
-    if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
-    } else {
-        panic!("Only tensor input is valid");
-    }
-}
+    use super::*;
+    use crate::onnx::from_onnx::parse_onnx;
 
-/// Infers the shape of a ConvTranspose2d node and replaces the shape of the output tensor.
-fn conv_transpose2d_update_outputs(node: &mut Node) {
-    // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
-    if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
-    } else {
-        panic!("Only tensor input is valid");
+    #[test]
+    fn split_gives_the_remaining_size_to_the_last_output() {
+        let graph = parse_onnx(Path::new("onnx-tests/tests/split/split.onnx"));
+        let split = graph
+            .nodes
+            .iter()
+            .find(|node| node.node_type == NodeType::Split)
+            .unwrap();
+
+        let shapes: Vec<_> = split
+            .outputs
+            .iter()
+            .map(|output| match &output.ty {
+                ArgType::Tensor(tensor) => tensor.shape.clone(),
+                ty => panic!("Expected a tensor output, got {ty:?}"),
+            })
+            .collect();
+
+        assert_eq!(
+            shapes,
+            vec![Some(vec![2, 3]), Some(vec![2, 3]), Some(vec![2, 1])]
+        );
     }
 }
@@ -5,8 +5,8 @@ use std::{
 };
 
 use crate::onnx::{
-    coalesce::coalesce, ir::TensorType, node_remap::remap_node_type,
-    proto_conversion::convert_node_proto,
+    coalesce::coalesce, constant_folding::fold_constants, ir::TensorType,
+    node_remap::remap_node_type, proto_conversion::convert_node_proto,
 };
 
 use super::dim_inference::dim_inference;
@@ -15,13 +15,16 @@ use super::protos::{ModelProto, TensorProto};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 6] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 9] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
+    NodeType::ConstantOfShape,
     NodeType::Conv1d,
     NodeType::Conv2d,
     NodeType::Dropout,
+    NodeType::Pad,
     NodeType::Reshape,
+    NodeType::Resize,
 ];
 
 /// Open an onnx file and convert it to a Graph (intermediate representation)
@@ -57,10 +60,20 @@ pub fn parse_onnx(onnx_path: &Path) -> ONNXGraph {
 
     log::debug!("Number of outputs: {:?}", onnx_model.graph.output.len());
 
+    // The behavior of the operators depends on the version of the default operator set
+    let opset_version = onnx_model
+        .opset_import
+        .iter()
+        .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+        .map(|opset| opset.version as usize)
+        .expect("The model must import the default ONNX operator set");
+
+    log::debug!("Opset version: {:?}", opset_version);
+
     // Convert the nodes
     let mut nodes: Vec<Node> = vec![];
     for onnx_node in onnx_model.graph.node.iter() {
-        let mut node = convert_node_proto(onnx_node);
+        let mut node = convert_node_proto(onnx_node, opset_version);
         remap_node_type(&mut node);
         nodes.push(node);
     }
@@ -72,14 +85,27 @@ pub fn parse_onnx(onnx_path: &Path) -> ONNXGraph {
     // Move inputs with initializers to states
     move_inputs_to_state(&mut nodes, &onnx_model.graph.initializer);
 
+    let graph_outputs: Vec<String> = onnx_model
+        .graph
+        .output
+        .iter()
+        .map(|output| output.name.clone())
+        .collect();
+
     // Handle Identity nodes (expects inputs to be moved to states)
-    handle_identity(&mut nodes);
+    handle_identity(&mut nodes, &graph_outputs);
+
+    // Fold the nodes computed from constants only into constants
+    fold_constants(&mut nodes, &graph_outputs);
 
     // Lift constants to initializers (expects inputs to be moved to states)
     lift_constants(&mut nodes);
 
     // Coalesce and transform nodes
-    coalesce(&mut nodes);
+    coalesce(&mut nodes, &graph_outputs);
+
+    // Remove the nodes that don't contribute to the graph outputs
+    remove_dead_nodes(&mut nodes, &graph_outputs);
 
     // Rename nodes and inputs, save the mapping for later
     let old_node_names = rename_nodes(&mut nodes);
@@ -105,6 +131,9 @@ pub fn parse_onnx(onnx_path: &Path) -> ONNXGraph {
     // Infer shapes and update the inputs and outputs
     dim_inference(&mut nodes, &inputs, &mut outputs);
 
+    // Remove the Shape nodes that are resolved at import time
+    remove_static_shape_nodes(&mut nodes, &outputs);
+
     // Remove the graph inputs/output that are not used by any node
     remove_unused_graph_inputs(&mut inputs, &mut outputs, &nodes);
 
@@ -116,6 +145,7 @@ pub fn parse_onnx(onnx_path: &Path) -> ONNXGraph {
         outputs,
         old_node_names,
         old_input_names,
+        opset_version,
     }
 }
 
@@ -218,10 +248,16 @@ fn lift_constants(nodes: &mut Vec<Node>) {
             continue;
         }
 
-        // Skip the first input because it is the node's true input and not a constant/state
+        // Skip the first input because it is the node's true input and not a constant/state,
+        // except for the nodes whose only input is a constant
+        let skip = match node.node_type {
+            NodeType::ConstantOfShape => 0,
+            _ => 1,
+        };
+
         node.inputs
             .iter_mut()
-            .skip(1) // TODO make configurable
+            .skip(skip) // TODO make configurable
             .for_each(|input| {
                 if let Some(constant) = constants.get(&input.name) {
                     if !constant.inputs.is_empty() && constant.inputs[0].value.is_some() {
@@ -252,38 +288,164 @@ fn lift_constants(nodes: &mut Vec<Node>) {
     );
 }
 
-fn handle_identity(nodes: &mut Vec<Node>) {
-    log::info!("Handling identity nodes");
+/// Removes the nodes whose outputs don't reach any graph output, e.g. the shape computations of
+/// branches removed by the exporter. They are removed before the dimension inference, so they
+/// don't need to be supported.
+fn remove_dead_nodes(nodes: &mut Vec<Node>, graph_outputs: &[String]) {
+    let mut used: HashSet<&String> = graph_outputs.iter().collect();
+    let mut live = vec![false; nodes.len()];
+
+    // The nodes are topologically sorted, so the consumers are visited before the producers
+    for (position, node) in nodes.iter().enumerate().rev() {
+        if node
+            .outputs
+            .iter()
+            .any(|output| used.contains(&output.name))
+        {
+            live[position] = true;
+            used.extend(node.inputs.iter().map(|input| &input.name));
+        }
+    }
+
+    let mut live = live.into_iter();
+    let num_nodes = nodes.len();
+    nodes.retain(|_| live.next().unwrap());
 
-    let mut nodes_to_remove = HashSet::new();
+    log::debug!(
+        "The number of dead nodes removed: {}",
+        num_nodes - nodes.len()
+    );
+}
 
-    let identity_nodes = nodes
+/// Removes the Shape nodes whose static shape is only read at import time, e.g. as the target
+/// shape of a Reshape node, so the generated model doesn't keep an unused constant.
+fn remove_static_shape_nodes(nodes: &mut Vec<Node>, graph_outputs: &[Argument]) {
+    let read_at_import = |node: &Node, name: &String| {
+        node.inputs.iter().enumerate().all(|(position, input)| {
+            &input.name != name
+                || matches!(
+                    (&node.node_type, position),
+                    (NodeType::Reshape, 1) | (NodeType::ConstantOfShape, 0)
+                )
+        })
+    };
+
+    let static_shapes = nodes
         .iter()
-        .filter(|node| node.node_type == NodeType::Identity)
-        .cloned()
-        .collect::<Vec<Node>>();
+        .filter(|node| node.node_type == NodeType::Shape && node.outputs[0].value.is_some())
+        .map(|node| node.outputs[0].name.clone())
+        .filter(|name| {
+            !graph_outputs.iter().any(|output| &output.name == name)
+                && nodes.iter().all(|node| read_at_import(node, name))
+        })
+        .collect::<HashSet<String>>();
+
+    nodes.retain(|node| {
+        node.node_type != NodeType::Shape || !static_shapes.contains(&node.outputs[0].name)
+    });
+
+    log::debug!(
+        "The number of static shape nodes removed: {}",
+        static_shapes.len()
+    );
+}
+
+fn handle_identity(nodes: &mut Vec<Node>, graph_outputs: &[String]) {
+    log::info!("Handling identity nodes");
+
+    remove_pass_through_nodes(nodes, graph_outputs, NodeType::Identity);
+}
+
+/// Removes the nodes of the given type that return their first input unchanged, and rewires the
+/// consumers of their output to that input.
+///
+/// When the output is a graph output, the node producing the input is renamed instead, so the node
+/// is kept if its input is a graph input, which has no producer, or another graph output. The
+/// nodes whose input is a constant or a state are kept, as well as the nodes with other outputs in
+/// use, e.g. the mask of a Dropout node.
+pub(crate) fn remove_pass_through_nodes(
+    nodes: &mut Vec<Node>,
+    graph_outputs: &[String],
+    node_type: NodeType,
+) {
+    let mut position = 0;
+    let mut num_removed = 0;
 
-    // Handle pass-through nodes.
-    for identity_node in identity_nodes {
-        if identity_node.node_type == NodeType::Identity && identity_node.inputs[0].value.is_none()
+    while position < nodes.len() {
+        if nodes[position].node_type != node_type
+            || !remove_pass_through_node(nodes, graph_outputs, position)
         {
-            let input_name = &identity_node.inputs[0].name;
-            let output_name = &identity_node.outputs[0].name;
-
-            // Replace the identity node's output with its input in the connected nodes.
-            for node in nodes.iter_mut() {
-                if let Some(matched_input) = node.inputs.iter_mut().find(|x| x.name == *output_name)
-                {
-                    matched_input.name = input_name.clone();
-                }
+            position += 1;
+        } else {
+            num_removed += 1;
+        }
+    }
+
+    log::debug!(
+        "The number of {:?} nodes removed: {}",
+        node_type,
+        num_removed
+    );
+}
+
+/// Removes the pass-through node at the given position, if possible.
+fn remove_pass_through_node(
+    nodes: &mut Vec<Node>,
+    graph_outputs: &[String],
+    position: usize,
+) -> bool {
+    let node = &nodes[position];
+    let input = &node.inputs[0];
+
+    if input.value.is_some() {
+        return false;
+    }
+
+    let is_used = |name: &String| {
+        graph_outputs.contains(name)
+            || nodes
+                .iter()
+                .any(|node| node.inputs.iter().any(|input| &input.name == name))
+    };
+    if node.outputs[1..].iter().any(|output| is_used(&output.name)) {
+        return false;
+    }
+
+    let input_name = input.name.clone();
+    let output_name = node.outputs[0].name.clone();
+
+    // The name of a graph output is kept by renaming the producer of the input
+    let (old_name, new_name) = if graph_outputs.contains(&output_name) {
+        let producer = nodes
+            .iter()
+            .position(|node| node.outputs.iter().any(|output| output.name == input_name));
+
+        match producer {
+            Some(producer) if !graph_outputs.contains(&input_name) => {
+                let output = nodes[producer]
+                    .outputs
+                    .iter_mut()
+                    .find(|output| output.name == input_name)
+                    .unwrap();
+                output.name = output_name.clone();
+
+                (input_name, output_name)
             }
+            _ => return false,
+        }
+    } else {
+        (output_name, input_name)
+    };
+
+    nodes.remove(position);
 
-            nodes_to_remove.insert(identity_node);
+    for input in nodes.iter_mut().flat_map(|node| node.inputs.iter_mut()) {
+        if input.name == old_name {
+            input.name = new_name.clone();
         }
     }
 
-    // Remove the identity nodes.
-    nodes.retain(|node| !nodes_to_remove.contains(node));
+    true
 }
 
 /// Rename the nodes in the graph to be unique and return a map of the old names to the new names.
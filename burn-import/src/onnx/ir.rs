@@ -47,7 +47,7 @@ pub enum AttributeValue {
 pub type Attributes = HashMap<String, AttributeValue>;
 
 /// The type of an element.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ElementType {
     Float32,
     Float64,
@@ -144,6 +144,22 @@ pub struct ONNXGraph {
 
     /// The original input names.
     pub old_input_names: HashMap<String, String>,
+
+    /// The version of the default ONNX operator set imported by the model.
+    pub opset_version: usize,
+}
+
+impl ONNXGraph {
+    /// The static shapes of the graph inputs, or `None` for the inputs whose shape isn't known.
+    pub fn input_shapes(&self) -> Vec<Option<Shape>> {
+        self.inputs
+            .iter()
+            .map(|input| match &input.ty {
+                ArgType::Tensor(tensor) => tensor.shape.clone(),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -162,6 +178,9 @@ pub struct Node {
 
     /// The attributes of the node.
     pub attrs: Attributes,
+
+    /// The version of the operator set the node is defined in.
+    pub opset_version: usize,
 }
 
 // Required by topological sort
@@ -271,6 +290,7 @@ pub enum NodeType {
     GlobalAveragePool,
     GlobalLpPool,
     GlobalMaxPool,
+    Glu,
     Greater,
     GreaterOrEqual,
     GridSample,
@@ -1,4 +1,5 @@
 mod coalesce;
+mod constant_folding;
 mod dim_inference;
 mod from_onnx;
 mod ir;
@@ -1,11 +1,14 @@
 use burn::nn::{
     conv::Conv1dConfig,
     conv::{Conv2dConfig, ConvTranspose2dConfig},
-    pool::{AvgPool2dConfig, MaxPool2dConfig},
+    pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool2dConfig},
     BatchNormConfig, DropoutConfig, LinearConfig, PaddingConfig1d, PaddingConfig2d,
 };
+use burn::tensor::ops::{InterpolateMode, InterpolateOptions};
 
-use super::ir::{ArgType, AttributeValue, Data, Node};
+use super::ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, TensorType};
+use super::protos::tensor_proto::DataType;
+use protobuf::Enum;
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -174,6 +177,34 @@ pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
     .with_bias(bias)
 }
 
+/// Create a AvgPool1dConfig from the attributes of the node
+pub fn avg_pool1d_config(curr: &Node) -> AvgPool1dConfig {
+    let mut kernel_shape = Vec::new();
+    let mut strides = vec![1];
+    let mut pads = vec![0, 0];
+    let mut count_include_pad: i64 = 0;
+    let mut ceil_mode: i64 = 0;
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "strides" => strides = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            "count_include_pad" => count_include_pad = value.clone().into_i64(),
+            "ceil_mode" => ceil_mode = value.clone().into_i64(),
+            _ => {}
+        }
+    }
+
+    let padding = padding_config_1d(&pads);
+
+    AvgPool1dConfig::new(kernel_shape[0] as usize)
+        .with_stride(strides[0] as usize)
+        .with_padding(padding)
+        .with_count_include_pad(count_include_pad == 1)
+        .with_ceil_mode(ceil_mode == 1)
+}
+
 /// Create a AvgPool2dConfig from the attributes of the node
 pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
     let mut kernel_shape = Vec::new();
@@ -193,16 +224,13 @@ pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
         }
     }
 
-    if ceil_mode == 1 {
-        panic!("ceil_mode is not supported");
-    }
-
     let padding = padding_config(&pads);
 
     AvgPool2dConfig::new([kernel_shape[0] as usize, kernel_shape[1] as usize])
         .with_strides([strides[0] as usize, strides[1] as usize])
         .with_padding(padding)
         .with_count_include_pad(count_include_pad == 1)
+        .with_ceil_mode(ceil_mode == 1)
 }
 
 /// Create a FlattenConfig from the attributes of the node
@@ -283,6 +311,118 @@ pub fn gather_config(curr: &Node) -> usize {
     dim as usize
 }
 
+/// Create the range of the dimensions returned by a Shape node, from the `start` and `end`
+/// attributes (opset 15).
+pub fn shape_config(curr: &Node) -> (usize, usize) {
+    if curr.inputs.len() != 1 {
+        panic!("Shape: multiple inputs are not supported: {:?}", curr);
+    }
+
+    let rank = match &curr.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => panic!("Shape: only tensor input is valid"),
+    };
+
+    // Default: the whole shape per ONNX spec
+    let mut start: i64 = 0;
+    let mut end: i64 = rank;
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "start" => start = value.clone().into_i64(),
+            "end" => end = value.clone().into_i64(),
+            _ => {}
+        }
+    }
+
+    // Negative values are counted from the end, and the out of range values are clamped
+    let clamp = |axis: i64| {
+        let axis = if axis < 0 { axis + rank } else { axis };
+        axis.clamp(0, rank) as usize
+    };
+    let (start, end) = (clamp(start), clamp(end));
+
+    (start, end.max(start))
+}
+
+/// Create a GatherNdConfig from the attributes of the node
+pub fn gather_nd_config(curr: &Node) -> usize {
+    // Default: 0 per ONNX spec
+    let mut batch_dims: i64 = 0;
+
+    if curr.inputs.len() != 2 {
+        panic!("GatherND: index tensor must be present");
+    }
+
+    for (key, value) in curr.attrs.iter() {
+        if key == "batch_dims" {
+            batch_dims = value.clone().into_i64();
+        }
+    }
+
+    let tensor = match curr.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    // Only full coordinates are supported, so each index must point to a single element
+    if let ArgType::Tensor(TensorType {
+        shape: Some(shape), ..
+    }) = &curr.inputs[1].ty
+    {
+        let coordinate_size = *shape.last().unwrap() as i64;
+
+        if coordinate_size + batch_dims != tensor.dim as i64 {
+            panic!(
+                "GatherND: only indices pointing to single elements are supported, got \
+                 coordinates of size {coordinate_size} with {batch_dims} batch dimensions for \
+                 a tensor of rank {}",
+                tensor.dim
+            );
+        }
+    }
+
+    batch_dims as usize
+}
+
+/// Validate the attributes of the ScatterND node
+pub fn scatter_nd_config(curr: &Node) {
+    if curr.inputs.len() != 3 {
+        panic!("ScatterND: index and updates tensors must be present");
+    }
+
+    for (key, value) in curr.attrs.iter() {
+        if key == "reduction" {
+            let reduction = value.clone().into_string();
+
+            if reduction != "none" {
+                panic!("ScatterND: reduction '{reduction}' is not supported");
+            }
+        }
+    }
+
+    let tensor = match curr.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    // Only full coordinates are supported, so each index must point to a single element
+    if let ArgType::Tensor(TensorType {
+        shape: Some(shape), ..
+    }) = &curr.inputs[1].ty
+    {
+        let coordinate_size = *shape.last().unwrap();
+
+        if coordinate_size != tensor.dim {
+            panic!(
+                "ScatterND: only indices pointing to single elements are supported, got \
+                 coordinates of size {coordinate_size} for a tensor of rank {}",
+                tensor.dim
+            );
+        }
+    }
+}
+
 /// Create a LinearConfig from the attributes of the node
 pub fn linear_config(node: &Node) -> LinearConfig {
     if node.inputs.len() < 2 {
@@ -343,7 +483,7 @@ pub fn dropout_config(node: &Node) -> DropoutConfig {
 
 /// Create log_softmax config from the attributes of the node
 pub fn log_softmax_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
+    // the axis is the last dimension (Default: -1 per ONNX spec since opset 13)
     let mut axis: i64 = -1;
 
     // check if the node has only one input
@@ -378,7 +518,7 @@ pub fn log_softmax_config(node: &Node) -> usize {
 
 /// Create softmax config from the attributes of the node
 pub fn softmax_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
+    // the axis is the last dimension (Default: -1 per ONNX spec since opset 13)
     let mut axis: i64 = -1;
 
     // check if the node has only one input
@@ -411,6 +551,295 @@ pub fn softmax_config(node: &Node) -> usize {
     axis as usize
 }
 
+/// Create local response normalization config from the attributes of the node
+///
+/// Returns the `(size, alpha, beta, bias)` parameters.
+pub fn local_response_norm_config(node: &Node) -> (usize, f64, f64, f64) {
+    let mut size: Option<i64> = None;
+
+    // Defaults per ONNX spec
+    let mut alpha: f32 = 0.0001;
+    let mut beta: f32 = 0.75;
+    let mut bias: f32 = 1.0;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "size" => size = Some(value.clone().into_i64()),
+            "alpha" => alpha = value.clone().into_f32(),
+            "beta" => beta = value.clone().into_f32(),
+            "bias" => bias = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    let size = size.expect("LRN: size attribute is required");
+
+    (size as usize, alpha as f64, beta as f64, bias as f64)
+}
+
+/// Create leaky relu config from the attributes of the node
+///
+/// Returns the negative slope (`alpha`).
+pub fn leaky_relu_config(node: &Node) -> f64 {
+    // Default per ONNX spec
+    let mut alpha: f32 = 0.01;
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "alpha" {
+            alpha = value.clone().into_f32();
+        }
+    }
+
+    alpha as f64
+}
+
+/// Create split config from the attributes and inputs of the node
+///
+/// Returns the split axis and the size of each output when they are provided.
+pub fn split_config(node: &Node) -> (usize, Option<Vec<usize>>) {
+    // the axis is the first dimension (Default: 0 per ONNX spec)
+    let mut axis: i64 = 0;
+    let mut split: Option<Vec<i64>> = None;
+
+    // extract the shape of the input tensor
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            // Before opset 13, the sizes of the outputs are provided as an attribute
+            "split" if node.opset_version < 13 => split = Some(value.clone().into_i64s()),
+            _ => {}
+        }
+    }
+
+    // Since opset 13, the sizes of the outputs are provided as an optional input, which has no
+    // value and isn't passed from another node when it is omitted
+    if let Some(input) = node
+        .inputs
+        .get(1)
+        .filter(|input| node.opset_version >= 13 && (input.value.is_some() || input.passed))
+    {
+        match &input.value {
+            Some(Data::Int64s(sizes)) => split = Some(sizes.clone()),
+            Some(_) => panic!("Split: the split sizes must be int64"),
+            None => panic!("Split: the split sizes must be a constant"),
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    let split = split.map(|sizes| sizes.into_iter().map(|size| size as usize).collect());
+
+    (axis as usize, split)
+}
+
+/// Read the `axes` of a squeeze, unsqueeze or reduce node.
+///
+/// The axes are an attribute before the `input_since` opset and an optional input from then on,
+/// which is opset 13 for the squeeze and unsqueeze nodes and opset 18 for the reduce nodes.
+fn axes_attr_or_input(node: &Node, input_since: usize) -> Option<Vec<i64>> {
+    if node.opset_version < input_since {
+        return node
+            .attrs
+            .get("axes")
+            .map(|value| value.clone().into_i64s());
+    }
+
+    // An omitted optional input has no value and isn't passed from another node
+    let input = node
+        .inputs
+        .get(1)
+        .filter(|input| input.value.is_some() || input.passed)?;
+
+    match &input.value {
+        Some(Data::Int64s(values)) => Some(values.clone()),
+        Some(_) => panic!("{:?}: the axes must be int64", node.node_type),
+        None => panic!("{:?}: the axes must be a constant", node.node_type),
+    }
+}
+
+/// Create squeeze config from the attributes and inputs of the node
+///
+/// Returns the dimensions to squeeze in ascending order.
+pub fn squeeze_config(node: &Node) -> Vec<usize> {
+    // extract the shape of the input tensor
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Squeeze: only tensor input is valid"),
+    };
+
+    let mut dims: Vec<usize> = match axes_attr_or_input(node, 13) {
+        Some(axes) => axes
+            .into_iter()
+            .map(|axis| {
+                // if axis is negative, it is counted from the end
+                let dim = if axis < 0 {
+                    axis + tensor.dim as i64
+                } else {
+                    axis
+                };
+
+                if dim < 0 || dim >= tensor.dim as i64 {
+                    panic!(
+                        "Squeeze: axis {axis} is out of range for rank {}",
+                        tensor.dim
+                    );
+                }
+
+                dim as usize
+            })
+            .collect(),
+        // without axes, all the dimensions of size 1 are removed
+        None => match &tensor.shape {
+            Some(shape) => (0..tensor.dim).filter(|&dim| shape[dim] == 1).collect(),
+            None => panic!("Squeeze: the axes are required when the input shape is unknown"),
+        },
+    };
+
+    dims.sort_unstable();
+    dims.dedup();
+
+    // same constraint as `Tensor::squeeze`, validated here when the shape is known
+    if let Some(shape) = &tensor.shape {
+        for &dim in dims.iter() {
+            if shape[dim] != 1 {
+                panic!("Squeeze: can't squeeze dimension {dim} because its size is not 1");
+            }
+        }
+    }
+
+    dims
+}
+
+/// Create reduce config from the attributes and inputs of a ReduceMean or ReduceSum node
+///
+/// Returns the reduced dimensions in ascending order and whether they are kept with a size of 1.
+pub fn reduce_config(node: &Node) -> (Vec<usize>, bool) {
+    let mut keepdims = 1;
+    let mut noop_with_empty_axes = 0;
+
+    // extract the shape of the input tensor
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{:?}: only tensor input is valid", node.node_type),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "keepdims" => keepdims = value.clone().into_i64(),
+            "noop_with_empty_axes" => noop_with_empty_axes = value.clone().into_i64(),
+            _ => {}
+        }
+    }
+
+    let mut dims: Vec<usize> = match axes_attr_or_input(node, 18).filter(|axes| !axes.is_empty()) {
+        Some(axes) => axes
+            .into_iter()
+            .map(|axis| {
+                // if axis is negative, it is counted from the end
+                let dim = if axis < 0 {
+                    axis + tensor.dim as i64
+                } else {
+                    axis
+                };
+
+                if dim < 0 || dim >= tensor.dim as i64 {
+                    panic!(
+                        "{:?}: axis {axis} is out of range for rank {}",
+                        node.node_type, tensor.dim
+                    );
+                }
+
+                dim as usize
+            })
+            .collect(),
+        // without axes, all the dimensions are reduced unless the node is a no-op
+        None if noop_with_empty_axes == 1 => Vec::new(),
+        None => (0..tensor.dim).collect(),
+    };
+
+    dims.sort_unstable();
+    dims.dedup();
+
+    (dims, keepdims == 1)
+}
+
+/// Create unsqueeze config from the attributes and inputs of the node
+///
+/// Returns the positions of the inserted dimensions in the output, in ascending order.
+pub fn unsqueeze_config(node: &Node) -> Vec<usize> {
+    // extract the shape of the input tensor
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Unsqueeze: only tensor input is valid"),
+    };
+
+    let axes = axes_attr_or_input(node, 13).expect("Unsqueeze: the axes are required");
+    let output_dim = tensor.dim + axes.len();
+
+    let mut dims: Vec<usize> = axes
+        .into_iter()
+        .map(|axis| {
+            // if axis is negative, it is counted from the end of the output
+            let dim = if axis < 0 {
+                axis + output_dim as i64
+            } else {
+                axis
+            };
+
+            // same constraint as `Tensor::unsqueeze_dim`, for each inserted dimension
+            if dim < 0 || dim >= output_dim as i64 {
+                panic!("Unsqueeze: axis {axis} is out of range for output rank {output_dim}");
+            }
+
+            dim as usize
+        })
+        .collect();
+
+    dims.sort_unstable();
+
+    if dims.windows(2).any(|pair| pair[0] == pair[1]) {
+        panic!("Unsqueeze: the axes must be unique");
+    }
+
+    dims
+}
+
+/// Create glu config from the attributes of the node
+///
+/// The Glu node is produced by fusing a `Split` with its gating `Sigmoid` and `Mul` nodes, so the
+/// split axis is used as the dimension.
+pub fn glu_config(node: &Node) -> usize {
+    // the axis is the first dimension (Default: 0 per ONNX spec)
+    let mut axis: i64 = 0;
+
+    // extract the shape of the input tensor
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        if key == "axis" {
+            axis = value.clone().into_i64();
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    axis as usize
+}
+
 /// Create concat config from the attributes of the node
 pub fn concat_config(node: &Node) -> usize {
     // the axis is the last dimension (Default: 1 per ONNX spec)
@@ -485,21 +914,23 @@ pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
 /// This function is used when the padding is specified as a list of integers,
 /// and not used when the padding is specified as a string, e.g. "SAME_UPPER".
 fn padding_config(pads: &[i64]) -> PaddingConfig2d {
-    let [left, top, right, bottom] = [pads[0], pads[1], pads[2], pads[3]];
+    // the pads are [x1_begin, x2_begin, x1_end, x2_end], i.e. [top, left, bottom, right]
+    let [top, left, bottom, right] = [pads[0], pads[1], pads[2], pads[3]];
 
-    if left < 0 || top < 0 || right < 0 || bottom < 0 {
+    if top < 0 || left < 0 || bottom < 0 || right < 0 {
         panic!("Negative pad values are not supported");
-    } else if (left != right) || (top != bottom) {
-        panic!("Asymmetric padding is not supported");
-    } else if left == top && top == right && right == bottom && bottom == 0 {
+    } else if (top != bottom) || (left != right) {
+        panic!(
+            "Asymmetric padding is not supported, the begin and end pads of each spatial \
+             dimension must be equal (got {:?})",
+            pads
+        );
+    } else if top == 0 && left == 0 {
         // i.e [0, 0, 0, 0]
         PaddingConfig2d::Valid
-    } else if left == right && top == bottom {
-        // i.e [2, 3, 2, 3]
-        PaddingConfig2d::Explicit(left as usize, top as usize)
     } else {
-        // Unaccounted for padding configuration
-        panic!("Padding configuration ({:?}) not supported", pads);
+        // i.e [2, 3, 2, 3]
+        PaddingConfig2d::Explicit(top as usize, left as usize)
     }
 }
 
@@ -538,50 +969,61 @@ pub fn reshape_config(node: &Node) -> Vec<i64> {
     }
 }
 
+/// Create a ConstantOfShape config from the input and the attribute of the node
+///
+/// Returns the static shape of the output and its fill value, which is a float32 zero when the
+/// `value` attribute is absent.
+pub fn constant_of_shape_config(node: &Node) -> (Vec<usize>, Data) {
+    let shape: Vec<usize> = match node.inputs.first().and_then(|input| input.value.as_ref()) {
+        Some(Data::Int64s(shape)) => shape.iter().map(|&size| size as usize).collect(),
+        Some(_) => panic!("ConstantOfShape: the shape must be int64"),
+        None => panic!("ConstantOfShape: the shape must be a constant"),
+    };
+
+    if shape.is_empty() {
+        panic!("ConstantOfShape: scalar outputs are not supported");
+    }
+
+    let value = match node.attrs.get("value") {
+        Some(AttributeValue::Tensor(tensor)) => tensor
+            .data
+            .clone()
+            .expect("ConstantOfShape: the value must have data")
+            .into_scalar(),
+        Some(_) => panic!("ConstantOfShape: the value must be a tensor"),
+        None => Data::Float32(0.0),
+    };
+
+    (shape, value)
+}
+
 pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
     let mut min_result: Option<f64> = None;
     let mut max_result: Option<f64> = None;
 
-    // For Clip Opset 6+ , the min and max values are attributes
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "min" => {
-                let min = value.clone().into_f32() as f64;
-                min_result = Some(min);
+    if node.opset_version < 11 {
+        // Before opset 11, the min and max values are attributes
+        for (key, value) in node.attrs.iter() {
+            match key.as_str() {
+                "min" => min_result = Some(value.clone().into_f32() as f64),
+                "max" => max_result = Some(value.clone().into_f32() as f64),
+                _ => {}
             }
-            "max" => {
-                let max = value.clone().into_f32();
-                max_result = Some(max as f64);
-            }
-            _ => {}
-        }
-    }
-
-    // For Clip Opset 11+ , the min and max values are inputs
-    // Get the min and max values from the input values
-    if min_result.is_none() && max_result.is_none() {
-        let min = &node.inputs[1].value;
-        let max = &node.inputs[2].value;
-
-        if min_result.is_none() && min.is_some() {
-            let min = min.clone().unwrap().into_scalar();
-            min_result = match min {
-                Data::Float16(min) => Some(f32::from(min) as f64),
-                Data::Float32(min) => Some(min as f64),
-                Data::Float64(min) => Some(min),
-                _ => panic!("Clip: only float min is supported"),
-            };
         }
+    } else {
+        // Since opset 11, the min and max values are optional inputs
+        let scalar = |index: usize, name: &str| {
+            let value = node.inputs.get(index)?.value.clone()?;
+            match value.into_scalar() {
+                Data::Float16(value) => Some(f32::from(value) as f64),
+                Data::Float32(value) => Some(value as f64),
+                Data::Float64(value) => Some(value),
+                _ => panic!("Clip: only float {name} is supported"),
+            }
+        };
 
-        if max_result.is_none() && max.is_some() {
-            let max = max.clone().unwrap().into_scalar();
-            max_result = match max {
-                Data::Float16(max) => Some(f32::from(max) as f64),
-                Data::Float32(max) => Some(max as f64),
-                Data::Float64(max) => Some(max),
-                _ => panic!("Clip: only float max is supported"),
-            };
-        }
+        min_result = scalar(1, "min");
+        max_result = scalar(2, "max");
     }
 
     if min_result.is_none() && max_result.is_none() {
@@ -628,3 +1070,189 @@ fn padding_config_1d(pads: &[i64]) -> PaddingConfig1d {
         panic!("Padding configuration ({:?}) not supported", pads);
     }
 }
+
+/// Create pad config from the attributes and inputs of the node
+///
+/// Returns the `(before, after)` padding of each dimension and the constant value.
+pub fn pad_config(node: &Node) -> (Vec<(usize, usize)>, f64) {
+    let mut pads: Option<Vec<i64>> = None;
+    let mut value = 0.0;
+    let mut mode = "constant".to_string();
+
+    // extract the shape of the input tensor
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Pad: only tensor input is valid"),
+    };
+
+    // Before opset 11, the pads and the value are attributes
+    for (key, attr) in node.attrs.iter() {
+        match key.as_str() {
+            "pads" => pads = Some(attr.clone().into_i64s()),
+            "value" => value = attr.clone().into_f32() as f64,
+            "mode" => mode = attr.clone().into_string(),
+            _ => {}
+        }
+    }
+
+    if mode != "constant" {
+        panic!("Pad: the '{mode}' mode is not supported, only the 'constant' mode is");
+    }
+
+    // Since opset 11, the pads and the value are inputs
+    if let Some(input) = node.inputs.get(1) {
+        match &input.value {
+            Some(Data::Int64s(values)) => pads = Some(values.clone()),
+            Some(_) => panic!("Pad: the pads must be int64"),
+            None => panic!("Pad: the pads must be a constant"),
+        }
+    }
+
+    // An omitted optional input has no name and no value
+    let is_given = |input: &&Argument| input.value.is_some() || !input.name.is_empty();
+
+    if let Some(input) = node.inputs.get(2).filter(is_given) {
+        value = match input.value.clone().map(Data::into_scalar) {
+            Some(Data::Float16(value)) => f32::from(value) as f64,
+            Some(Data::Float32(value)) => value as f64,
+            Some(Data::Float64(value)) => value,
+            Some(Data::Int32(value)) => value as f64,
+            Some(Data::Int64(value)) => value as f64,
+            Some(_) => panic!("Pad: the constant value must be a number"),
+            None => panic!("Pad: the constant value must be a constant"),
+        };
+    }
+
+    if node.inputs.get(3).filter(is_given).is_some() {
+        panic!("Pad: the axes input is not supported");
+    }
+
+    // the pads are [x1_begin, x2_begin, ..., x1_end, x2_end, ...]
+    let pads = pads.expect("Pad: the pads are required");
+    if pads.len() != 2 * tensor.dim {
+        panic!(
+            "Pad: expected {} pads for a tensor of rank {}, got {}",
+            2 * tensor.dim,
+            tensor.dim,
+            pads.len()
+        );
+    }
+    if pads.iter().any(|&pad| pad < 0) {
+        panic!("Pad: negative pads are not supported, got {pads:?}");
+    }
+
+    let padding = (0..tensor.dim)
+        .map(|i| (pads[i] as usize, pads[i + tensor.dim] as usize))
+        .collect();
+
+    (padding, value)
+}
+
+/// Create the output size and the interpolate options of a Resize node
+///
+/// Only the 4D inputs are supported, with either the PyTorch `nearest` convention
+/// (`asymmetric` coordinates with the `floor` rounding) or the `linear` mode with the
+/// `half_pixel`, `pytorch_half_pixel` or `align_corners` coordinates.
+pub fn resize_config(node: &Node) -> ([usize; 2], InterpolateOptions) {
+    let mut mode = "nearest".to_string();
+    let mut coordinate_transformation_mode = "half_pixel".to_string();
+    let mut nearest_mode = "round_prefer_floor".to_string();
+
+    // extract the shape of the input tensor
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Resize: only tensor input is valid"),
+    };
+
+    if tensor.dim != 4 {
+        panic!("Resize: only 4D tensors are supported, got {}D", tensor.dim);
+    }
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "mode" => mode = value.clone().into_string(),
+            "coordinate_transformation_mode" => {
+                coordinate_transformation_mode = value.clone().into_string()
+            }
+            "nearest_mode" => nearest_mode = value.clone().into_string(),
+            _ => {}
+        }
+    }
+
+    let options = match (mode.as_str(), coordinate_transformation_mode.as_str()) {
+        ("nearest", "asymmetric") if nearest_mode == "floor" => {
+            InterpolateOptions::new(InterpolateMode::Nearest, false)
+        }
+        ("linear", "half_pixel" | "pytorch_half_pixel") => {
+            InterpolateOptions::new(InterpolateMode::Bilinear, false)
+        }
+        ("linear", "align_corners") => InterpolateOptions::new(InterpolateMode::Bilinear, true),
+        _ => panic!(
+            "Resize: the '{mode}' mode with the '{coordinate_transformation_mode}' coordinate \
+             transformation and the '{nearest_mode}' nearest mode is not supported"
+        ),
+    };
+
+    // Before opset 11, the inputs are [X, scales], then [X, roi, scales, sizes]
+    let (scales, sizes) = match node.inputs.len() {
+        2 => (node.inputs.get(1), None),
+        _ => (node.inputs.get(2), node.inputs.get(3)),
+    };
+
+    let constant = |input: Option<&Argument>, name: &str| match input {
+        Some(input) if input.value.is_some() => input.value.clone(),
+        Some(input) if input.passed => panic!("Resize: the {name} must be a constant"),
+        _ => None,
+    };
+
+    let output_shape: Vec<usize> = match (constant(sizes, "sizes"), constant(scales, "scales")) {
+        (Some(Data::Int64s(sizes)), _) => sizes.iter().map(|&size| size as usize).collect(),
+        (None, Some(Data::Float32s(scales))) => {
+            let shape = tensor
+                .shape
+                .as_ref()
+                .expect("Resize: the input shape is required to apply the scales");
+            shape
+                .iter()
+                .zip(scales.iter())
+                .map(|(&size, &scale)| (size as f32 * scale).floor() as usize)
+                .collect()
+        }
+        (None, None) => panic!("Resize: either the scales or the sizes are required"),
+        _ => panic!("Resize: the sizes must be int64 and the scales must be float32"),
+    };
+
+    if output_shape.len() != 4 {
+        panic!("Resize: expected 4 output sizes, got {output_shape:?}");
+    }
+    if let Some(shape) = tensor.shape {
+        if output_shape[..2] != shape[..2] {
+            panic!("Resize: only the spatial dimensions can be resized, got {output_shape:?}");
+        }
+    }
+
+    ([output_shape[2], output_shape[3]], options)
+}
+
+/// Create the target element type of a Cast node from its "to" attribute
+pub fn cast_config(node: &Node) -> ElementType {
+    if node.inputs.len() != 1 {
+        panic!("Cast: multiple inputs are not supported");
+    }
+
+    let type_id = match node.attrs.get("to") {
+        Some(AttributeValue::Int64(type_id)) => *type_id as i32,
+        Some(_) => panic!("Cast: the 'to' attribute must be an Int64"),
+        None => panic!("Cast: the 'to' attribute is required"),
+    };
+
+    match DataType::from_i32(type_id) {
+        Some(DataType::FLOAT) => ElementType::Float32,
+        Some(DataType::FLOAT16) => ElementType::Float16,
+        Some(DataType::DOUBLE) => ElementType::Float64,
+        Some(DataType::INT32) => ElementType::Int32,
+        Some(DataType::INT64) => ElementType::Int64,
+        Some(DataType::BOOL) => ElementType::Bool,
+        _ => panic!("Cast: unsupported type {type_id}"),
+    }
+}
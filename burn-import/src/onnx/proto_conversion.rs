@@ -12,6 +12,7 @@ use super::protos::{
 };
 
 use bytemuck::cast_slice;
+use half::f16;
 use protobuf::Enum;
 
 /// Error type for parsing ONNX model
@@ -34,6 +35,27 @@ impl TryFrom<TensorProto> for Tensor {
                     Data::Float32s(tensor.float_data)
                 },
             ),
+            DataType::FLOAT16 => (
+                ElementType::Float16,
+                // Convert the raw data (or the bits stored as int32) to a vector of halfs
+                if !tensor.raw_data.is_empty() {
+                    Data::Float16s(
+                        tensor
+                            .raw_data
+                            .chunks_exact(2)
+                            .map(|bytes| f16::from_le_bytes([bytes[0], bytes[1]]))
+                            .collect(),
+                    )
+                } else {
+                    Data::Float16s(
+                        tensor
+                            .int32_data
+                            .iter()
+                            .map(|&bits| f16::from_bits(bits as u16))
+                            .collect(),
+                    )
+                },
+            ),
             DataType::INT16 => {
                 // TODO : Add support for int16 by converting to int32
                 todo!("Add support for int16");
@@ -106,6 +128,7 @@ impl TryFrom<&type_proto::Tensor> for Tensor {
     fn try_from(tensor: &type_proto::Tensor) -> Result<Tensor, Self::Error> {
         let elem_type = match DataType::from_i32(tensor.elem_type).unwrap() {
             DataType::FLOAT => ElementType::Float32,
+            DataType::FLOAT16 => ElementType::Float16,
             DataType::INT32 => ElementType::Int32,
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
@@ -179,7 +202,7 @@ pub fn convert_vec_attrs_proto(attrs: Vec<AttributeProto>) -> Attributes {
     result
 }
 
-pub fn convert_node_proto(node: &NodeProto) -> Node {
+pub fn convert_node_proto(node: &NodeProto, opset_version: usize) -> Node {
     let name = node.name.clone();
 
     log::debug!("Converting ONNX node with type {:?}", node.op_type.as_str());
@@ -198,6 +221,7 @@ pub fn convert_node_proto(node: &NodeProto) -> Node {
         inputs,
         outputs,
         attrs,
+        opset_version,
     }
 }
 
@@ -228,6 +252,7 @@ impl TryFrom<ValueInfoProto> for Argument {
 
         let elem_type = match DataType::from_i32(tensor_proto.elem_type).unwrap() {
             DataType::FLOAT => ElementType::Float32,
+            DataType::FLOAT16 => ElementType::Float16,
             DataType::INT32 => ElementType::Int32,
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
@@ -13,23 +13,31 @@ use crate::{
     burn::{
         graph::BurnGraph,
         node::{
+            avg_pool1d::AvgPool1dNode,
             avg_pool2d::AvgPool2dNode,
             batch_norm::BatchNormNode,
             binary::BinaryNode,
             clip::ClipNode,
             concat::ConcatNode,
             constant::{ConstantNode, ConstantValue, TensorValue},
+            constant_of_shape::ConstantOfShapeNode,
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             dropout::DropoutNode,
             gather::GatherNode,
-            global_avg_pool::GlobalAvgPoolNode,
+            gather_elements::GatherElementsNode,
+            gather_nd::GatherNdNode,
             linear::LinearNode,
             matmul::MatmulNode,
             max_pool2d::MaxPool2dNode,
+            pad::PadNode,
             reshape::ReshapeNode,
+            resize::ResizeNode,
+            scatter_nd::ScatterNdNode,
+            split::SplitNode,
             unary::UnaryNode,
+            Node as BurnNode, NodeCodegen,
         },
         ScalarKind, ScalarType, TensorKind, TensorType, Type,
     },
@@ -43,16 +51,47 @@ use crate::{
 };
 
 use super::{
-    from_onnx::parse_onnx,
+    from_onnx::{parse_onnx, remove_pass_through_nodes},
     ir::{self, ArgType, Argument, Data, ElementType, ONNXGraph},
     op_configuration::{
-        avg_pool2d_config, clip_config, concat_config, dropout_config, reshape_config,
-        softmax_config,
+        avg_pool1d_config, avg_pool2d_config, clip_config, concat_config, dropout_config,
+        reshape_config, softmax_config,
     },
 };
 
 pub use crate::burn::graph::RecordType;
 
+/// Error of the code generation from `.onnx` files.
+#[derive(Debug)]
+pub enum ModelGenError {
+    /// The output directory is not set.
+    MissingOutDir,
+    /// The model contains nodes which can't be converted, listed once per node type.
+    UnsupportedNodes(Vec<String>),
+    /// The generated files can't be written.
+    Io(std::io::Error),
+}
+
+impl core::fmt::Display for ModelGenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingOutDir => f.write_str("The output directory is not set"),
+            Self::UnsupportedNodes(node_types) => {
+                write!(f, "Unsupported node conversions: {}", node_types.join(", "))
+            }
+            Self::Io(err) => write!(f, "Failed to write the generated files: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ModelGenError {}
+
+impl From<std::io::Error> for ModelGenError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
 /// Generate code and states from `.onnx` files and save them to the `out_dir`.
 #[derive(Debug, Default)]
 pub struct ModelGen {
@@ -63,6 +102,8 @@ pub struct ModelGen {
     half_precision: bool,
     record_type: RecordType,
     embed_states: bool,
+    skip_dropout: bool,
+    visibility: Option<String>,
 }
 
 impl ModelGen {
@@ -86,8 +127,9 @@ impl ModelGen {
 
     /// Set development mode.
     ///
-    /// If this is set to true, the generated model will be saved as `.graph.txt` files and model
-    /// states will be saved as `.json` file.
+    /// If this is set to true, the parsed ONNX graph will also be saved as a `.graph.txt` file
+    /// for debugging. The format of the model states is chosen with
+    /// [record_type](ModelGen::record_type) independently of this mode.
     pub fn development(&mut self, development: bool) -> &mut Self {
         self.development = development;
         self
@@ -96,15 +138,22 @@ impl ModelGen {
     /// Run code generation.
     ///
     /// This function is intended to be called from `build.rs` script.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the code generation fails, which stops the build.
     pub fn run_from_script(&self) {
-        self.run(true);
+        if let Err(err) = self.run(true) {
+            panic!("{err}");
+        }
     }
 
     /// Run code generation.
     ///
-    /// This function is intended to be called from CLI.
-    pub fn run_from_cli(&self) {
-        self.run(false);
+    /// This function is intended to be called from CLI, where the error can be reported to the
+    /// user, e.g. every unsupported node type of the model.
+    pub fn run_from_cli(&self) -> Result<(), ModelGenError> {
+        self.run(false)
     }
 
     /// Specify parameter precision to be saved.
@@ -138,25 +187,49 @@ impl ModelGen {
         self
     }
 
+    /// Specify whether to skip the dropout layers in the generated code.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_dropout` - If true, the Dropout nodes are removed from the graph, since they don't
+    ///   change their input at inference time. Otherwise, they are generated as dropout layers.
+    pub fn skip_dropout(&mut self, skip_dropout: bool) -> &mut Self {
+        self.skip_dropout = skip_dropout;
+        self
+    }
+
+    /// Specify the visibility of the generated model struct and of its fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `vis` - The Rust visibility, e.g. `pub` or `pub(crate)`. By default, the struct is public
+    ///   and its fields are private.
+    pub fn visibility(&mut self, vis: &str) -> &mut Self {
+        self.visibility = Some(vis.to_string());
+        self
+    }
+
     /// Run code generation.
-    fn run(&self, is_build_script: bool) {
+    fn run(&self, is_build_script: bool) -> Result<(), ModelGenError> {
         log::info!("Starting to convert ONNX to Burn");
 
+        let out_dir = self.out_dir.clone().ok_or(ModelGenError::MissingOutDir)?;
+
         // prepend the out_dir to the cargo_out_dir if this is a build script
         let out_dir = if is_build_script {
             let cargo_out_dir = env::var("OUT_DIR").expect("OUT_DIR env is not set");
             let mut path = PathBuf::from(cargo_out_dir);
 
             // // Append the out_dir to the cargo_out_dir
-            path.push(self.out_dir.clone().unwrap());
+            path.push(out_dir);
             path
         } else {
-            self.out_dir.as_ref().expect("out_dir is not set").clone()
+            out_dir
         };
 
         log::debug!("Output directory: {:?}", out_dir);
 
-        create_dir_all(&out_dir).unwrap();
+        create_dir_all(&out_dir)?;
 
         for input in self.inputs.iter() {
             let file_name = input.file_stem().unwrap();
@@ -166,26 +239,34 @@ impl ModelGen {
             log::debug!("Input file name: {:?}", file_name);
             log::debug!("Output file: {:?}", out_file);
 
-            self.generate_model(input, out_file);
+            self.generate_model(input, out_file)?;
         }
 
         log::info!("Finished converting ONNX to Burn");
+
+        Ok(())
     }
 
     /// Generate model source code and model state.
-    fn generate_model(&self, input: &PathBuf, out_file: PathBuf) {
+    fn generate_model(&self, input: &PathBuf, out_file: PathBuf) -> Result<(), ModelGenError> {
         log::info!("Generating model from {:?}", input);
         log::debug!("Development mode: {:?}", self.development);
+        log::debug!("Record type: {:?}", self.record_type);
+        log::debug!("Visibility: {:?}", self.visibility);
         log::debug!("Output file: {:?}", out_file);
 
-        let graph = parse_onnx(input.as_ref());
+        let mut graph = parse_onnx(input.as_ref());
+
+        if self.skip_dropout {
+            graph.remove_dropout();
+        }
 
         if self.development {
             // export the graph
             let debug_graph = format!("{:#?}", graph);
             let graph_file = out_file.with_extension("graph.txt");
             log::debug!("Writing debug graph file: {:?}", graph_file);
-            fs::write(graph_file, debug_graph).unwrap();
+            fs::write(graph_file, debug_graph)?;
         }
 
         let new_fn = true;
@@ -194,82 +275,76 @@ impl ModelGen {
 
         let code = if self.half_precision {
             graph
-                .into_burn::<HalfPrecisionSettings>()
+                .into_burn::<HalfPrecisionSettings>()?
                 .with_record(out_file.clone(), self.record_type, self.embed_states)
                 .with_new_fn(new_fn)
                 .with_blank_space(blank_space)
                 .with_top_comment(top_comment)
+                .with_visibility(self.visibility.as_deref())
                 .codegen()
         } else {
             graph
-                .into_burn::<FullPrecisionSettings>()
+                .into_burn::<FullPrecisionSettings>()?
                 .with_record(out_file.clone(), self.record_type, self.embed_states)
                 .with_new_fn(new_fn)
                 .with_blank_space(blank_space)
                 .with_top_comment(top_comment)
+                .with_visibility(self.visibility.as_deref())
                 .codegen()
         };
 
         let code_str = format_tokens(code);
-        fs::write(out_file.with_extension("rs"), code_str).unwrap();
+        fs::write(out_file.with_extension("rs"), code_str)?;
 
         log::info!("Model generated");
+
+        Ok(())
     }
 }
 
 impl ONNXGraph {
+    /// Removes the Dropout nodes, which pass their input through at inference time.
+    pub(crate) fn remove_dropout(&mut self) {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| output.name.clone())
+            .collect::<Vec<_>>();
+
+        remove_pass_through_nodes(&mut self.nodes, &outputs, NodeType::Dropout);
+    }
+
     /// Converts ONNX graph to Burn graph.
-    pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
-        let mut graph = BurnGraph::<PS>::default();
+    ///
+    /// Returns an error listing every unsupported node type of the graph.
+    pub fn into_burn<PS: PrecisionSettings + 'static>(
+        self,
+    ) -> Result<BurnGraph<PS>, ModelGenError> {
+        let mut unsupported = Vec::new();
+        let mut conversions = Vec::with_capacity(self.nodes.len());
 
         for node in self.nodes {
-            match node.node_type {
-                NodeType::Add => graph.register(Self::add_conversion(node)),
-                NodeType::Sub => graph.register(Self::sub_conversion(node)),
-                NodeType::Mul => graph.register(Self::mul_conversion(node)),
-                NodeType::Div => graph.register(Self::div_conversion(node)),
-                NodeType::Equal => graph.register(Self::equal_conversion(node)),
-                NodeType::Erf => graph.register(Self::erf_conversion(node)),
-                NodeType::Exp => graph.register(Self::exp_conversion(node)),
-                NodeType::Clip => graph.register(Self::clip_conversion(node)),
-                NodeType::Cos => graph.register(Self::cos_conversion(node)),
-                NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
-                NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
-                NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
-                NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
-                NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
-                NodeType::Neg => graph.register(Self::neg_conversion(node)),
-                NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
-                NodeType::BatchNormalization => {
-                    graph.register(Self::batch_norm_conversion::<PS>(node))
+            match Self::node_conversion::<PS>(&node.node_type) {
+                Some(conversion) => conversions.push((conversion, node)),
+                None => {
+                    let node_type = node.node_type.to_string();
+                    if !unsupported.contains(&node_type) {
+                        unsupported.push(node_type);
+                    }
                 }
-                NodeType::Relu => graph.register(Self::relu_conversion(node)),
-                NodeType::Gelu => graph.register(Self::gelu_conversion(node)),
-                NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
-                NodeType::GatherElements => graph.register(Self::gather_conversion(node)),
-                NodeType::Log => graph.register(Self::log_conversion(node)),
-                NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
-                NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
-                NodeType::Sqrt => graph.register(Self::sqrt_conversion(node)),
-                NodeType::Tanh => graph.register(Self::tanh_conversion(node)),
-                NodeType::Constant => graph.register(Self::constant_conversion::<PS>(node)),
-                NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
-                NodeType::Reciprocal => graph.register(Self::reciprocal_conversion(node)),
-                NodeType::Sigmoid => graph.register(Self::sigmoid_conversion(node)),
-                NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
-                NodeType::Concat => graph.register(Self::concat_conversion(node)),
-                NodeType::Cast => graph.register(Self::cast_conversion(node)),
-                NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
-                NodeType::GlobalAveragePool => {
-                    graph.register(Self::global_avg_pool_conversion(node))
-                }
-                NodeType::ConvTranspose2d => {
-                    graph.register(Self::conv_transpose2d_conversion(node))
-                }
-                _ => panic!("Unsupported node conversion {}", node.node_type),
             }
         }
 
+        if !unsupported.is_empty() {
+            return Err(ModelGenError::UnsupportedNodes(unsupported));
+        }
+
+        let mut graph = BurnGraph::<PS>::default();
+
+        for (conversion, node) in conversions {
+            graph.register(conversion(node));
+        }
+
         // Get input and output names
         let input_names = self
             .inputs
@@ -285,7 +360,86 @@ impl ONNXGraph {
         // Register inputs and outputs with the graph
         graph.register_input_output(input_names, output_names);
 
-        graph
+        Ok(graph)
+    }
+
+    /// Returns the conversion of the nodes of the given type into a Burn node, or `None` if the
+    /// node type is not supported.
+    fn node_conversion<PS: PrecisionSettings + 'static>(
+        node_type: &NodeType,
+    ) -> Option<fn(Node) -> BurnNode<PS>> {
+        let conversion: fn(Node) -> BurnNode<PS> = match node_type {
+            NodeType::Add => |node| Self::add_conversion(node).into_node(),
+            NodeType::Sub => |node| Self::sub_conversion(node).into_node(),
+            NodeType::Mul => |node| Self::mul_conversion(node).into_node(),
+            NodeType::Div => |node| Self::div_conversion(node).into_node(),
+            NodeType::Equal => |node| Self::equal_conversion(node).into_node(),
+            NodeType::And => |node| Self::and_conversion(node).into_node(),
+            NodeType::Or => |node| Self::or_conversion(node).into_node(),
+            NodeType::Xor => |node| Self::xor_conversion(node).into_node(),
+            NodeType::Not => |node| Self::not_conversion(node).into_node(),
+            NodeType::Erf => |node| Self::erf_conversion(node).into_node(),
+            NodeType::Exp => |node| Self::exp_conversion(node).into_node(),
+            NodeType::Clip => |node| Self::clip_conversion(node).into_node(),
+            NodeType::Pad => |node| Self::pad_conversion(node).into_node(),
+            NodeType::Cos => |node| Self::cos_conversion(node).into_node(),
+            NodeType::Conv1d => |node| Self::conv1d_conversion::<PS>(node).into_node(),
+            NodeType::Conv2d => |node| Self::conv2d_conversion::<PS>(node).into_node(),
+            NodeType::MaxPool2d => |node| Self::max_pool2d_conversion(node).into_node(),
+            NodeType::AveragePool1d => |node| Self::avg_pool_1d_conversion(node).into_node(),
+            NodeType::AveragePool2d => |node| Self::avg_pool_2d_conversion(node).into_node(),
+            NodeType::MatMul => |node| Self::matmul_conversion(node).into_node(),
+            NodeType::Neg => |node| Self::neg_conversion(node).into_node(),
+            NodeType::Linear => |node| Self::linear_conversion::<PS>(node).into_node(),
+            NodeType::Relu => |node| Self::relu_conversion(node).into_node(),
+            NodeType::ReduceMean => |node| Self::reduce_mean_conversion(node).into_node(),
+            NodeType::ReduceSum => |node| Self::reduce_sum_conversion(node).into_node(),
+            NodeType::Squeeze => |node| Self::squeeze_conversion(node).into_node(),
+            NodeType::Unsqueeze => |node| Self::unsqueeze_conversion(node).into_node(),
+            NodeType::LeakyRelu => |node| Self::leaky_relu_conversion(node).into_node(),
+            NodeType::Gelu => |node| Self::gelu_conversion(node).into_node(),
+            NodeType::Glu => |node| Self::glu_conversion(node).into_node(),
+            NodeType::Flatten => |node| Self::flatten_conversion(node).into_node(),
+            NodeType::Gather => |node| Self::gather_conversion(node).into_node(),
+            NodeType::GatherElements => |node| Self::gather_elements_conversion(node).into_node(),
+            NodeType::GatherND => |node| Self::gather_nd_conversion(node).into_node(),
+            NodeType::Log => |node| Self::log_conversion(node).into_node(),
+            NodeType::LRN => |node| Self::local_response_norm_conversion(node).into_node(),
+            NodeType::LogSoftmax => |node| Self::log_softmax_conversion(node).into_node(),
+            NodeType::Softmax => |node| Self::softmax_conversion(node).into_node(),
+            NodeType::Sqrt => |node| Self::sqrt_conversion(node).into_node(),
+            NodeType::Tanh => |node| Self::tanh_conversion(node).into_node(),
+            NodeType::Constant => |node| Self::constant_conversion::<PS>(node).into_node(),
+            NodeType::Reshape => |node| Self::reshape_conversion(node).into_node(),
+            NodeType::Shape => |node| Self::shape_conversion::<PS>(node).into_node(),
+            NodeType::Resize => |node| Self::resize_conversion(node).into_node(),
+            NodeType::ScatterND => |node| Self::scatter_nd_conversion(node).into_node(),
+            NodeType::Reciprocal => |node| Self::reciprocal_conversion(node).into_node(),
+            NodeType::Floor => |node| Self::floor_conversion(node).into_node(),
+            NodeType::Identity => |node| Self::identity_conversion(node).into_node(),
+            NodeType::Ceil => |node| Self::ceil_conversion(node).into_node(),
+            NodeType::Round => |node| Self::round_conversion(node).into_node(),
+            NodeType::Sigmoid => |node| Self::sigmoid_conversion(node).into_node(),
+            NodeType::Transpose => |node| Self::transpose_conversion(node).into_node(),
+            NodeType::Concat => |node| Self::concat_conversion(node).into_node(),
+            NodeType::Split => |node| Self::split_conversion(node).into_node(),
+            NodeType::Cast => |node| Self::cast_conversion(node).into_node(),
+            NodeType::Dropout => |node| Self::dropout_conversion(node).into_node(),
+            NodeType::BatchNormalization => {
+                |node| Self::batch_norm_conversion::<PS>(node).into_node()
+            }
+            NodeType::ConstantOfShape => {
+                |node| Self::constant_of_shape_conversion(node).into_node()
+            }
+            NodeType::GlobalAveragePool => {
+                |node| Self::global_avg_pool_conversion(node).into_node()
+            }
+            NodeType::GlobalMaxPool => |node| Self::global_max_pool_conversion(node).into_node(),
+            NodeType::ConvTranspose2d => |node| Self::conv_transpose2d_conversion(node).into_node(),
+            _ => return None,
+        };
+
+        Some(conversion)
     }
 
     fn constant_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode<PS> {
@@ -306,7 +460,7 @@ impl ONNXGraph {
 
                     let tensor_value = match tensor.elem_type {
                         // TODO Review how double precision should be supported
-                        ElementType::Float32 | ElementType::Float64 => {
+                        ElementType::Float16 | ElementType::Float32 | ElementType::Float64 => {
                             TensorValue::Float(serialize_data::<PS::FloatElem>(
                                 attr.value.unwrap(),
                                 tensor.shape.unwrap(),
@@ -328,6 +482,9 @@ impl ONNXGraph {
             ArgType::Scalar(elem_type) => match elem_type {
                 ElementType::Float64 => ConstantValue::Float64(attr.value.unwrap().into_f64()),
                 ElementType::Float32 => ConstantValue::Float32(attr.value.unwrap().into_f32()),
+                ElementType::Float16 => {
+                    ConstantValue::Float32(f32::from(attr.value.unwrap().into_f16()))
+                }
                 ElementType::Int32 => ConstantValue::Int32(attr.value.unwrap().into_i32()),
                 ElementType::Int64 => ConstantValue::Int64(attr.value.unwrap().into_i64()),
                 ElementType::Bool => ConstantValue::Bool(attr.value.unwrap().into_bool()),
@@ -339,6 +496,44 @@ impl ONNXGraph {
         ConstantNode::new(node.name.clone(), const_value, output.to_type())
     }
 
+    /// The Shape nodes are resolved at import time, so they are converted into constant nodes
+    /// holding the static shape of their input.
+    fn shape_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode<PS> {
+        let output = node.outputs.first().unwrap();
+
+        let shape = match &output.value {
+            Some(Data::Int64s(shape)) => shape.clone(),
+            _ => panic!("Shape: the shape of the input must be known statically"),
+        };
+
+        let num_dims = shape.len();
+        let const_value = ConstantValue::Tensor(
+            TensorType::new(node.name.clone(), 1, TensorKind::Int, Some(vec![num_dims])),
+            TensorValue::Int(serialize_data::<PS::IntElem>(
+                Data::Int64s(shape),
+                vec![num_dims],
+            )),
+        );
+
+        ConstantNode::new(node.name.clone(), const_value, output.to_type())
+    }
+
+    fn constant_of_shape_conversion(node: Node) -> ConstantOfShapeNode {
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (_, value) = constant_of_shape_config(&node);
+
+        let value = match value {
+            Data::Float16(value) => f32::from(value) as f64,
+            Data::Float32(value) => value as f64,
+            Data::Float64(value) => value,
+            Data::Int32(value) => value as f64,
+            Data::Int64(value) => value as f64,
+            _ => panic!("ConstantOfShape: only float and int values are supported"),
+        };
+
+        ConstantOfShapeNode::new(node.name.clone(), output, value)
+    }
+
     fn add_conversion(node: Node) -> BinaryNode {
         let lhs = node.inputs.first().unwrap().to_type();
         let rhs = node.inputs.get(1).unwrap().to_type();
@@ -387,6 +582,30 @@ impl ONNXGraph {
         BinaryNode::equal(lhs, rhs, output)
     }
 
+    fn and_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::and(lhs, rhs, output)
+    }
+
+    fn or_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::or(lhs, rhs, output)
+    }
+
+    fn xor_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        BinaryNode::xor(lhs, rhs, output)
+    }
+
     fn erf_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -401,6 +620,46 @@ impl ONNXGraph {
         UnaryNode::relu(input, output)
     }
 
+    fn reduce_mean_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (dims, keepdims) = reduce_config(&node);
+
+        UnaryNode::reduce_mean(input, output, dims, keepdims)
+    }
+
+    fn reduce_sum_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (dims, keepdims) = reduce_config(&node);
+
+        UnaryNode::reduce_sum(input, output, dims, keepdims)
+    }
+
+    fn squeeze_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let dims = squeeze_config(&node);
+
+        UnaryNode::squeeze(input, output, dims)
+    }
+
+    fn unsqueeze_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let dims = unsqueeze_config(&node);
+
+        UnaryNode::unsqueeze(input, output, dims)
+    }
+
+    fn leaky_relu_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let alpha = leaky_relu_config(&node);
+
+        UnaryNode::leaky_relu(input, output, alpha)
+    }
+
     fn gelu_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -408,6 +667,14 @@ impl ONNXGraph {
         UnaryNode::gelu(input, output)
     }
 
+    fn glu_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let dim = glu_config(&node);
+
+        UnaryNode::glu(input, output, dim)
+    }
+
     fn log_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -425,13 +692,42 @@ impl ONNXGraph {
 
     fn gather_conversion(node: Node) -> GatherNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
-        let index = node.inputs.get(1).unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
         let dim = gather_config(&node);
 
         GatherNode::new(input, index, output, dim)
     }
 
+    fn gather_elements_conversion(node: Node) -> GatherElementsNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let dim = gather_config(&node);
+
+        GatherElementsNode::new(input, index, output, dim)
+    }
+
+    fn gather_nd_conversion(node: Node) -> GatherNdNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let batch_dims = gather_nd_config(&node);
+
+        GatherNdNode::new(input, index, output, batch_dims)
+    }
+
+    fn scatter_nd_conversion(node: Node) -> ScatterNdNode {
+        scatter_nd_config(&node);
+
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_tensor_type();
+        let updates = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+
+        ScatterNdNode::new(input, index, updates, output)
+    }
+
     fn transpose_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -441,7 +737,20 @@ impl ONNXGraph {
 
     fn cast_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
-        let output = node.outputs.first().unwrap().to_type();
+        let elem_type = cast_config(&node);
+
+        // The output takes the kind of the target element type
+        let output = match node.outputs.first().unwrap().to_type() {
+            Type::Tensor(tensor) => Type::Tensor(TensorType {
+                kind: elem_type.into(),
+                ..tensor
+            }),
+            Type::Scalar(scalar) => Type::Scalar(ScalarType {
+                kind: (&elem_type).into(),
+                ..scalar
+            }),
+            _ => panic!("Cast: the output must be a tensor or a scalar"),
+        };
 
         UnaryNode::cast(input, output)
     }
@@ -454,6 +763,14 @@ impl ONNXGraph {
         ReshapeNode::new(input, output, shape)
     }
 
+    fn resize_conversion(node: Node) -> ResizeNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (output_size, options) = resize_config(&node);
+
+        ResizeNode::new(input, output, output_size, options)
+    }
+
     fn clip_conversion(node: Node) -> ClipNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
@@ -462,6 +779,14 @@ impl ONNXGraph {
         ClipNode::new(input, output, min, max)
     }
 
+    fn pad_conversion(node: Node) -> PadNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (padding, value) = pad_config(&node);
+
+        PadNode::new(input, output, padding, value)
+    }
+
     fn sigmoid_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -476,6 +801,42 @@ impl ONNXGraph {
         UnaryNode::reciprocal(input, output)
     }
 
+    fn identity_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::identity(input, output)
+    }
+
+    fn floor_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::floor(input, output)
+    }
+
+    fn ceil_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::ceil(input, output)
+    }
+
+    fn round_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::round(input, output)
+    }
+
+    fn local_response_norm_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (size, alpha, beta, bias) = local_response_norm_config(&node);
+
+        UnaryNode::local_response_norm(input, output, size, alpha, beta, bias)
+    }
+
     fn log_softmax_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -519,6 +880,18 @@ impl ONNXGraph {
         ConcatNode::new(inputs, output, dim)
     }
 
+    fn split_conversion(node: Node) -> SplitNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let outputs = node
+            .outputs
+            .iter()
+            .map(|output| output.to_tensor_type())
+            .collect();
+        let (dim, split_sizes) = split_config(&node);
+
+        SplitNode::new(input, outputs, dim, split_sizes)
+    }
+
     fn linear_conversion<PS: PrecisionSettings>(node: Node) -> LinearNode<PS> {
         let name = &node.name;
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -626,22 +999,36 @@ impl ONNXGraph {
         ConvTranspose2dNode::<PS>::new(name, input, output, weight, bias, config)
     }
 
-    fn avg_pool_2d_conversion(node: Node) -> AvgPool2dNode {
+    fn avg_pool_1d_conversion(node: Node) -> AvgPool1dNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
-        let config = avg_pool2d_config(&node);
+        let config = avg_pool1d_config(&node);
 
         let name = &node.name;
-        AvgPool2dNode::new(name, input, output, config)
+        AvgPool1dNode::new(name, input, output, config)
     }
 
-    fn global_avg_pool_conversion(node: Node) -> GlobalAvgPoolNode {
+    fn avg_pool_2d_conversion(node: Node) -> AvgPool2dNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
+        let config = avg_pool2d_config(&node);
 
         let name = &node.name;
+        AvgPool2dNode::new(name, input, output, config)
+    }
+
+    fn global_avg_pool_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
 
-        GlobalAvgPoolNode::new(name, input, output)
+        UnaryNode::global_avg_pool(input, output)
+    }
+
+    fn global_max_pool_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::global_max_pool(input, output)
     }
 
     fn cos_conversion(node: Node) -> UnaryNode {
@@ -663,6 +1050,12 @@ impl ONNXGraph {
         let output = node.outputs.first().unwrap().to_type();
         UnaryNode::neg(input, output)
     }
+
+    fn not_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        UnaryNode::not(input, output)
+    }
 }
 
 /// Extract data from node states and convert it to `DataSerialize`.
@@ -715,13 +1108,13 @@ impl Argument {
             ArgType::Tensor(ir::TensorType {
                 elem_type: ElementType::Float16 | ElementType::Float32 | ElementType::Float64,
                 dim,
-                ..
-            }) => TensorType::new_float(self.name.clone(), *dim),
+                shape,
+            }) => TensorType::new(self.name.clone(), *dim, TensorKind::Float, shape.clone()),
             ArgType::Tensor(ir::TensorType {
                 elem_type: ElementType::Int32 | ElementType::Int64,
                 dim,
-                ..
-            }) => TensorType::new_int(self.name.clone(), *dim),
+                shape,
+            }) => TensorType::new(self.name.clone(), *dim, TensorKind::Int, shape.clone()),
             _ => panic!("Can't transform to tensor."),
         }
     }
@@ -761,7 +1154,8 @@ impl From<&ElementType> for ScalarKind {
             ElementType::Int64 => ScalarKind::Int64,
             ElementType::Bool => ScalarKind::Bool,
             ElementType::String => panic!("String tensor unsupported"),
-            ElementType::Float16 => panic!("Float16 tensor unsupported"),
+            // Half precision values are widened to single precision
+            ElementType::Float16 => ScalarKind::Float32,
         }
     }
 }
@@ -769,6 +1163,7 @@ impl From<&ElementType> for ScalarKind {
 impl From<ElementType> for TensorKind {
     fn from(elem_type: ElementType) -> Self {
         match elem_type {
+            ElementType::Float16 => TensorKind::Float,
             ElementType::Float32 => TensorKind::Float,
             ElementType::Float64 => TensorKind::Float,
             ElementType::Int32 => TensorKind::Int,
@@ -778,3 +1173,29 @@ impl From<ElementType> for TensorKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_burn_reports_every_unsupported_node_type() {
+        let graph = parse_onnx(Path::new("onnx-tests/tests/unsupported/unsupported.onnx"));
+
+        match graph.into_burn::<FullPrecisionSettings>() {
+            Err(ModelGenError::UnsupportedNodes(node_types)) => {
+                assert_eq!(node_types, vec!["Abs".to_string(), "Sin".to_string()])
+            }
+            result => panic!("Expected unsupported nodes, got {:?}", result.err()),
+        }
+    }
+
+    #[test]
+    fn run_from_cli_without_out_dir() {
+        let result = ModelGen::new()
+            .input("onnx-tests/tests/relu/relu.onnx")
+            .run_from_cli();
+
+        assert!(matches!(result, Err(ModelGenError::MissingOutDir)));
+    }
+}
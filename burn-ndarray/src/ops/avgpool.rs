@@ -3,7 +3,7 @@ use crate::{
     tensor::NdArrayTensor,
 };
 
-use burn_tensor::ElementConversion;
+use burn_tensor::{ops::conv::calculate_pool_output_size, ElementConversion};
 use ndarray::Array4;
 
 pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
@@ -12,14 +12,29 @@ pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> NdArrayTensor<E, 4> {
     let [kernel_height, kernel_width] = kernel_size;
     let [padding_height, padding_width] = padding;
     let [stride_height, stride_width] = stride;
     let [batch_size, channels, x_height, x_width] = x.shape().dims;
 
-    let out_height = ((x_height + 2 * padding_height - kernel_height) / stride_height) + 1;
-    let out_width = ((x_width + 2 * padding_width - kernel_width) / stride_width) + 1;
+    let out_height = calculate_pool_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        1,
+        x_height,
+        ceil_mode,
+    );
+    let out_width = calculate_pool_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        1,
+        x_width,
+        ceil_mode,
+    );
 
     let x = x.array;
 
@@ -60,7 +75,18 @@ pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
                     }
 
                     if count_include_pad {
-                        count = ((kernel_height * kernel_width) as i32).elem();
+                        // Padding is counted, but not the part of the window that overflows it.
+                        let ih_end = usize::min(
+                            oh * stride_height + kernel_height,
+                            x_height + 2 * padding_height,
+                        );
+                        let iw_end = usize::min(
+                            ow * stride_width + kernel_width,
+                            x_width + 2 * padding_width,
+                        );
+                        let size = (ih_end - oh * stride_height) * (iw_end - ow * stride_width);
+
+                        count = (size as i32).elem();
                     }
 
                     output[[b, c, oh, ow]] = sum_val / count;
@@ -79,6 +105,7 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    _ceil_mode: bool,
 ) -> NdArrayTensor<E, 4> {
     let [kernel_height, kernel_width] = kernel_size;
     let [stride_height, stride_width] = stride;
@@ -103,8 +130,11 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
                     let ih_start = oh * stride_height;
                     let iw_start = ow * stride_width;
 
-                    let ih_end = ih_start + kernel_height;
-                    let iw_end = iw_start + kernel_width;
+                    let ih_end =
+                        usize::min(ih_start + kernel_height, x_height + 2 * padding_height);
+                    let iw_end = usize::min(iw_start + kernel_width, x_width + 2 * padding_width);
+
+                    let count_padded = (ih_end - ih_start) * (iw_end - iw_start);
 
                     let ih_start = usize::max(ih_start, padding_height);
                     let iw_start = usize::max(iw_start, padding_width);
@@ -113,7 +143,7 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
                     let iw_end = usize::min(iw_end, x_width + padding_width);
 
                     let count = match count_include_pad {
-                        true => kernel_width * kernel_height,
+                        true => count_padded,
                         false => (ih_end - ih_start) * (iw_end - iw_start),
                     };
 
@@ -3,6 +3,7 @@ use burn_tensor::Data;
 use burn_tensor::ElementConversion;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
+use ndarray::ArcArray;
 use ndarray::Array2;
 
 use burn_tensor::Shape;
@@ -10,6 +11,7 @@ use ndarray::Axis;
 use ndarray::Dim;
 use ndarray::IxDyn;
 use ndarray::SliceInfoElem;
+use ndarray::Zip;
 
 use crate::element::NdArrayElement;
 use crate::ops::macros::{keepdim, mean_dim, sum_dim};
@@ -321,15 +323,18 @@ where
         mask: NdArrayTensor<bool, D>,
         source: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul_4tensor = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_mul_4source = mask.array.mapv(|x| match x {
-            true => 1.elem(),
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul_4tensor) + (source.array * mask_mul_4source);
+        let mut array = Self::broadcast_masked(tensor, &[mask.array.shape(), source.array.shape()]);
+
+        // The elements are selected instead of multiplied by the mask, so the non-finite values
+        // that are masked out don't propagate.
+        Zip::from(&mut array)
+            .and_broadcast(&mask.array)
+            .and_broadcast(&source.array)
+            .for_each(|value, &mask, &source| {
+                if mask {
+                    *value = source;
+                }
+            });
 
         NdArrayTensor::new(array)
     }
@@ -339,19 +344,37 @@ where
         mask: NdArrayTensor<bool, D>,
         value: E,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_add = mask.array.mapv(|x| match x {
-            true => value,
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul) + mask_add;
+        let mut array = Self::broadcast_masked(tensor, &[mask.array.shape()]);
+
+        Zip::from(&mut array)
+            .and_broadcast(&mask.array)
+            .for_each(|element, &mask| {
+                if mask {
+                    *element = value;
+                }
+            });
 
         NdArrayTensor::new(array)
     }
 
+    /// The array of the tensor broadcast to the shape it has once combined with the other shapes.
+    fn broadcast_masked<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        others: &[&[usize]],
+    ) -> ArcArray<E, IxDyn> {
+        let mut shape = tensor.array.shape().to_vec();
+        for other in others {
+            for (dim, &other) in shape.iter_mut().zip(other.iter()) {
+                *dim = usize::max(*dim, other);
+            }
+        }
+
+        match tensor.array.shape() == shape.as_slice() {
+            true => tensor.array,
+            false => tensor.array.broadcast(shape).unwrap().to_shared(),
+        }
+    }
+
     fn gather_batch_size<const D: usize>(
         shape_tensor: &Shape<D>,
         shape_indices: &Shape<D>,
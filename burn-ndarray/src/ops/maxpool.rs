@@ -3,7 +3,7 @@ use crate::{
     sharing::UnsafeSharedRef, tensor::NdArrayTensor,
 };
 
-use burn_tensor::ElementConversion;
+use burn_tensor::{ops::conv::calculate_pool_output_size, ElementConversion};
 use ndarray::Array4;
 
 pub(crate) fn max_pool2d<E: FloatNdArrayElement>(
@@ -12,6 +12,7 @@ pub(crate) fn max_pool2d<E: FloatNdArrayElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> NdArrayTensor<E, 4> {
     let [kernel_height, kernel_width] = kernel_size;
     let [padding_height, padding_width] = padding;
@@ -20,12 +21,22 @@ pub(crate) fn max_pool2d<E: FloatNdArrayElement>(
     let [batch_size, channels, x_height, x_width] = x.shape().dims;
     let inf = (-f32::INFINITY).elem::<E>();
 
-    let out_height = ((x_height + 2 * padding_height - dilation_height * (kernel_height - 1) - 1)
-        / stride_height)
-        + 1;
-    let out_width = ((x_width + 2 * padding_width - dilation_width * (kernel_width - 1) - 1)
-        / stride_width)
-        + 1;
+    let out_height = calculate_pool_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        x_height,
+        ceil_mode,
+    );
+    let out_width = calculate_pool_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        x_width,
+        ceil_mode,
+    );
 
     let x = apply_padding_4d(x, padding, inf).array;
 
@@ -46,9 +57,17 @@ pub(crate) fn max_pool2d<E: FloatNdArrayElement>(
                     for kh in 0..kernel_height {
                         let ih = oh * stride_height + kh * dilation_height;
 
+                        if ih >= x_height + 2 * padding_height {
+                            continue;
+                        }
+
                         for kw in 0..kernel_width {
                             let iw = ow * stride_width + kw * dilation_width;
 
+                            if iw >= x_width + 2 * padding_width {
+                                continue;
+                            }
+
                             let val = x[[b, c, ih, iw]];
 
                             if val > max_val {
@@ -72,6 +91,7 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> (NdArrayTensor<E, 4>, NdArrayTensor<i64, 4>) {
     let [kernel_height, kernel_width] = kernel_size;
     let [padding_height, padding_width] = padding;
@@ -80,12 +100,22 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement>(
     let [batch_size, channels, x_height, x_width] = x.shape().dims;
     let inf = (-f32::INFINITY).elem::<E>();
 
-    let out_height = ((x_height + 2 * padding_height - dilation_height * (kernel_height - 1) - 1)
-        / stride_height)
-        + 1;
-    let out_width = ((x_width + 2 * padding_width - dilation_width * (kernel_width - 1) - 1)
-        / stride_width)
-        + 1;
+    let out_height = calculate_pool_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        x_height,
+        ceil_mode,
+    );
+    let out_width = calculate_pool_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        x_width,
+        ceil_mode,
+    );
 
     let x = apply_padding_4d(x, padding, inf).array;
 
@@ -111,8 +141,17 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement>(
                     for kh in 0..kernel_height {
                         let ih = oh * stride_height + kh * dilation_height;
 
+                        if ih >= x_height + 2 * padding_height {
+                            continue;
+                        }
+
                         for kw in 0..kernel_width {
                             let iw = ow * stride_width + kw * dilation_width;
+
+                            if iw >= x_width + 2 * padding_width {
+                                continue;
+                            }
+
                             let val = x[[b, c, ih, iw]];
 
                             if val > max_val {
@@ -121,7 +160,7 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement>(
                                 let ih = ih as i64 - padding_height as i64;
                                 let iw = iw as i64 - padding_width as i64;
 
-                                index = ih * x_height as i64 + iw;
+                                index = ih * x_width as i64 + iw;
                             }
                         }
                     }
@@ -139,12 +178,14 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement>(
     (output, indices)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn max_pool2d_backward<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
     _kernel_size: [usize; 2],
     _stride: [usize; 2],
     _padding: [usize; 2],
     _dilation: [usize; 2],
+    _ceil_mode: bool,
     output_grad: NdArrayTensor<E, 4>,
     indices: NdArrayTensor<i64, 4>,
 ) -> NdArrayTensor<E, 4> {
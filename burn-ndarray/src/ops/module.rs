@@ -32,8 +32,16 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> NdArrayTensor<E, 4> {
-        avg_pool2d(x, kernel_size, stride, padding, count_include_pad)
+        avg_pool2d(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
 
     fn avg_pool2d_backward(
@@ -43,8 +51,17 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> NdArrayTensor<E, 4> {
-        avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+        avg_pool2d_backward(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
 
     fn max_pool2d(
@@ -53,8 +70,9 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> NdArrayTensor<E, 4> {
-        max_pool2d(x, kernel_size, stride, padding, dilation)
+        max_pool2d(x, kernel_size, stride, padding, dilation, ceil_mode)
     }
 
     fn max_pool2d_with_indices(
@@ -63,8 +81,10 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<NdArray<E>> {
-        let (output, indices) = max_pool2d_with_indices(x, kernel_size, stride, padding, dilation);
+        let (output, indices) =
+            max_pool2d_with_indices(x, kernel_size, stride, padding, dilation, ceil_mode);
 
         MaxPool2dWithIndices::new(output, indices)
     }
@@ -75,6 +95,7 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: NdArrayTensor<E, 4>,
         indices: NdArrayTensor<i64, 4>,
     ) -> MaxPool2dBackward<NdArray<E>> {
@@ -84,6 +105,7 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             output_grad,
             indices,
         ))
@@ -14,7 +14,7 @@ use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Sha
 use burn_tensor::{Distribution, Reader};
 
 // External crates
-use libm::{cos, erf, sin, tanh};
+use libm::{ceil, cos, erf, floor, rint, sin, tanh};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -417,6 +417,34 @@ impl<E: FloatNdArrayElement> TensorOps<Self> for NdArray<E> {
         NdArrayTensor::new(array)
     }
 
+    fn floor<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| floor(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn ceil<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| ceil(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn round<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        // `rint` rounds halfway cases to the nearest even integer.
+        let array = tensor
+            .array
+            .mapv_into(|a| rint(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn cat<const D: usize>(tensors: Vec<NdArrayTensor<E, D>>, dim: usize) -> NdArrayTensor<E, D> {
         NdArrayOps::cat(tensors, dim)
     }
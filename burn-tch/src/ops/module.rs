@@ -113,13 +113,14 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> TchTensor<E, 3> {
         let tensor = tch::Tensor::avg_pool1d(
             &x.tensor,
             [kernel_size as i64],
             [stride as i64],
             [padding as i64],
-            false,
+            ceil_mode,
             count_include_pad,
         );
 
@@ -131,13 +132,14 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> TchTensor<E, 4> {
         let tensor = tch::Tensor::avg_pool2d(
             &x.tensor,
             [kernel_size[0] as i64, kernel_size[1] as i64],
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
-            false,
+            ceil_mode,
             count_include_pad,
             None,
         );
@@ -152,6 +154,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> TchTensor<E, 4> {
         let tensor = tch::Tensor::avg_pool2d_backward(
             &x.tensor,
@@ -159,7 +162,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             [kernel_size[0] as i64, kernel_size[1] as i64],
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
-            false,
+            ceil_mode,
             count_include_pad,
             None,
         );
@@ -173,6 +176,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> TchTensor<E, 3> {
         let tensor = tch::Tensor::max_pool1d(
             &x.tensor,
@@ -180,7 +184,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             stride as i64,
             padding as i64,
             dilation as i64,
-            false,
+            ceil_mode,
         );
 
         TchTensor::new(tensor)
@@ -192,6 +196,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<LibTorch<E>> {
         let (tensor, indices) = tch::Tensor::max_pool1d_with_indices(
             &x.tensor,
@@ -199,7 +204,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             stride as i64,
             padding as i64,
             dilation as i64,
-            false,
+            ceil_mode,
         );
 
         MaxPool1dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
@@ -211,6 +216,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> TchTensor<E, 4> {
         let tensor = tch::Tensor::max_pool2d(
             &x.tensor,
@@ -218,7 +224,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
             [dilation[0] as i64, dilation[1] as i64],
-            false,
+            ceil_mode,
         );
 
         TchTensor::new(tensor)
@@ -230,6 +236,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<LibTorch<E>> {
         let (tensor, indices) = tch::Tensor::max_pool2d_with_indices(
             &x.tensor,
@@ -237,7 +244,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
             [dilation[0] as i64, dilation[1] as i64],
-            false,
+            ceil_mode,
         );
 
         MaxPool2dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
@@ -249,6 +256,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: TchTensor<E, 4>,
         indices: TchTensor<i64, 4>,
     ) -> MaxPool2dBackward<LibTorch<E>> {
@@ -259,7 +267,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
             [dilation[0] as i64, dilation[1] as i64],
-            false,
+            ceil_mode,
             &indices.tensor,
         );
 
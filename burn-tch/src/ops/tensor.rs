@@ -174,6 +174,18 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
         TchTensor::new(tensor.tensor.reciprocal())
     }
 
+    fn floor<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.floor())
+    }
+
+    fn ceil<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.ceil())
+    }
+
+    fn round<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.round())
+    }
+
     fn swap_dims<const D: usize>(
         tensor: TchTensor<E, D>,
         dim1: usize,
@@ -8,6 +8,19 @@ pub fn relu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     tensor.relu()
 }
 
+/// Applies the leaky rectified linear unit function.
+///
+/// `leaky_relu(x_i) = x_i if x_i >= 0 else negative_slope * x_i`
+pub fn leaky_relu<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    negative_slope: f64,
+) -> Tensor<B, D> {
+    let mask = tensor.clone().lower_elem(0);
+    tensor
+        .clone()
+        .mask_where(mask, tensor.mul_scalar(negative_slope))
+}
+
 /// Applies the Gaussian Error Linear Units function as described in the paper in [Gaussian Error Linear Units (GELUs)](https://arxiv.org/pdf/1606.08415v3.pdf).
 pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(B::gelu(tensor.primitive))
@@ -109,3 +122,19 @@ pub fn mish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
 pub fn tanh<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     tensor.tanh()
 }
+
+/// Applies the gated linear unit function along the given dimension.
+///
+/// The input is split in two halves `a` and `b` along the dimension and `glu(x) = a * sigmoid(b)`.
+///
+/// # Notes
+///
+/// The size of the dimension `dim` must be even.
+pub fn glu<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    check!(TensorCheck::glu::<D>(&tensor.shape(), dim));
+
+    let half = tensor.dims()[dim] / 2;
+    let gate = sigmoid(tensor.clone().narrow(dim, half, half));
+
+    tensor.narrow(dim, 0, half).mul(gate)
+}
@@ -15,7 +15,10 @@ use core::{fmt::Debug, ops::Range};
 use crate::check::TensorCheck;
 use crate::tensor::api::chunk::chunk;
 use crate::tensor::api::narrow::narrow;
-use crate::{backend::Backend, check, Bool, Data, Float, Int, Shape, TensorKind};
+use crate::tensor::api::split::split;
+use crate::{
+    backend::Backend, check, Bool, Data, Float, FromKind, Int, Shape, TensorError, TensorKind,
+};
 
 /// A tensor with a given backend, shape and data type.
 #[derive(new, Clone, Debug)]
@@ -70,6 +73,15 @@ where
         K::shape(&self.primitive)
     }
 
+    /// Returns the estimated number of bytes occupied by the elements of the tensor.
+    ///
+    /// This is the number of elements times the size of an element, unless the backend reports
+    /// the actual size of its buffers. It can be used to profile a model or to decide which
+    /// activations to checkpoint.
+    pub fn memory_footprint(&self) -> usize {
+        K::memory_footprint(&self.primitive)
+    }
+
     /// Reshape the tensor to have the given shape.
     ///
     /// A `-1` in the shape is used to infer the remaining dimensions, e.g.: `[2, -1]`
@@ -109,7 +121,9 @@ where
         Tensor::new(K::reshape::<D, D2>(self.primitive, shape))
     }
 
-    /// Transpose the tensor.
+    /// Transpose the tensor, swapping its last two dimensions.
+    ///
+    /// For a matrix, this is the usual transpose, the same as `swap_dims(0, 1)`.
     ///
     /// # Arguments
     ///
@@ -132,8 +146,14 @@ where
     ///
     /// # Returns
     ///
-    /// The tensor with the dimensions swapped.
+    /// The tensor with the dimensions swapped, or the tensor unchanged when both dimensions are
+    /// the same.
     pub fn swap_dims(self, dim1: usize, dim2: usize) -> Tensor<B, D, K> {
+        if dim1 == dim2 {
+            check!(TensorCheck::swap_dims::<D>(dim1, dim2));
+            return self;
+        }
+
         Tensor::new(K::swap_dims(self.primitive, dim1, dim2))
     }
 
@@ -192,6 +212,63 @@ where
         Tensor::new(K::reshape::<D, D2>(self.primitive, new_dims.into()))
     }
 
+    /// Flattens the tensor into a 2D tensor, keeping the dimensions before `start_dim` as the
+    /// leading dimension and collapsing the remaining ones into a single trailing dimension.
+    ///
+    /// With a `start_dim` of 1, this is the usual transition from convolutional features to a
+    /// linear layer, preserving the batch dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 4>::ones(Shape::new([2, 3, 4, 4]), &device);
+    ///
+    ///     let flattened_tensor = tensor.flatten_from_dim(1);
+    ///
+    ///     // The resulting tensor will have dimensions (2, 48).
+    ///     println!("{:?}", flattened_tensor.shape());
+    /// }
+    /// ```
+    pub fn flatten_from_dim(self, start_dim: usize) -> Tensor<B, 2, K> {
+        check!(TensorCheck::flatten_from_dim::<D>(start_dim));
+
+        let dims = self.shape().dims;
+        let leading: usize = dims[..start_dim].iter().product();
+        let trailing: usize = dims[start_dim..].iter().product();
+
+        self.reshape([leading, trailing])
+    }
+
+    /// Flattens all the dimensions but the first one, which is kept as the batch dimension.
+    ///
+    /// This is the usual transition from convolutional features to a linear layer, and matches
+    /// the ONNX `Flatten` operator with its default axis of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 4>::ones(Shape::new([8, 3, 4, 4]), &device);
+    ///
+    ///     let flattened_tensor = tensor.flatten_keep_batch();
+    ///
+    ///     // The resulting tensor will have dimensions (8, 48).
+    ///     println!("{:?}", flattened_tensor.shape());
+    /// }
+    /// ```
+    pub fn flatten_keep_batch(self) -> Tensor<B, 2, K> {
+        self.flatten_from_dim(1)
+    }
+
     /// Squeeze the tensor along the given dimension, removing the specified dimension
     /// of size one, and effectively reducing the rank of the tensor by one.
     ///
@@ -422,6 +499,28 @@ where
         Self::into_data(self.clone())
     }
 
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns the values of the current tensor in row-major chunks of `chunk_elems` elements,
+    /// the last chunk being smaller when the number of elements isn't a multiple of it.
+    ///
+    /// Each chunk is only read back when the iterator reaches it, which bounds the host memory
+    /// used to inspect a large tensor to the size of a chunk.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_elems` is zero.
+    pub fn into_data_chunks(self, chunk_elems: usize) -> impl Iterator<Item = Vec<K::Elem>> {
+        assert!(chunk_elems > 0, "The chunk size must be greater than zero");
+
+        let num_elems = self.shape().num_elements();
+        let flattened: Tensor<B, 1, K> = self.reshape([num_elems]);
+
+        (0..num_elems).step_by(chunk_elems).map(move |start| {
+            let end = usize::min(start + chunk_elems, num_elems);
+            flattened.clone().slice([start..end]).into_data().value
+        })
+    }
+
     /// Create a tensor from the given data on the given device.
     pub fn from_data<T>(data: T, device: &B::Device) -> Self
     where
@@ -439,6 +538,68 @@ where
         Self::new(K::repeat(self.primitive, dim, times))
     }
 
+    /// Expand the tensor to the given shape by repeating the dimensions of size 1.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension of the tensor is neither 1 nor equal to the target dimension.
+    pub fn expand<S: Into<Shape<D>>>(self, shape: S) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::expand(&self.shape(), &shape));
+
+        let dims = self.shape().dims;
+        let mut tensor = self;
+
+        for (dim, (size, target)) in dims.iter().zip(shape.dims).enumerate() {
+            if *size != target {
+                tensor = tensor.repeat(dim, target);
+            }
+        }
+
+        tensor
+    }
+
+    /// Assert that the tensor has the expected shape, returning the tensor to chain the
+    /// following operations.
+    ///
+    /// # Panics
+    ///
+    /// If the shape of the tensor is different from the expected shape.
+    pub fn assert_shape(self, expected: [usize; D]) -> Self {
+        check!(TensorCheck::assert_shape(
+            &self.shape(),
+            &Shape::new(expected)
+        ));
+        self
+    }
+
+    /// Expand the tensor to the shape of the `other` tensor.
+    ///
+    /// See [expand](Tensor::expand).
+    pub fn expand_as<K2: BasicOps<B>>(self, other: &Tensor<B, D, K2>) -> Self {
+        self.expand(other.shape())
+    }
+
+    /// Reshape the tensor to the shape of the `other` tensor.
+    ///
+    /// See [reshape](Tensor::reshape).
+    pub fn reshape_as<const D2: usize, K2: BasicOps<B>>(
+        self,
+        other: &Tensor<B, D2, K2>,
+    ) -> Tensor<B, D2, K> {
+        self.reshape(other.shape())
+    }
+
+    /// Convert the tensor to the kind of the `other` tensor.
+    ///
+    /// Converting to a bool tensor maps every non-zero value to `true`.
+    pub fn type_as<const D2: usize, K2: FromKind<B, K>>(
+        self,
+        _other: &Tensor<B, D2, K2>,
+    ) -> Tensor<B, D, K2> {
+        Tensor::new(K2::from_kind(self.primitive))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Panics
@@ -449,6 +610,34 @@ where
         K::equal(self.primitive, other.primitive)
     }
 
+    /// Applies element wise not-equal comparison and returns a boolean tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have the same shape.
+    pub fn not_equal(self, other: Self) -> Tensor<B, D, Bool> {
+        check!(TensorCheck::binary_ops_ew("NotEqual", &self, &other));
+        K::equal(self.primitive, other.primitive).bool_not()
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns true if both tensors have the same shape and all their elements are equal.
+    ///
+    /// Unlike [equal](Tensor::equal), tensors with different shapes are not equal instead of
+    /// panicking. The elements are compared exactly, including for float tensors.
+    pub fn all_equal(&self, other: &Self) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+
+        self.clone()
+            .equal(other.clone())
+            .into_data()
+            .value
+            .into_iter()
+            .all(|equal| equal)
+    }
+
     /// Concatenates all tensors into a new one along the given dimension.
     ///
     /// # Panics
@@ -463,6 +652,17 @@ where
         ))
     }
 
+    /// Concatenates all tensors into a new one along the given dimension, or return an error if
+    /// the tensors don't have the same shape instead of panicking.
+    pub fn checked_cat(tensors: Vec<Self>, dim: usize) -> Result<Self, TensorError> {
+        TensorCheck::cat(&tensors, dim).into_result()?;
+
+        Ok(Self::new(K::cat(
+            tensors.into_iter().map(|vector| vector.primitive).collect(),
+            dim,
+        )))
+    }
+
     /// Concatenates all tensors into a new one along a new dimension.
     ///
     /// # Panics
@@ -524,6 +724,146 @@ where
             .map(|v| Self::new(v))
             .collect()
     }
+
+    /// Splits the tensor along the given dimension into parts of the given sizes.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is greater than the number of dimensions of the tensor.
+    /// - If one of the sizes is zero.
+    /// - If the sizes don't sum to the size of the tensor along the given dimension.
+    ///
+    /// # Returns
+    /// A vector of tensors, one for each size.
+    pub fn split(self, sizes: &[usize], dim: usize) -> Vec<Self> {
+        check!(TensorCheck::dim_ops::<D>("split", dim));
+        check!(TensorCheck::split(&self.shape(), sizes, dim));
+        split::<B, D, K>(self.primitive, sizes, dim)
+            .into_iter()
+            .map(|v| Self::new(v))
+            .collect()
+    }
+
+    /// Circularly shifts the elements of the tensor along the given dimension.
+    ///
+    /// Elements shifted beyond the last position are re-introduced at the first position, and a
+    /// negative shift moves the elements towards the start of the dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape where the elements are shifted along the given dimension.
+    pub fn roll(self, shift: i64, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("roll", dim));
+
+        let size = self.dims()[dim];
+        if size == 0 {
+            return self;
+        }
+
+        let shift = shift.rem_euclid(size as i64) as usize;
+        self.circular_shift(shift, dim)
+    }
+
+    /// Shifts the zero-frequency component of a spectrum to the center of the given dimension.
+    ///
+    /// This is the equivalent of `torch.fft.fftshift` for a single dimension and is reverted with
+    /// [ifftshift](Tensor::ifftshift).
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    pub fn fftshift(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("fftshift", dim));
+
+        let shift = self.dims()[dim] / 2;
+        self.circular_shift(shift, dim)
+    }
+
+    /// Shifts the zero-frequency component of a centered spectrum back to the start of the given
+    /// dimension.
+    ///
+    /// This is the inverse of [fftshift](Tensor::fftshift), which only differs for dimensions of
+    /// odd length.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    pub fn ifftshift(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("ifftshift", dim));
+
+        let size = self.dims()[dim];
+        self.circular_shift(size - size / 2, dim)
+    }
+
+    /// Moves the last `shift` elements along the given dimension to its start, where `shift` is at
+    /// most the size of the dimension.
+    fn circular_shift(self, shift: usize, dim: usize) -> Self {
+        let size = self.dims()[dim];
+        if shift == 0 || shift == size {
+            return self;
+        }
+
+        let split = size - shift;
+        let tail = self.clone().narrow(dim, split, shift);
+        let head = self.narrow(dim, 0, split);
+
+        Tensor::cat(vec![tail, head], dim)
+    }
+}
+
+impl<B, K> Tensor<B, 1, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Creates the coordinate grids of the current tensor and the `other` tensor, using the
+    /// matrix (`ij`) indexing.
+    ///
+    /// For inputs of sizes `n` and `m`, both grids have the shape `[n, m]`. The first grid repeats
+    /// the current tensor along the columns and the second grid repeats the `other` tensor along
+    /// the rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let rows = Tensor::<B, 1, Int>::arange(0..2, &device);
+    ///     let cols = Tensor::<B, 1, Int>::arange(0..3, &device);
+    ///     let (grid_rows, grid_cols) = rows.meshgrid(cols);
+    ///     println!("{}", grid_rows.to_data());
+    ///     // [[0, 0, 0], [1, 1, 1]]
+    ///     println!("{}", grid_cols.to_data());
+    ///     // [[0, 1, 2], [0, 1, 2]]
+    /// }
+    /// ```
+    pub fn meshgrid(self, other: Self) -> (Tensor<B, 2, K>, Tensor<B, 2, K>) {
+        let [n] = self.dims();
+        let [m] = other.dims();
+
+        let grid_self = self.reshape([n, 1]).expand([n, m]);
+        let grid_other = other.reshape([1, m]).expand([n, m]);
+
+        (grid_self, grid_other)
+    }
+}
+
+impl<B, K> Tensor<B, 2, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Transpose the matrix, a shorthand for [transpose](Tensor::transpose).
+    pub fn t(self) -> Self {
+        self.transpose()
+    }
 }
 
 /// Iterator given by (Tensor::iter_dim).
@@ -867,6 +1207,27 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D>;
 
+    /// Returns the number of bytes occupied by the elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The estimated memory footprint of the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the memory footprint of a tensor, users should prefer the
+    /// [Tensor::memory_footprint](Tensor::memory_footprint) function, which is more high-level and
+    /// designed for public use.
+    fn memory_footprint<const D: usize>(tensor: &Self::Primitive<D>) -> usize;
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -1144,6 +1505,10 @@ impl<B: Backend> BasicOps<B> for Float {
         B::shape(tensor)
     }
 
+    fn memory_footprint<const D: usize>(tensor: &Self::Primitive<D>) -> usize {
+        B::memory_footprint(tensor)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
@@ -1231,6 +1596,10 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_shape(tensor)
     }
 
+    fn memory_footprint<const D: usize>(tensor: &Self::Primitive<D>) -> usize {
+        B::int_memory_footprint(tensor)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
@@ -1318,6 +1687,10 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_shape(tensor)
     }
 
+    fn memory_footprint<const D: usize>(tensor: &Self::Primitive<D>) -> usize {
+        B::bool_memory_footprint(tensor)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
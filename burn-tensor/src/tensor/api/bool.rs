@@ -1,3 +1,7 @@
+use alloc::vec::Vec;
+
+use crate::check;
+use crate::check::TensorCheck;
 use crate::{backend::Backend, Bool, Data, Int, Tensor};
 
 impl<B, const D: usize> Tensor<B, D, Bool>
@@ -23,4 +27,87 @@ where
     pub fn bool_not(self) -> Self {
         Tensor::new(B::bool_not(self.primitive))
     }
+
+    /// Applies the element wise logical and with another tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors can't be broadcasted to the same shape.
+    pub fn bool_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolAnd", &self, &other));
+        Tensor::new(B::bool_and(self.primitive, other.primitive))
+    }
+
+    /// Applies the element wise logical or with another tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors can't be broadcasted to the same shape.
+    pub fn bool_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolOr", &self, &other));
+        Tensor::new(B::bool_or(self.primitive, other.primitive))
+    }
+
+    /// Applies the element wise logical exclusive or with another tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors can't be broadcasted to the same shape.
+    pub fn bool_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolXor", &self, &other));
+        Tensor::new(B::bool_xor(self.primitive, other.primitive))
+    }
+
+    /// Tests if any element of the tensor is true.
+    pub fn any(self) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::bool_any(self.primitive))
+    }
+
+    /// Tests if any element along the given dimension is true, keeping the dimension with a size
+    /// of 1.
+    pub fn any_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Any", dim));
+        Tensor::new(B::bool_any_dim(self.primitive, dim))
+    }
+
+    /// Tests if all the elements of the tensor are true.
+    pub fn all(self) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::bool_all(self.primitive))
+    }
+
+    /// Tests if all the elements along the given dimension are true, keeping the dimension with a
+    /// size of 1.
+    pub fn all_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("All", dim));
+        Tensor::new(B::bool_all_dim(self.primitive, dim))
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns the coordinates of the true elements, in row-major order.
+    ///
+    /// # Notes
+    ///
+    /// The tensor is read back from the device, so the coordinates can be used directly by logic
+    /// running on the host, e.g. to extract bounding boxes from a mask.
+    pub fn argwhere(self) -> Vec<[usize; D]> {
+        let data = self.into_data();
+        let dims = data.shape.dims;
+
+        data.value
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| *value)
+            .map(|(index, _)| {
+                let mut coordinates = [0; D];
+                let mut remainder = index;
+
+                for (coordinate, size) in coordinates.iter_mut().zip(dims).rev() {
+                    *coordinate = remainder % size;
+                    remainder /= size;
+                }
+
+                coordinates
+            })
+            .collect()
+    }
 }
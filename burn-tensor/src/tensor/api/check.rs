@@ -1,4 +1,4 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{backend::Backend, BasicOps, Bool, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -49,6 +49,35 @@ impl TensorCheck {
             .binary_ops_ew_shape(ops, &lhs.shape(), &rhs.shape())
     }
 
+    /// Checks device and shape equality for the condition and both sources of a select where.
+    pub(crate) fn select_where<B: Backend, const D: usize, K: BasicOps<B>>(
+        condition: &Tensor<B, D, Bool>,
+        a: &Tensor<B, D, K>,
+        b: &Tensor<B, D, K>,
+    ) -> Self {
+        let ops = "Select Where";
+        let mut check = Self::Ok
+            .binary_ops_device(ops, &a.device(), &b.device())
+            .binary_ops_device(ops, &condition.device(), &a.device());
+
+        let shape_condition = condition.shape();
+        let shape_a = a.shape();
+        let shape_b = b.shape();
+
+        if shape_condition != shape_a || shape_a != shape_b {
+            check = check.register(
+                ops,
+                TensorError::new("The condition and both source tensors must have the same shape.")
+                    .details(format!(
+                        "Condition shape {:?}, a shape {:?}, b shape {:?}.",
+                        shape_condition.dims, shape_a.dims, shape_b.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn into_scalar<const D: usize>(shape: &Shape<D>) -> Self {
         let mut check = Self::Ok;
 
@@ -125,6 +154,34 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn split<const D: usize>(shape: &Shape<D>, sizes: &[usize], dim: usize) -> Self {
+        let mut check = Self::Ok;
+        let total: usize = sizes.iter().sum();
+
+        if sizes.contains(&0) {
+            check = check.register(
+                "Split",
+                TensorError::new("The split sizes must be greater than zero.")
+                    .details(format!("Split sizes: {sizes:?}.")),
+            );
+        }
+
+        if total != shape.dims[dim] {
+            check = check.register(
+                "Split",
+                TensorError::new(format!(
+                    "The split sizes must sum to the size of the tensor along dimension {dim}."
+                ))
+                .details(format!(
+                    "Split sizes {sizes:?} sum to {total}, but the tensor shape is {:?}.",
+                    shape.dims
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn reshape_args_usize<const D1: usize, const D2: usize>(
         original: &Shape<D1>,
         target: &Shape<D2>,
@@ -216,6 +273,77 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn expand<const D: usize>(shape: &Shape<D>, to: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        for i in 0..D {
+            let size = shape.dims[i];
+            let target = to.dims[i];
+
+            if size != target && size != 1 {
+                check = check.register(
+                    "Expand",
+                    TensorError::new(
+                        "Only the dimensions of size 1 can be expanded to a different size.",
+                    )
+                    .details(format!(
+                        "Tensor shape: {:?}, target shape: {:?}, invalid dimension: {i}.{}",
+                        shape.dims,
+                        to.dims,
+                        rank_alignment_hint("expanded", shape, to).unwrap_or_default()
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn assert_shape<const D: usize>(shape: &Shape<D>, expected: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != expected {
+            check = check.register(
+                "Assert Shape",
+                TensorError::new("The tensor doesn't have the expected shape.").details(format!(
+                    "Expected shape {:?}, got shape {:?}.",
+                    expected.dims, shape.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn glu<const D: usize>(shape: &Shape<D>, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("glu", dim);
+
+        if dim < D && shape.dims[dim] % 2 == 1 {
+            check = check.register(
+                "glu",
+                TensorError::new("The dimension to split in halves must have an even size.")
+                    .details(format!("Tensor shape: {:?}, dimension: {dim}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn flatten_from_dim<const D: usize>(start_dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if start_dim > D {
+            check = check.register(
+                "Flatten",
+                TensorError::new(format!(
+                    "The start dim ({start_dim}) must be smaller or equal to the tensor rank ({D})"
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn tri<const D: usize>() -> Self {
         let mut check = Self::Ok;
 
@@ -280,7 +408,7 @@ impl TensorCheck {
     pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim1 > D || dim2 > D {
+        if dim1 >= D || dim2 >= D {
             check = check.register(
                 "Swap Dims",
                 TensorError::new("The swap dimensions must be smaller than the tensor dimension")
@@ -325,6 +453,25 @@ impl TensorCheck {
             );
         }
 
+        for i in 0..D - 2 {
+            let batch_lhs = shape_lhs.dims[i];
+            let batch_rhs = shape_rhs.dims[i];
+
+            if batch_lhs != batch_rhs && batch_lhs != 1 && batch_rhs != 1 {
+                check = check.register(
+                    "Matmul",
+                    TensorError::new(format!(
+                        "The batch dimension '{i}' of matmul should be the same or 1 to be \
+                         broadcasted, but got {batch_lhs} and {batch_rhs}."
+                    ))
+                    .details(format!(
+                        "Lhs shape {:?}, rhs shape {:?}.",
+                        shape_lhs.dims, shape_rhs.dims
+                    )),
+                );
+            }
+        }
+
         check
     }
 
@@ -577,6 +724,62 @@ impl TensorCheck {
         Self::check_gather_scatter_indices(Self::Ok, "Gather", dim, shape, shape_indices)
     }
 
+    pub(crate) fn gather_nd<const D: usize>(shape_indices: &Shape<2>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_indices.dims[1] != D {
+            check = check.register(
+                "Gather ND",
+                TensorError::new(
+                    "Each index should be a full coordinate into the tensor.".to_string(),
+                )
+                .details(format!(
+                    "The last dimension of the indices tensor ({}) should be equal to the \
+                     number of dimensions of the tensor ({D}).",
+                    shape_indices.dims[1]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn scatter_nd<const D: usize>(
+        shape_indices: &Shape<2>,
+        shape_values: &Shape<1>,
+    ) -> Self {
+        let ops = "Scatter ND";
+        let mut check = Self::Ok;
+
+        if shape_indices.dims[1] != D {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "Each index should be a full coordinate into the tensor.".to_string(),
+                )
+                .details(format!(
+                    "The last dimension of the indices tensor ({}) should be equal to the \
+                     number of dimensions of the tensor ({D}).",
+                    shape_indices.dims[1]
+                )),
+            );
+        }
+
+        if shape_indices.dims[0] != shape_values.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new("There should be one value per index.".to_string()).details(
+                    format!(
+                        "The number of indices ({}) differs from the number of values ({}).",
+                        shape_indices.dims[0], shape_values.dims[0]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn scatter<const D: usize>(
         dim: usize,
         shape: &Shape<D>,
@@ -665,6 +868,222 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn clamp_tensor<const D: usize>(
+        shape: &Shape<D>,
+        min: &Shape<D>,
+        max: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (name, bound) in [("min", min), ("max", max)] {
+            for i in 0..D {
+                if bound.dims[i] != shape.dims[i] && bound.dims[i] != 1 {
+                    check = check.register(
+                        "ClampTensor",
+                        TensorError::new(format!(
+                            "The {name} bound can't be broadcasted to the shape of the tensor."
+                        ))
+                        .details(format!(
+                            "Tensor shape: {:?}, {name} shape: {:?}, invalid dimension: {i}.{}",
+                            shape.dims,
+                            bound.dims,
+                            rank_alignment_hint(name, bound, shape).unwrap_or_default()
+                        )),
+                    );
+                }
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn diag(shape: &Shape<2>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.dims[0] != shape.dims[1] {
+            check = check.register(
+                "Diag",
+                TensorError::new("The diagonal can only be extracted from a square matrix.")
+                    .details(format!("Tensor shape: {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn one_hot(num_classes: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if num_classes == 0 {
+            check = check.register(
+                "OneHot",
+                TensorError::new("The number of classes must be greater than zero."),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the output size of an adaptive pooling, which must be nonzero.
+    pub(crate) fn adaptive_pool<const N: usize>(ops: &str, output_size: [usize; N]) -> Self {
+        let mut check = Self::Ok;
+
+        if output_size.contains(&0) {
+            check = check.register(
+                ops,
+                TensorError::new("The output size must be nonzero.")
+                    .details(format!("Output size: {output_size:?}.")),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the inputs of a bilinear transformation against the weight of shape
+    /// `[d_output, d_input1, d_input2]` and the bias of shape `[d_output]`.
+    pub(crate) fn bilinear(
+        x1: &Shape<2>,
+        x2: &Shape<2>,
+        weight: &Shape<3>,
+        bias: Option<&Shape<1>>,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let [d_output, d_input1, d_input2] = weight.dims;
+
+        if x1.dims[0] != x2.dims[0] {
+            check = check.register(
+                "Bilinear",
+                TensorError::new("The inputs must have the same batch size.").details(format!(
+                    "Input1 shape {:?}, input2 shape {:?}.",
+                    x1.dims, x2.dims
+                )),
+            );
+        }
+
+        if x1.dims[1] != d_input1 || x2.dims[1] != d_input2 {
+            check = check.register(
+                "Bilinear",
+                TensorError::new(
+                    "The input features must match the dimensions 1 and 2 of the weight.",
+                )
+                .details(format!(
+                    "Input1 shape {:?}, input2 shape {:?}, weight shape {:?}.",
+                    x1.dims, x2.dims, weight.dims
+                )),
+            );
+        }
+
+        if let Some(bias) = bias {
+            if bias.dims[0] != d_output {
+                check = check.register(
+                    "Bilinear",
+                    TensorError::new("The bias must match the dimension 0 of the weight.").details(
+                        format!(
+                            "Bias shape {:?}, weight shape {:?}.",
+                            bias.dims, weight.dims
+                        ),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks that the window of a local response normalization covers at least one channel.
+    pub(crate) fn local_response_norm(size: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if size == 0 {
+            check = check.register(
+                "Local Response Norm",
+                TensorError::new("The size of the window must be nonzero.")
+                    .details(format!("Size: {size}.")),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the kernel and the stride of a 1D pooling over a `[batch_size, channels, length]`
+    /// input.
+    pub(crate) fn pool1d(
+        ops: &str,
+        shape: &Shape<3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> Self {
+        Self::pool(
+            ops,
+            [shape.dims[2]],
+            [kernel_size],
+            [stride],
+            [padding],
+            [dilation],
+        )
+    }
+
+    /// Checks the kernel and the stride of a 2D pooling over a
+    /// `[batch_size, channels, height, width]` input.
+    pub(crate) fn pool2d(
+        ops: &str,
+        shape: &Shape<4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> Self {
+        Self::pool(
+            ops,
+            [shape.dims[2], shape.dims[3]],
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        )
+    }
+
+    fn pool<const N: usize>(
+        ops: &str,
+        input_size: [usize; N],
+        kernel_size: [usize; N],
+        stride: [usize; N],
+        padding: [usize; N],
+        dilation: [usize; N],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for i in 0..N {
+            let padded_size = input_size[i] + 2 * padding[i];
+            let dilated_kernel_size = dilation[i] * (kernel_size[i].max(1) - 1) + 1;
+
+            if kernel_size[i] == 0 || dilated_kernel_size > padded_size {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The kernel size must be nonzero and fit in the padded input.",
+                    )
+                    .details(format!(
+                        "Input size: {input_size:?}, kernel size: {kernel_size:?}, padding: \
+                         {padding:?}, dilation: {dilation:?}, invalid dimension: {i}."
+                    )),
+                );
+            }
+
+            if stride[i] == 0 {
+                check = check.register(
+                    ops,
+                    TensorError::new("The stride must be nonzero.")
+                        .details(format!("Stride: {stride:?}, invalid dimension: {i}.")),
+                );
+            }
+        }
+
+        check
+    }
+
     /// Checks aggregate dimension such as mean and sum.
     pub(crate) fn aggregate_dim<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
@@ -681,6 +1100,33 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn aggregate_dims<const D: usize>(ops: &str, dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, dim) in dims.iter().enumerate() {
+            if *dim >= D {
+                check = check.register(
+                    ops,
+                    TensorError::new(format!(
+                        "Can't aggregate a tensor with ({D}) dimensions on axis ({dim})"
+                    )),
+                );
+            }
+
+            if dims[..i].contains(dim) {
+                check = check.register(
+                    ops,
+                    TensorError::new("The same dimension can't be aggregated more than once.")
+                        .details(format!(
+                            "Dimensions: {dims:?}, duplicated dimension: {dim}."
+                        )),
+                );
+            }
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -699,6 +1145,16 @@ impl TensorCheck {
         })
     }
 
+    /// Returns the failed check as an error instead of panicking, for the operations that can
+    /// recover from it. The error has the same message that the [check](crate::check) macro would
+    /// have printed.
+    pub(crate) fn into_result(self) -> Result<(), TensorError> {
+        match self {
+            Self::Ok => Ok(()),
+            Self::Failed(failed) => Err(TensorError::new(failed.format())),
+        }
+    }
+
     /// Checks if shapes are compatible for element wise operations supporting broadcasting.
     pub(crate) fn binary_ops_ew_shape<const D: usize>(
         self,
@@ -719,13 +1175,17 @@ impl TensorCheck {
                     continue;
                 }
 
+                let hint = rank_alignment_hint("rhs", rhs, lhs)
+                    .or_else(|| rank_alignment_hint("lhs", lhs, rhs))
+                    .unwrap_or_default();
+
                 check = check.register(
                     ops,
                     TensorError::new("The provided tensors have incompatible shapes.").details(
                         format!(
                             "Incompatible size at dimension '{}' => '{} != {}', which can't be \
-                             broadcasted. Lhs tensor shape {:?}, Rhs tensor shape {:?}.",
-                            i, d_lhs, d_rhs, lhs.dims, rhs.dims,
+                             broadcasted. Lhs tensor shape {:?}, Rhs tensor shape {:?}.{}",
+                            i, d_lhs, d_rhs, lhs.dims, rhs.dims, hint,
                         ),
                     ),
                 );
@@ -754,6 +1214,33 @@ impl TensorCheck {
     }
 }
 
+/// Suggests how to align the dimensions of a tensor that can't be broadcasted to the target shape,
+/// when it looks like a lower rank tensor unsqueezed on the wrong dimension, e.g. `[1, 4]` instead
+/// of `[4, 1]` to be broadcasted to `[4, 3]`.
+fn rank_alignment_hint<const D: usize>(
+    name: &str,
+    shape: &Shape<D>,
+    target: &Shape<D>,
+) -> Option<String> {
+    // The dimensions of the lower rank tensor, without the dimensions of size 1 around them
+    let start = shape.dims.iter().position(|&size| size != 1)?;
+    let end = shape.dims.iter().rposition(|&size| size != 1)? + 1;
+    let dims = &shape.dims[start..end];
+
+    let offset = (0..=D - dims.len())
+        .filter(|&offset| offset != start)
+        .find(|&offset| dims == &target.dims[offset..offset + dims.len()])?;
+
+    let mut aligned = [1; D];
+    aligned[offset..offset + dims.len()].copy_from_slice(dims);
+
+    Some(format!(
+        " Hint: the {name} tensor looks like a {:?} tensor unsqueezed on the wrong dimension, \
+         consider unsqueeze to align ranks, e.g. to the shape {:?}.",
+        dims, aligned
+    ))
+}
+
 pub(crate) struct FailedTensorCheck {
     ops: String,
     errors: Vec<TensorError>,
@@ -772,7 +1259,10 @@ impl FailedTensorCheck {
     }
 }
 
-struct TensorError {
+/// The error of a failed tensor check, returned by the operations that can recover from it, such
+/// as [checked_add](crate::Tensor::checked_add), instead of panicking.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorError {
     description: String,
     details: Option<String>,
 }
@@ -804,6 +1294,22 @@ impl TensorError {
     }
 }
 
+impl core::fmt::Display for TensorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.description.as_str())?;
+
+        if let Some(details) = &self.details {
+            write!(f, " {details}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// TODO: Move from std to core after Error is core (see https://github.com/rust-lang/rust/issues/103765)
+#[cfg(feature = "std")]
+impl std::error::Error for TensorError {}
+
 /// We use a macro for all checks, since the panic message file and line number will match the
 /// function that does the check instead of a the generic error.rs crate private unrelated file
 /// and line number.
@@ -852,6 +1358,84 @@ mod tests {
         check!(TensorCheck::slice(&Shape::new([3, 5]), &[0..1, 0..1, 0..1]));
     }
 
+    #[test]
+    #[should_panic]
+    fn aggregate_dims_duplicated() {
+        check!(TensorCheck::aggregate_dims::<3>("Sum", &[0, 2, 0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn aggregate_dims_exceed_rank() {
+        check!(TensorCheck::aggregate_dims::<3>("Sum", &[0, 3]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_tensor_bound_larger_than_tensor() {
+        check!(TensorCheck::clamp_tensor(
+            &Shape::new([3, 1]),
+            &Shape::new([1, 1]),
+            &Shape::new([1, 4])
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn diag_non_square_matrix() {
+        check!(TensorCheck::diag(&Shape::new([2, 3])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn one_hot_without_classes() {
+        check!(TensorCheck::one_hot(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn adaptive_pool1d_with_zero_output_size() {
+        check!(TensorCheck::adaptive_pool("AdaptiveAvgPool1d", [0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pool1d_kernel_larger_than_padded_input() {
+        check!(TensorCheck::pool1d(
+            "MaxPool1d",
+            &Shape::new([2, 3, 4]),
+            7,
+            1,
+            1,
+            1
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pool2d_zero_stride() {
+        check!(TensorCheck::pool2d(
+            "MaxPool2d",
+            &Shape::new([2, 3, 4, 4]),
+            [2, 2],
+            [1, 0],
+            [0, 0],
+            [1, 1]
+        ));
+    }
+
+    #[test]
+    fn pool2d_kernel_fits_padded_input() {
+        check!(TensorCheck::pool2d(
+            "MaxPool2d",
+            &Shape::new([2, 3, 4, 4]),
+            [6, 3],
+            [1, 1],
+            [1, 0],
+            [1, 1]
+        ));
+    }
+
     #[test]
     #[should_panic]
     fn binary_ops_shapes_no_broadcast() {
@@ -873,6 +1457,42 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[should_panic(expected = "consider unsqueeze to align ranks, e.g. to the shape [4, 1]")]
+    fn binary_ops_shapes_with_rank_alignment_hint() {
+        check!(TensorCheck::binary_ops_ew_shape(
+            TensorCheck::Ok,
+            "Test",
+            &Shape::new([4, 3]),
+            &Shape::new([1, 4])
+        ));
+    }
+
+    #[test]
+    fn binary_ops_shapes_without_rank_alignment_hint() {
+        let check = TensorCheck::binary_ops_ew_shape(
+            TensorCheck::Ok,
+            "Test",
+            &Shape::new([4, 3]),
+            &Shape::new([1, 5]),
+        );
+
+        match check {
+            TensorCheck::Failed(failed) => assert!(!failed.format().contains("Hint")),
+            TensorCheck::Ok => panic!("The shapes should be incompatible"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the max tensor looks like a [2] tensor unsqueezed")]
+    fn clamp_tensor_with_rank_alignment_hint() {
+        check!(TensorCheck::clamp_tensor(
+            &Shape::new([2, 3]),
+            &Shape::new([1, 1]),
+            &Shape::new([1, 2])
+        ));
+    }
+
     #[test]
     #[should_panic]
     fn binary_ops_devices() {
@@ -1,4 +1,4 @@
-use super::narrow::narrow;
+use super::split::split;
 use crate::{backend::Backend, BasicOps, TensorKind};
 use alloc::vec::Vec;
 
@@ -26,44 +26,16 @@ pub fn chunk<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
     dim: usize,
 ) -> Vec<K::Primitive<D>> {
     let size = K::shape(&tensor).dims[dim];
-    if size < chunks {
-        return (0..size)
-            .map(|i| narrow::<B, D, K>(tensor.clone(), dim, i, 1))
-            .collect();
-    }
 
-    let mut tensors = Vec::with_capacity(chunks);
-    let mut sum_chunk_size = 0;
-    if size % chunks == 0 {
-        let chunk_size = size / chunks;
-        for _ in 0..chunks {
-            tensors.push(narrow::<B, D, K>(
-                tensor.clone(),
-                dim,
-                sum_chunk_size,
-                chunk_size,
-            ));
-            sum_chunk_size += chunk_size;
-        }
-    } else {
-        let chunk_size = (size / chunks) + 1; // assumes not divisible
-        for _ in 0..chunks - 1 {
-            tensors.push(narrow::<B, D, K>(
-                tensor.clone(),
-                dim,
-                sum_chunk_size,
-                chunk_size,
-            ));
-            sum_chunk_size += chunk_size;
-        }
-        let remainder = size % chunk_size;
-        tensors.push(narrow::<B, D, K>(
-            tensor.clone(),
-            dim,
-            sum_chunk_size,
-            remainder,
-        ));
-    }
+    // All chunks have the same size, except for the last one which takes the remaining elements.
+    // Less chunks than requested are returned when there aren't enough elements to fill them.
+    // `usize::div_ceil` requires a newer Rust version than the one supported.
+    #[allow(clippy::manual_div_ceil)]
+    let chunk_size = ((size + chunks - 1) / chunks).max(1);
+    let sizes = (0..size)
+        .step_by(chunk_size)
+        .map(|start| usize::min(chunk_size, size - start))
+        .collect::<Vec<_>>();
 
-    tensors
+    split::<B, D, K>(tensor, &sizes, dim)
 }
@@ -6,8 +6,10 @@ use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Data, Distribution, Shape};
+use crate::Bool;
 use crate::Int;
 use crate::Tensor;
+use crate::TensorError;
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -41,6 +43,9 @@ where
     /// Applies element wise natural log operation *ln*.
     ///
     /// `y = log(x)`
+    ///
+    /// Following the backend float semantics, the log of zero is `-inf` and the log of a negative
+    /// value is `NaN`.
     pub fn log(self) -> Self {
         Self::new(B::log(self.primitive))
     }
@@ -48,6 +53,8 @@ where
     /// Applies the natural logarithm of one plus the input tensor, element-wise.
     ///
     /// `y = log(x+1)`
+    ///
+    /// Following the backend float semantics, the result is `-inf` for `-1` and `NaN` below `-1`.
     pub fn log1p(self) -> Self {
         Self::new(B::log1p(self.primitive))
     }
@@ -71,7 +78,27 @@ where
         Self::new(B::recip(self.primitive))
     }
 
+    /// Applies element wise floor operation, rounding down to the nearest integer.
+    pub fn floor(self) -> Self {
+        Self::new(B::floor(self.primitive))
+    }
+
+    /// Applies element wise ceil operation, rounding up to the nearest integer.
+    pub fn ceil(self) -> Self {
+        Self::new(B::ceil(self.primitive))
+    }
+
+    /// Applies element wise round operation, rounding to the nearest integer.
+    ///
+    /// Halfway cases are rounded to the nearest even integer, e.g. `2.5` becomes `2.0` and
+    /// `-2.5` becomes `-2.0`.
+    pub fn round(self) -> Self {
+        Self::new(B::round(self.primitive))
+    }
+
     /// Applies element wise root square operation.
+    ///
+    /// Following the backend float semantics, the square root of a negative value is `NaN`.
     pub fn sqrt(self) -> Self {
         Self::new(B::sqrt(self.primitive))
     }
@@ -91,6 +118,25 @@ where
         Self::new(B::tanh(self.primitive))
     }
 
+    /// Returns a boolean tensor which is true where the elements are NaN.
+    pub fn is_nan(self) -> Tensor<B, D, Bool> {
+        Tensor::new(B::is_nan(self.primitive))
+    }
+
+    /// Returns a boolean tensor which is true where the elements are positive or negative
+    /// infinity.
+    pub fn is_inf(self) -> Tensor<B, D, Bool> {
+        Tensor::new(B::is_inf(self.primitive))
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns true if any element of the tensor is NaN.
+    ///
+    /// The NaN mask is reduced on the device, so only a single value is read back.
+    pub fn contains_nan(self) -> bool {
+        self.is_nan().any().into_data().value[0]
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -175,12 +221,71 @@ where
     ///
     /// `C = AB`
     ///
+    /// The batch dimensions, all but the last two, are broadcasted: a batch dimension of size 1
+    /// is expanded to the size of the other operand, e.g. `[4, 2, 3]` times `[1, 3, 5]` gives
+    /// `[4, 2, 5]`.
+    ///
     /// # Panics
     ///
     /// If the two tensors dont' have a compatible shape.
     pub fn matmul(self, other: Self) -> Self {
         check!(TensorCheck::matmul(&self, &other));
-        Self::new(B::matmul(self.primitive, other.primitive))
+        let (lhs, rhs) = Self::matmul_broadcast(self, other);
+        Self::new(B::matmul(lhs.primitive, rhs.primitive))
+    }
+
+    /// Applies the matrix multiplication operation, or return an error if the two tensors don't
+    /// have a compatible shape instead of panicking.
+    pub fn checked_matmul(self, other: Self) -> Result<Self, TensorError> {
+        TensorCheck::matmul(&self, &other).into_result()?;
+        let (lhs, rhs) = Self::matmul_broadcast(self, other);
+        Ok(Self::new(B::matmul(lhs.primitive, rhs.primitive)))
+    }
+
+    /// Expands the batch dimensions of size 1 of the operands of a matrix multiplication, so the
+    /// backend always receives the same batch dimensions.
+    fn matmul_broadcast(lhs: Self, rhs: Self) -> (Self, Self) {
+        if D <= 2 {
+            return (lhs, rhs);
+        }
+
+        let mut shape_lhs = lhs.shape();
+        let mut shape_rhs = rhs.shape();
+        if shape_lhs.dims[..D - 2] == shape_rhs.dims[..D - 2] {
+            return (lhs, rhs);
+        }
+
+        for i in 0..D - 2 {
+            let batch = usize::max(shape_lhs.dims[i], shape_rhs.dims[i]);
+            shape_lhs.dims[i] = batch;
+            shape_rhs.dims[i] = batch;
+        }
+
+        (lhs.expand(shape_lhs), rhs.expand(shape_rhs))
+    }
+
+    /// Aggregate all elements in the tensor with the product operation.
+    ///
+    /// The product of an empty tensor is one.
+    pub fn prod(self) -> Tensor<B, 1> {
+        Tensor::new(B::prod(self.primitive))
+    }
+
+    /// Aggregate all elements in the tensor with the sum operation, using the compensated (Kahan)
+    /// summation.
+    ///
+    /// This is slower than [sum](Tensor::sum), but the rounding error doesn't grow with the number
+    /// of elements, which matters for long reductions such as large batch statistics.
+    pub fn sum_stable(self) -> Tensor<B, 1> {
+        Tensor::new(B::sum_stable(self.primitive))
+    }
+
+    /// Aggregate all elements in the tensor with the mean operation, using the compensated (Kahan)
+    /// summation.
+    ///
+    /// See [sum_stable](Tensor::sum_stable).
+    pub fn mean_stable(self) -> Tensor<B, 1> {
+        Tensor::new(B::mean_stable(self.primitive))
     }
 
     /// Calculate the variance along the given dimension.
@@ -207,6 +312,86 @@ where
         (var, mean)
     }
 
+    /// Calculate the standard deviation along the given dimension.
+    pub fn std(self, dim: usize) -> Self {
+        self.var(dim).sqrt()
+    }
+
+    /// Calculate the standard deviation along the given dimension without applying the Bessel’s
+    /// correction.
+    pub fn std_bias(self, dim: usize) -> Self {
+        self.var_bias(dim).sqrt()
+    }
+
+    /// Calculate the standard deviation along the given dimension and also returns the mean.
+    ///
+    /// The mean is computed once and reused to compute the standard deviation.
+    pub fn std_mean(self, dim: usize) -> (Self, Self) {
+        let (var, mean) = self.var_mean(dim);
+        (var.sqrt(), mean)
+    }
+
+    /// Calculate the standard deviation along the given dimension without applying the Bessel’s
+    /// correction and also returns the mean.
+    pub fn std_mean_bias(self, dim: usize) -> (Self, Self) {
+        let (var, mean) = self.var_mean_bias(dim);
+        (var.sqrt(), mean)
+    }
+
+    /// Rescale the values along the given dimension to the `[0, 1]` range.
+    ///
+    /// `y = (x - min) / max(max - min, eps)`
+    ///
+    /// The range is floored to a small epsilon (`1e-7`), so the values along a constant
+    /// dimension become zeros instead of `NaN`.
+    pub fn min_max_normalize(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("MinMaxNormalize", dim));
+
+        let min = self.clone().min_dim(dim);
+        let max = self.clone().max_dim(dim);
+        let range = (max - min.clone()).clamp_min(1e-7);
+
+        (self - min) / range
+    }
+
+    /// Calculate the cumulative log-sum-exp along the given dimension.
+    ///
+    /// `output[..., i, ...] = log(sum_{j <= i}(exp(input[..., j, ...])))`
+    ///
+    /// # Notes
+    ///
+    /// The scan is sequential over the dimension and keeps track of the running maximum, so large
+    /// values don't overflow as with `log(cumsum(exp(x)))`.
+    pub fn cumlogsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumLogSumExp", dim));
+
+        let size = self.dims()[dim];
+        if size == 0 {
+            return self;
+        }
+
+        let mut outputs = Vec::with_capacity(size);
+        let mut current = self.clone().narrow(dim, 0, 1);
+        outputs.push(current.clone());
+
+        for i in 1..size {
+            let value = self.clone().narrow(dim, i, 1);
+            let max = current.clone().maximum(value.clone()).detach();
+            // The maximum of a prefix of -inf values is replaced by zero, so the shifted values
+            // stay -inf instead of NaN.
+            let max = max
+                .clone()
+                .mask_fill(max.equal_elem(f32::NEG_INFINITY), 0.0);
+
+            // log(exp(a) + exp(b)) = max + log(exp(a - max) + exp(b - max))
+            let sum = (current - max.clone()).exp() + (value - max.clone()).exp();
+            current = max + sum.log();
+            outputs.push(current.clone());
+        }
+
+        Tensor::cat(outputs, dim)
+    }
+
     /// Create a random tensor of the given shape on the given device where each element is
     /// sampled from the given distribution.
     pub fn random<S: Into<Shape<D>>>(
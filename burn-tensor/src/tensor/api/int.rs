@@ -1,3 +1,5 @@
+use crate::check;
+use crate::check::TensorCheck;
 use crate::{backend::Backend, Data, Float, Int, Tensor};
 use core::ops::Range;
 
@@ -24,6 +26,39 @@ where
     pub fn arange_step(range: Range<usize>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::arange_step(range, step, device))
     }
+
+    /// Converts the class indices into a one-hot encoded float tensor of shape
+    /// `[num_indices, num_classes]`.
+    ///
+    /// # Notes
+    ///
+    /// The indices are not read back from the device, so they aren't validated. An index outside
+    /// of `0..num_classes` has a backend-specific behavior, it may panic or be ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let targets = Tensor::<B, 1, Int>::from_ints([2, 0, 1], &device);
+    ///     let one_hot = targets.one_hot(3);
+    ///     println!("{}", one_hot.to_data());
+    ///     // [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+    /// }
+    /// ```
+    pub fn one_hot(self, num_classes: usize) -> Tensor<B, 2, Float> {
+        check!(TensorCheck::one_hot(num_classes));
+
+        let device = self.device();
+        let [num_indices] = self.dims();
+        let indices = self.reshape([num_indices, 1]);
+        let values = Tensor::ones([num_indices, 1], &device);
+
+        Tensor::zeros([num_indices, num_classes], &device).scatter(1, indices, values)
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -1,4 +1,4 @@
-use crate::backend::Backend;
+use crate::{backend::Backend, ElementConversion};
 
 /// A type-level representation of the kind of a float tensor
 #[derive(Clone, Debug)]
@@ -41,3 +41,63 @@ impl<B: Backend> TensorKind<B> for Bool {
         "Bool"
     }
 }
+
+/// Conversion of a tensor primitive from the kind `K` into the implementing kind.
+pub trait FromKind<B: Backend, K: TensorKind<B>>: TensorKind<B> {
+    /// Converts a primitive of kind `K` into a primitive of the implementing kind.
+    fn from_kind<const D: usize>(tensor: K::Primitive<D>) -> Self::Primitive<D>;
+}
+
+impl<B: Backend> FromKind<B, Float> for Float {
+    fn from_kind<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        tensor
+    }
+}
+
+impl<B: Backend> FromKind<B, Int> for Float {
+    fn from_kind<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        B::int_into_float(tensor)
+    }
+}
+
+impl<B: Backend> FromKind<B, Bool> for Float {
+    fn from_kind<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        B::bool_into_float(tensor)
+    }
+}
+
+impl<B: Backend> FromKind<B, Float> for Int {
+    fn from_kind<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
+        B::into_int(tensor)
+    }
+}
+
+impl<B: Backend> FromKind<B, Int> for Int {
+    fn from_kind<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
+        tensor
+    }
+}
+
+impl<B: Backend> FromKind<B, Bool> for Int {
+    fn from_kind<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
+        B::bool_into_int(tensor)
+    }
+}
+
+impl<B: Backend> FromKind<B, Float> for Bool {
+    fn from_kind<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::BoolTensorPrimitive<D> {
+        B::bool_not(B::equal_elem(tensor, 0.elem()))
+    }
+}
+
+impl<B: Backend> FromKind<B, Int> for Bool {
+    fn from_kind<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::BoolTensorPrimitive<D> {
+        B::bool_not(B::int_equal_elem(tensor, 0.elem()))
+    }
+}
+
+impl<B: Backend> FromKind<B, Bool> for Bool {
+    fn from_kind<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> B::BoolTensorPrimitive<D> {
+        tensor
+    }
+}
@@ -9,10 +9,15 @@ mod int;
 mod kind;
 mod narrow;
 mod numeric;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod sort;
+mod split;
 
 pub use autodiff::*;
 pub use base::*;
+pub use check::TensorError;
 pub use chunk::chunk;
 pub use kind::*;
 pub use narrow::narrow;
 pub use numeric::*;
+pub use split::split;
@@ -1,6 +1,8 @@
+use alloc::vec;
+
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Element, ElementConversion,
+    Float, Int, Shape, Tensor, TensorError, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -16,9 +18,7 @@ where
     ///
     /// If the tensor doesn't have one element.
     pub fn into_scalar(self) -> K::Elem {
-        check!(TensorCheck::into_scalar(&self.shape()));
-        let data = self.into_data();
-        data.value[0]
+        self.try_into_scalar().unwrap_or_else(|e| panic!("{e}"))
     }
 
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
@@ -28,9 +28,25 @@ where
     ///
     /// If the tensor doesn't have one element.
     pub async fn into_scalar(self) -> K::Elem {
-        check!(TensorCheck::into_scalar(&self.shape()));
+        self.try_into_scalar()
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Convert the tensor into a scalar, or return an error if the tensor doesn't have one element.
+    pub fn try_into_scalar(self) -> Result<K::Elem, TensorError> {
+        TensorCheck::into_scalar(&self.shape()).into_result()?;
+        let data = self.into_data();
+        Ok(data.value[0])
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Convert the tensor into a scalar, or return an error if the tensor doesn't have one element.
+    pub async fn try_into_scalar(self) -> Result<K::Elem, TensorError> {
+        TensorCheck::into_scalar(&self.shape()).into_result()?;
         let data = self.into_data().await;
-        data.value[0]
+        Ok(data.value[0])
     }
 
     /// Applies element wise addition operation.
@@ -42,6 +58,13 @@ where
         Self::new(K::add(self.primitive, other.primitive))
     }
 
+    /// Applies element wise addition operation, or return an error if the tensors have
+    /// incompatible shapes or devices instead of panicking.
+    pub fn checked_add(self, other: Self) -> Result<Self, TensorError> {
+        TensorCheck::binary_ops_ew("Add", &self, &other).into_result()?;
+        Ok(Self::new(K::add(self.primitive, other.primitive)))
+    }
+
     /// Applies element wise addition operation with a scalar.
     ///
     /// `y = x + s`
@@ -80,6 +103,37 @@ where
     pub fn div_scalar<E: ElementConversion>(self, other: E) -> Self {
         Self::new(K::div_scalar(self.primitive, other))
     }
+
+    /// Applies element wise remainder operation.
+    ///
+    /// The result has the same sign as the divisor, like the `%` operator in Python, e.g.
+    /// `-7 % 3 = 2`.
+    ///
+    /// `y = x2 - x1 * floor(x2 / x1)`
+    ///
+    /// # Notes
+    ///
+    /// For float tensors, a division by zero results in `NaN`. For integer tensors, a division
+    /// by zero follows the behavior of the backend's integer division, which may panic.
+    pub fn remainder(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Remainder", &self, &other));
+        Self::new(K::remainder(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise remainder operation with a scalar.
+    ///
+    /// The result has the same sign as the divisor, like the `%` operator in Python, e.g.
+    /// `-7 % 3 = 2`.
+    ///
+    /// `y = x - s * floor(x / s)`
+    ///
+    /// # Notes
+    ///
+    /// For float tensors, a division by zero results in `NaN`. For integer tensors, a division
+    /// by zero follows the behavior of the backend's integer division, which may panic.
+    pub fn remainder_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::remainder_scalar(self.primitive, other))
+    }
     ///
     /// Applies element wise multiplication operation.
     ///
@@ -100,6 +154,20 @@ where
     /// Switch sign of each element in the tensor.
     ///
     /// `y = -x`
+    ///
+    /// For int tensors, the values are negated with the two's complement of the backend integer
+    /// element. Int tensors aren't tracked by autodiff, so no gradient is computed.
+    ///
+    /// Negation isn't defined for bool tensors, use [bool_not](Tensor::bool_not) to invert them.
+    ///
+    /// ```rust,compile_fail
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>(tensor: Tensor<B, 1, Bool>) {
+    ///     let _ = tensor.neg();
+    /// }
+    /// ```
     #[allow(clippy::should_implement_trait)]
     pub fn neg(self) -> Self {
         Self::new(K::neg(self.primitive))
@@ -146,6 +214,24 @@ where
         Self::new(K::sum_dim(self.primitive, dim))
     }
 
+    /// Aggregate all elements along the given *dimensions* or *axes* in the tensor with the mean
+    /// operation.
+    ///
+    /// The reduced dimensions are kept with a size of 1.
+    pub fn mean_dims(self, dims: &[usize]) -> Self {
+        check!(TensorCheck::aggregate_dims::<D>("Mean", dims));
+        dims.iter().fold(self, |tensor, dim| tensor.mean_dim(*dim))
+    }
+
+    /// Aggregate all elements along the given *dimensions* or *axes* in the tensor with the sum
+    /// operation.
+    ///
+    /// The reduced dimensions are kept with a size of 1.
+    pub fn sum_dims(self, dims: &[usize]) -> Self {
+        check!(TensorCheck::aggregate_dims::<D>("Sum", dims));
+        dims.iter().fold(self, |tensor, dim| tensor.sum_dim(*dim))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     pub fn equal_elem<E: Element>(self, other: E) -> Tensor<B, D, Bool> {
         K::equal_elem::<D>(self.primitive, other.elem())
@@ -227,6 +313,22 @@ where
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    /// Select the elements from `a` where the condition is true and from `b` otherwise.
+    ///
+    /// This is the equivalent of `torch.where(condition, a, b)`. It is similar to
+    /// [mask_where](Tensor::mask_where), however both sources are provided independently.
+    ///
+    /// `output[i] = if condition[i] { a[i] } else { b[i] }`
+    ///
+    /// # Panics
+    ///
+    /// If the condition and the two tensors don't have the same shape or are not on the same
+    /// device.
+    pub fn select_where(condition: Tensor<B, D, Bool>, a: Self, b: Self) -> Self {
+        check!(TensorCheck::select_where(&condition, &a, &b));
+        b.mask_where(condition, a)
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -249,6 +351,91 @@ where
         Self::new(K::gather(dim, self.primitive, indices))
     }
 
+    /// Gather the elements located at the given coordinates.
+    ///
+    /// Each row of the index tensor is a full coordinate into the current tensor, returning one
+    /// element per row.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `output[i] = input[indices[i, 0], indices[i, 1], indices[i, 2]]`
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have a shape of `[num_indices, D]`.
+    pub fn gather_nd(self, indices: Tensor<B, 2, Int>) -> Tensor<B, 1, K> {
+        check!(TensorCheck::gather_nd::<D>(&indices.shape()));
+
+        let shape = self.shape();
+        let flat_indices = Self::flat_indices(&shape, indices, &self.device());
+
+        self.reshape([shape.num_elements()]).select(0, flat_indices)
+    }
+
+    /// Assign the values to the elements located at the given coordinates.
+    ///
+    /// Each row of the index tensor is a full coordinate into the current tensor, where the value
+    /// at the same position replaces the current element.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i, 0], indices[i, 1], indices[i, 2]] = values[i]`
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have a shape of `[num_values, D]`. The resulting element is
+    /// undefined when the same coordinate is provided more than once.
+    ///
+    /// Other references to the input tensor will not be modified by this operation.
+    pub fn scatter_nd(self, indices: Tensor<B, 2, Int>, values: Tensor<B, 1, K>) -> Self {
+        check!(TensorCheck::scatter_nd::<D>(
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        let shape = self.shape();
+        let device = self.device();
+        let num_elements = shape.num_elements();
+        let num_values = values.dims()[0];
+        let flat_indices = Self::flat_indices(&shape, indices, &device);
+
+        let mask = Tensor::<B, 1, Int>::zeros([num_elements], &device)
+            .select_assign(0, flat_indices.clone(), Tensor::ones([num_values], &device))
+            .greater_elem(0);
+        let scattered = Tensor::<B, 1, K>::zeros([num_elements], &device).select_assign(
+            0,
+            flat_indices,
+            values,
+        );
+
+        self.reshape([num_elements])
+            .mask_where(mask, scattered)
+            .reshape(shape)
+    }
+
+    /// Converts each coordinate (row) of the index tensor into an index of the flattened tensor.
+    fn flat_indices(
+        shape: &Shape<D>,
+        indices: Tensor<B, 2, Int>,
+        device: &B::Device,
+    ) -> Tensor<B, 1, Int> {
+        let num_indices = indices.dims()[0];
+
+        let mut strides = [0i64; D];
+        let mut current = 1;
+        for i in (0..D).rev() {
+            strides[i] = current as i64;
+            current *= shape.dims[i];
+        }
+
+        let strides = Tensor::<B, 1, Int>::from_data(Data::from(strides).convert(), device);
+
+        indices
+            .mul(strides.unsqueeze())
+            .sum_dim(1)
+            .reshape([num_indices])
+    }
+
     /// Assign the gathered elements corresponding to the given indices along the specified dimension
     /// from the value tensor to the original tensor using sum reduction.
     ///
@@ -311,6 +498,36 @@ where
         ))
     }
 
+    /// Fill the slices along the given dimension corresponding to the given indices with a
+    /// scalar value.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i], j, k] = value; // dim = 0`
+    /// `input[i, indices[j], k] = value; // dim = 1`
+    /// `input[i, j, indices[k]] = value; // dim = 2`
+    ///
+    /// This is similar to [mask_fill](Tensor::mask_fill), however whole slices are filled instead
+    /// of the elements of a mask.
+    pub fn index_fill<E: ElementConversion>(
+        self,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        value: E,
+    ) -> Self {
+        check!(TensorCheck::select_assign::<D>(dim));
+
+        let device = self.device();
+        let mut shape = self.shape();
+        let mask = Tensor::<B, D, Int>::zeros(shape.clone(), &device);
+        shape.dims[dim] = indices.dims()[0];
+        let mask = mask
+            .select_assign(dim, indices, Tensor::ones(shape, &device))
+            .greater_elem(0);
+
+        self.mask_fill(mask, value)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -357,6 +574,15 @@ where
         (tensor, index)
     }
 
+    /// Find the maximum value of the flattened tensor.
+    ///
+    /// Also returns the flat index of the maximum value, avoiding a second pass with
+    /// [argmax](Tensor::argmax).
+    pub fn max_with_argmax(self) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>) {
+        let num_elements = self.shape().num_elements();
+        self.reshape([num_elements]).max_dim_with_indices(0)
+    }
+
     /// Applies the argmin function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -402,6 +628,44 @@ where
         (tensor, index)
     }
 
+    /// Find the minimum value of the flattened tensor.
+    ///
+    /// Also returns the flat index of the minimum value, avoiding a second pass with
+    /// [argmin](Tensor::argmin).
+    pub fn min_with_argmin(self) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>) {
+        let num_elements = self.shape().num_elements();
+        self.reshape([num_elements]).min_dim_with_indices(0)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Sort the elements in ascending order along the given dimension.
+    ///
+    /// The sorted tensor is gathered from the original one, so gradients flow back to the
+    /// position each element had before sorting.
+    pub fn sort(self, dim: usize) -> Self {
+        self.sort_with_indices(dim).0
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Sort the elements in ascending order along the given dimension and also return the
+    /// original position of each sorted element.
+    ///
+    /// `output[..., i, ...] = input[..., indices[..., i, ...], ...]`
+    pub fn sort_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        let indices = self.clone().argsort(dim);
+
+        (self.gather(dim, indices.clone()), indices)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Return the indices that sort the elements in ascending order along the given dimension.
+    ///
+    /// The sort is stable, equal elements keep their relative order.
+    pub fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::dim_ops::<D>("Argsort", dim));
+        super::sort::argsort(self, dim)
+    }
+
     /// Clamp the tensor between the given min and max values.
     ///
     /// # Arguments
@@ -445,11 +709,140 @@ where
         Self::new(K::clamp_max(self.primitive, max.elem()))
     }
 
+    /// Clamps a tensor between element wise minimum and maximum bounds.
+    ///
+    /// `output[i] = min(max(self[i], min[i]), max[i])`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum values, broadcasted to the shape of the tensor.
+    /// * `max` - The maximum values, broadcasted to the shape of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the values clamped between the given bounds. The gradient only flows
+    /// to the tensor where it isn't clamped, and to the bounds otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the bounds can't be broadcasted to the shape of the tensor.
+    pub fn clamp_tensor(self, min: Self, max: Self) -> Self {
+        check!(TensorCheck::clamp_tensor(
+            &self.shape(),
+            &min.shape(),
+            &max.shape()
+        ));
+
+        self.maximum(min).minimum(max)
+    }
+
+    /// Applies the element wise maximum between two tensors.
+    ///
+    /// `output[i] = max(self[i], other[i])`
+    ///
+    /// Each element is selected from one of the two tensors, so the gradient flows entirely to
+    /// the selected operand. When both values are equal, `self` is selected.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have the same shape or are not on the same device.
+    pub fn maximum(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Maximum", &self, &other));
+        let mask = self.clone().lower(other.clone());
+        self.mask_where(mask, other)
+    }
+
+    /// Compute the running maximum along the given dimension.
+    ///
+    /// `output[..., i, ...] = max_{j <= i}(input[..., j, ...])`
+    ///
+    /// These are the values of a cumulative maximum without the indices, so the output is
+    /// non-decreasing along the dimension, e.g. the upper envelope of a time series.
+    ///
+    /// # Notes
+    ///
+    /// The scan takes `log2(n)` steps, each one combining every element with the running maximum
+    /// `2^k` positions before it.
+    pub fn prefix_max(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("PrefixMax", dim));
+
+        let size = self.dims()[dim];
+        let mut tensor = self;
+        let mut offset = 1;
+
+        while offset < size {
+            let head = tensor.clone().narrow(dim, 0, offset);
+            let previous = tensor.clone().narrow(dim, 0, size - offset);
+            let current = tensor.narrow(dim, offset, size - offset);
+
+            tensor = Tensor::cat(vec![head, current.maximum(previous)], dim);
+            offset *= 2;
+        }
+
+        tensor
+    }
+
+    /// Applies the element wise minimum between two tensors.
+    ///
+    /// `output[i] = min(self[i], other[i])`
+    ///
+    /// Each element is selected from one of the two tensors, so the gradient flows entirely to
+    /// the selected operand. When both values are equal, `self` is selected.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have the same shape or are not on the same device.
+    pub fn minimum(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Minimum", &self, &other));
+        let mask = self.clone().greater(other.clone());
+        self.mask_where(mask, other)
+    }
+
+    /// Pad the tensor with a constant value.
+    ///
+    /// # Arguments
+    ///
+    /// * `padding` - The number of elements added `(before, after)` each dimension.
+    /// * `value` - The value of the added elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([2, 3]), &device);
+    ///     let padded = tensor.pad([(1, 0), (0, 2)], 0.0);
+    ///     println!("{:?}", padded.dims()); // [3, 5]
+    /// }
+    /// ```
+    pub fn pad<E: ElementConversion>(self, padding: [(usize, usize); D], value: E) -> Self {
+        let dims = self.dims();
+        let padded_dims: [usize; D] = core::array::from_fn(|i| {
+            let (before, after) = padding[i];
+            before + dims[i] + after
+        });
+        let ranges: [core::ops::Range<usize>; D] =
+            core::array::from_fn(|i| padding[i].0..padding[i].0 + dims[i]);
+
+        Self::full(padded_dims, value, &self.device()).slice_assign(ranges, self)
+    }
+
     /// Apply element wise absolute value operation
     pub fn abs(self) -> Self {
         Self::new(K::abs(self.primitive))
     }
 
+    /// Returns the sign of each element: `-1` for negative values, `0` for zero and `1` for
+    /// positive values.
+    ///
+    /// For floats, negative zero is treated as zero.
+    pub fn sign(self) -> Self {
+        Self::new(K::sign(self.primitive))
+    }
+
     /// Returns the triangular part of a matrix (2-D tensor) or batch of matrices,
     /// based on the specified comparison method, zeroing out the other elements.
     ///
@@ -567,6 +960,67 @@ where
         let zeros = K::zeros([size, size].into(), device);
         Self::new(K::scatter(0, zeros, indices, ones))
     }
+
+    /// Returns the main diagonal of a square matrix.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///    let diagonal = tensor.diag();
+    ///    println!("{}", diagonal);
+    ///    // [1.0, 4.0]
+    /// }
+    /// ```
+    pub fn diag(self) -> Tensor<B, 1, K> {
+        check!(TensorCheck::diag(&self.shape()));
+
+        let [size, _] = self.dims();
+        let indices = Tensor::<B, 1, Int>::arange(0..size, &self.device()).reshape([size, 1]);
+
+        self.gather(1, indices).reshape([size])
+    }
+}
+
+impl<B, K> Tensor<B, 1, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Returns the square matrix with the values of the vector on its main diagonal and zeros
+    /// elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0], &device);
+    ///    let matrix = tensor.diag_embed();
+    ///    println!("{}", matrix);
+    ///    // [[1.0, 0.0], [0.0, 2.0]]
+    /// }
+    /// ```
+    pub fn diag_embed(self) -> Tensor<B, 2, K> {
+        let [size] = self.dims();
+        let device = self.device();
+        let indices = Tensor::<B, 1, Int>::arange(0..size, &device).reshape([size, 1]);
+
+        Tensor::zeros([size, size], &device).scatter(1, indices, self.reshape([size, 1]))
+    }
 }
 
 /// Trait that list all operations that can be applied on all numerical tensors.
@@ -713,6 +1167,55 @@ where
         rhs: E,
     ) -> Self::Primitive<D>;
 
+    /// Computes the remainder of the division of two tensors, with the sign of the divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The divisor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the remainder of tensors, users should prefer the [Tensor::remainder](Tensor::remainder) function,
+    /// which is more high-level and designed for public use.
+    fn remainder<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Self::Primitive<D>;
+
+    /// Computes the remainder of the division of a tensor by a scalar, with the sign of the divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The dividend.
+    /// * `rhs` - The scalar divisor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the remainder of a tensor by a scalar, users should prefer the
+    /// [Tensor::remainder_scalar](Tensor::remainder_scalar) function, which is more high-level
+    /// and designed for public use.
+    fn remainder_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D>;
+
     /// Multiplies two tensors.
     ///
     /// # Arguments
@@ -1589,6 +2092,26 @@ where
     /// For calculating abs of the elements of a tensor, users should prefer the [Tensor::abs](Tensor::abs) function,
     /// which is more high-level and designed for public use.
     fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
+
+    /// Returns the sign of the elements of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the sign of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `-1`, `0` or `1` for each element.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For calculating the sign of the elements of a tensor, users should prefer the [Tensor::sign](Tensor::sign) function,
+    /// which is more high-level and designed for public use.
+    fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -1628,6 +2151,18 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive<D> {
         B::int_div_scalar(lhs, rhs.elem())
     }
+    fn remainder<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::int_remainder(lhs, rhs)
+    }
+    fn remainder_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D> {
+        B::int_remainder_scalar(lhs, rhs.elem())
+    }
     fn mul<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -1843,6 +2378,10 @@ impl<B: Backend> Numeric<B> for Int {
     fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::int_abs(tensor)
     }
+
+    fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::int_sign(tensor)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -1882,6 +2421,18 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> Self::Primitive<D> {
         B::div_scalar(lhs, rhs.elem())
     }
+    fn remainder<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::remainder(lhs, rhs)
+    }
+    fn remainder_scalar<const D: usize, E: ElementConversion>(
+        lhs: Self::Primitive<D>,
+        rhs: E,
+    ) -> Self::Primitive<D> {
+        B::remainder_scalar(lhs, rhs.elem())
+    }
     fn mul<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -2098,6 +2649,10 @@ impl<B: Backend> Numeric<B> for Float {
     fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::abs(tensor)
     }
+
+    fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::sign(tensor)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
@@ -2181,6 +2736,33 @@ where
     }
 }
 
+impl<B, const D: usize, K> core::ops::Rem<Tensor<B, D, K>> for Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Tensor<B, D, K>) -> Self {
+        Tensor::remainder(self, rhs)
+    }
+}
+
+impl<E, const D: usize, B, K> core::ops::Rem<E> for Tensor<B, D, K>
+where
+    E: ElementConversion,
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn rem(self, other: E) -> Self {
+        Tensor::remainder_scalar(self, other)
+    }
+}
+
 impl<B, const D: usize, K> core::ops::Mul<Tensor<B, D, K>> for Tensor<B, D, K>
 where
     B: Backend,
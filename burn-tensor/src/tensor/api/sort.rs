@@ -0,0 +1,57 @@
+use crate::{backend::Backend, Data, Element, ElementConversion, Int, Numeric, Shape, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Compute the indices that sort the tensor in ascending order along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the tensor will be sorted.
+///
+/// # Returns
+///
+/// A tensor of the same shape with the positions of the sorted elements along the dimension.
+///
+/// # Remarks
+///
+/// The indices are computed on the host, so the tensor data is read back from the device. The
+/// sort is stable: equal elements keep their relative order.
+pub(crate) fn argsort<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> Tensor<B, D, Int>
+where
+    K::Elem: Element,
+{
+    let device = tensor.device();
+    let shape = tensor.shape();
+    let data = tensor.into_data();
+
+    let dim_size = shape.dims[dim];
+    let stride: usize = shape.dims[dim + 1..].iter().product();
+    let num_elements = shape.num_elements();
+    let mut indices = vec![0; num_elements];
+
+    if num_elements > 0 {
+        let mut lane: Vec<usize> = Vec::with_capacity(dim_size);
+
+        for start in 0..num_elements / dim_size {
+            let offset = (start / stride) * dim_size * stride + start % stride;
+            let value = |i: usize| data.value[offset + i * stride].elem::<f64>();
+
+            lane.clear();
+            lane.extend(0..dim_size);
+            lane.sort_by(|&a, &b| value(a).total_cmp(&value(b)));
+
+            for (i, index) in lane.iter().enumerate() {
+                indices[offset + i * stride] = *index as i64;
+            }
+        }
+    }
+
+    Tensor::from_data(
+        Data::new(indices, Shape::new(shape.dims)).convert(),
+        &device,
+    )
+}
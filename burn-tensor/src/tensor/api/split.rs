@@ -0,0 +1,32 @@
+use super::narrow::narrow;
+use crate::{backend::Backend, BasicOps, TensorKind};
+use alloc::vec::Vec;
+
+/// Split the tensor along the given dimension into parts of the given sizes.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `sizes` - The size of each part along the given dimension, which must sum to the size of
+///   the tensor along this dimension.
+/// * `dim` - The dimension along which the tensor will be split.
+///
+/// # Returns
+///
+/// A vector of tensors, one for each size.
+pub fn split<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    sizes: &[usize],
+    dim: usize,
+) -> Vec<K::Primitive<D>> {
+    let mut start = 0;
+
+    sizes
+        .iter()
+        .map(|&size| {
+            let part = narrow::<B, D, K>(tensor.clone(), dim, start, size);
+            start += size;
+            part
+        })
+        .collect()
+}
@@ -1,7 +1,11 @@
+use alloc::vec::Vec;
+
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, UnfoldOptions},
-    Int, Tensor,
+    check,
+    check::TensorCheck,
+    ops::{ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions, UnfoldOptions},
+    Data, Int, Shape, Tensor,
 };
 
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
@@ -12,6 +16,47 @@ where
     Tensor::new(B::embedding(weights.primitive, indices.primitive))
 }
 
+/// Applies a bilinear transformation to the incoming data, `y = x1^T W x2 + b` for each sample.
+///
+/// # Shapes
+///
+/// x1: `[batch_size, d_input1]`, x2: `[batch_size, d_input2]`,
+/// weight: `[d_output, d_input1, d_input2]`, bias: `[d_output]`,
+/// output: `[batch_size, d_output]`
+pub fn bilinear<B>(
+    x1: Tensor<B, 2>,
+    x2: Tensor<B, 2>,
+    weight: Tensor<B, 3>,
+    bias: Option<Tensor<B, 1>>,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    check!(TensorCheck::bilinear(
+        &x1.shape(),
+        &x2.shape(),
+        &weight.shape(),
+        bias.as_ref().map(|bias| bias.shape()).as_ref(),
+    ));
+
+    let [batch_size, d_input1] = x1.dims();
+    let [_, d_input2] = x2.dims();
+    let [d_output, _, _] = weight.dims();
+
+    // [1, batch_size, d_input1] @ [d_output, d_input1, d_input2] => [d_output, batch_size, d_input2]
+    let projected = x1.reshape([1, batch_size, d_input1]).matmul(weight);
+    let output = projected
+        .mul(x2.reshape([1, batch_size, d_input2]))
+        .sum_dim(2)
+        .reshape([d_output, batch_size])
+        .transpose();
+
+    match bias {
+        Some(bias) => output + bias.unsqueeze(),
+        None => output,
+    }
+}
+
 /// Applies a [1D convolution](crate::ops::ModuleOps::conv2d).
 pub fn conv1d<B>(
     x: Tensor<B, 3>,
@@ -99,16 +144,27 @@ pub fn max_pool1d<B>(
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
 ) -> Tensor<B, 3>
 where
     B: Backend,
 {
+    check!(TensorCheck::pool1d(
+        "MaxPool1d",
+        &x.shape(),
+        kernel_size,
+        stride,
+        padding,
+        dilation
+    ));
+
     Tensor::new(B::max_pool1d(
         x.primitive,
         kernel_size,
         stride,
         padding,
         dilation,
+        ceil_mode,
     ))
 }
 
@@ -119,16 +175,27 @@ pub fn max_pool2d<B>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> Tensor<B, 4>
 where
     B: Backend,
 {
+    check!(TensorCheck::pool2d(
+        "MaxPool2d",
+        &x.shape(),
+        kernel_size,
+        stride,
+        padding,
+        dilation
+    ));
+
     Tensor::new(B::max_pool2d(
         x.primitive,
         kernel_size,
         stride,
         padding,
         dilation,
+        ceil_mode,
     ))
 }
 
@@ -139,6 +206,7 @@ pub fn avg_pool2d<B>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> Tensor<B, 4>
 where
     B: Backend,
@@ -149,6 +217,7 @@ where
         stride,
         padding,
         count_include_pad,
+        ceil_mode,
     ))
 }
 
@@ -159,6 +228,7 @@ pub fn avg_pool1d<B>(
     stride: usize,
     padding: usize,
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> Tensor<B, 3>
 where
     B: Backend,
@@ -169,6 +239,7 @@ where
         stride,
         padding,
         count_include_pad,
+        ceil_mode,
     ))
 }
 
@@ -179,11 +250,28 @@ pub fn max_pool1d_with_indices<B>(
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
 ) -> (Tensor<B, 3>, Tensor<B, 3, Int>)
 where
     B: Backend,
 {
-    let output = B::max_pool1d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+    check!(TensorCheck::pool1d(
+        "MaxPool1d",
+        &x.shape(),
+        kernel_size,
+        stride,
+        padding,
+        dilation
+    ));
+
+    let output = B::max_pool1d_with_indices(
+        x.primitive,
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+        ceil_mode,
+    );
 
     (Tensor::new(output.output), Tensor::new(output.indices))
 }
@@ -195,15 +283,54 @@ pub fn max_pool2d_with_indices<B>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> (Tensor<B, 4>, Tensor<B, 4, Int>)
 where
     B: Backend,
 {
-    let output = B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+    check!(TensorCheck::pool2d(
+        "MaxPool2d",
+        &x.shape(),
+        kernel_size,
+        stride,
+        padding,
+        dilation
+    ));
+
+    let output = B::max_pool2d_with_indices(
+        x.primitive,
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+        ceil_mode,
+    );
 
     (Tensor::new(output.output), Tensor::new(output.indices))
 }
 
+/// Applies a [2D max unpooling](crate::ops::ModuleOps::max_unpool2d).
+pub fn max_unpool2d<B>(
+    x: Tensor<B, 4>,
+    indices: Tensor<B, 4, Int>,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    output_size: Option<[usize; 2]>,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::max_unpool2d(
+        x.primitive,
+        indices.primitive,
+        kernel_size,
+        stride,
+        padding,
+        output_size,
+    ))
+}
+
 /// Applies a [2D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool2d).
 pub fn adaptive_avg_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
 where
@@ -213,9 +340,213 @@ where
 }
 
 /// Applies a [1D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool1d).
+///
+/// The output element `i` is the average of the input window
+/// `[floor(i * L / n), ceil((i + 1) * L / n))`, as in PyTorch. When the output size is larger
+/// than the input length, the windows overlap and the input values are repeated, which upsamples
+/// the input. When both sizes are equal, the input is returned unchanged.
+///
+/// # Panics
+///
+/// If the output size is zero.
 pub fn adaptive_avg_pool1d<B>(x: Tensor<B, 3>, output_size: usize) -> Tensor<B, 3>
 where
     B: Backend,
 {
+    check!(TensorCheck::adaptive_pool(
+        "AdaptiveAvgPool1d",
+        [output_size]
+    ));
+
+    if x.dims()[2] == output_size {
+        return x;
+    }
+
     Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
 }
+
+/// Applies a 1D global average pooling, which averages each channel over its whole length.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, length]`, output: `[batch_size, channels, 1]`
+pub fn global_avg_pool1d<B>(x: Tensor<B, 3>) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    x.mean_dim(2)
+}
+
+/// Applies a 2D global average pooling, which averages each channel over its height and width.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, height, width]`, output: `[batch_size, channels, 1, 1]`
+pub fn global_avg_pool2d<B>(x: Tensor<B, 4>) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    x.mean_dims(&[2, 3])
+}
+
+/// Applies a 1D global max pooling, which takes the maximum of each channel over its whole length.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, length]`, output: `[batch_size, channels, 1]`
+pub fn global_max_pool1d<B>(x: Tensor<B, 3>) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    x.max_dim(2)
+}
+
+/// Applies a 2D global max pooling, which takes the maximum of each channel over its height and
+/// width.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, height, width]`, output: `[batch_size, channels, 1, 1]`
+pub fn global_max_pool2d<B>(x: Tensor<B, 4>) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    x.max_dim(3).max_dim(2)
+}
+
+/// Applies a local response normalization across the channels of the input, as introduced in
+/// [ImageNet Classification with Deep Convolutional Neural Networks](https://papers.nips.cc/paper/2012/hash/c399862d3b9d6b76c8436e924a68c45b-Abstract.html).
+///
+/// Each element is divided by `(k + alpha / size * sum(x^2))^beta`, where the sum runs over a
+/// window of `size` neighboring channels centered on the element's channel.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, ...]`
+pub fn local_response_norm<B, const D: usize>(
+    x: Tensor<B, D>,
+    size: usize,
+    alpha: f64,
+    beta: f64,
+    k: f64,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    check!(TensorCheck::local_response_norm(size));
+
+    let dims = x.dims();
+    let channels = dims[1];
+    let pad_before = (size - 1) / 2;
+
+    let mut padded_dims = dims;
+    padded_dims[1] = channels + size - 1;
+    let mut ranges = dims.map(|dim| 0..dim);
+    ranges[1] = pad_before..pad_before + channels;
+
+    let squared = x.clone().powf(2.0);
+    let padded = Tensor::zeros(padded_dims, &x.device()).slice_assign(ranges, squared);
+
+    let sum = (1..size).fold(padded.clone().narrow(1, 0, channels), |acc, i| {
+        acc + padded.clone().narrow(1, i, channels)
+    });
+    let div = sum
+        .mul_scalar(alpha / size as f64)
+        .add_scalar(k)
+        .powf(beta as f32);
+
+    x / div
+}
+
+/// Resizes the spatial dimensions of the input to the given output size.
+///
+/// The bilinear interpolation is separable, so it is applied along the height and then along the
+/// width, with the coordinate mapping selected by
+/// [align_corners](crate::ops::InterpolateOptions::align_corners).
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, height, width]`
+/// output: `[batch_size, channels, output_size[0], output_size[1]]`
+pub fn interpolate<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let x = interpolate_dim(x, 2, output_size[0], &options);
+    interpolate_dim(x, 3, output_size[1], &options)
+}
+
+fn interpolate_dim<B: Backend>(
+    x: Tensor<B, 4>,
+    dim: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+) -> Tensor<B, 4> {
+    let input_size = x.dims()[dim];
+    let device = x.device();
+
+    assert!(
+        input_size > 0 && output_size > 0,
+        "Interpolate: the input size {input_size} and the output size {output_size} of dimension {dim} must not be zero"
+    );
+
+    let indices = |indices: Vec<usize>| {
+        let indices = indices.into_iter().map(|i| i as i64).collect::<Vec<_>>();
+        Tensor::<B, 1, Int>::from_data(
+            Data::new(indices, Shape::new([output_size])).convert(),
+            &device,
+        )
+    };
+
+    match options.mode {
+        InterpolateMode::Nearest => {
+            let nearest = (0..output_size)
+                .map(|i| (i * input_size / output_size).min(input_size - 1))
+                .collect();
+
+            x.select(dim, indices(nearest))
+        }
+        InterpolateMode::Bilinear => {
+            let coordinates: Vec<f64> = (0..output_size)
+                .map(|i| {
+                    if options.align_corners {
+                        match output_size {
+                            1 => 0.0,
+                            _ => i as f64 * (input_size - 1) as f64 / (output_size - 1) as f64,
+                        }
+                    } else {
+                        let scale = input_size as f64 / output_size as f64;
+                        ((i as f64 + 0.5) * scale - 0.5).max(0.0)
+                    }
+                })
+                .collect();
+
+            let lower: Vec<usize> = coordinates
+                .iter()
+                .map(|&c| (libm::floor(c) as usize).min(input_size - 1))
+                .collect();
+            let upper = lower.iter().map(|&i| (i + 1).min(input_size - 1)).collect();
+            let weights: Vec<f32> = coordinates
+                .iter()
+                .zip(lower.iter())
+                .map(|(&c, &i)| (c - i as f64) as f32)
+                .collect();
+
+            let mut weights_shape = [1; 4];
+            weights_shape[dim] = output_size;
+            let weights = Tensor::from_data(
+                Data::new(weights, Shape::new(weights_shape)).convert(),
+                &device,
+            );
+
+            let lower = x.clone().select(dim, indices(lower));
+            let upper = x.select(dim, indices(upper));
+
+            lower.clone() + (upper - lower) * weights
+        }
+    }
+}
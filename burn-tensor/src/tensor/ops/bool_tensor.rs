@@ -1,5 +1,5 @@
 use super::{BoolTensor, Device, FloatTensor, IntTensor};
-use crate::{backend::Backend, chunk, narrow, tensor::Shape, Bool, Data};
+use crate::{backend::Backend, chunk, narrow, tensor::Shape, Bool, Data, ElementConversion};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -30,6 +30,21 @@ pub trait BoolTensorOps<B: Backend> {
     /// The shape of the tensor.
     fn bool_shape<const D: usize>(tensor: &BoolTensor<B, D>) -> Shape<D>;
 
+    /// Returns the number of bytes occupied by the elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The estimated memory footprint of the tensor, which is the number of elements times the size
+    /// of an element by default. Backends allocating padded or aligned buffers can report the
+    /// actual size instead.
+    fn bool_memory_footprint<const D: usize>(tensor: &BoolTensor<B, D>) -> usize {
+        B::bool_shape(tensor).num_elements() * core::mem::size_of::<bool>()
+    }
+
     /// Converts the tensor to a data structure.
     ///
     /// # Arguments
@@ -229,6 +244,51 @@ pub trait BoolTensorOps<B: Backend> {
     /// The tensor with the result of the negation.
     fn bool_not<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, D>;
 
+    /// Element-wise logical and of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor which is true where both `lhs` and `rhs` are true.
+    fn bool_and<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let product = B::int_mul(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_greater_elem(product, 0.elem())
+    }
+
+    /// Element-wise logical or of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor which is true where `lhs` or `rhs` is true.
+    fn bool_or<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let sum = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Element-wise logical exclusive or of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor which is true where exactly one of `lhs` and `rhs` is true.
+    fn bool_xor<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let sum = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(sum, 1.elem())
+    }
+
     /// Transposes a bool tensor.
     ///
     /// # Arguments
@@ -302,4 +362,63 @@ pub trait BoolTensorOps<B: Backend> {
     ) -> Vec<BoolTensor<B, D>> {
         chunk::<B, D, Bool>(tensor, chunks, dim)
     }
+
+    /// Tests if any element of the tensor is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, true if any element of `tensor` is true.
+    fn bool_any<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, 1> {
+        let count = B::int_sum(B::bool_into_int(tensor));
+        B::int_greater_elem(count, 0.elem())
+    }
+
+    /// Tests if any element of the tensor is true along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor which is true where any element along `dim` is true.
+    fn bool_any_dim<const D: usize>(tensor: BoolTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        let count = B::int_sum_dim(B::bool_into_int(tensor), dim);
+        B::int_greater_elem(count, 0.elem())
+    }
+
+    /// Tests if all the elements of the tensor are true.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, true if all the elements of `tensor` are true.
+    fn bool_all<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, 1> {
+        // all the elements are true when none of them is false
+        let any_false = B::bool_any(B::bool_not(tensor));
+        B::bool_not(any_false)
+    }
+
+    /// Tests if all the elements of the tensor are true along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor which is true where all the elements along `dim` are true.
+    fn bool_all_dim<const D: usize>(tensor: BoolTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        let any_false = B::bool_any_dim(B::bool_not(tensor), dim);
+        B::bool_not(any_false)
+    }
 }
@@ -31,6 +31,21 @@ pub trait IntTensorOps<B: Backend> {
     /// The shape of the tensor.
     fn int_shape<const D: usize>(tensor: &IntTensor<B, D>) -> Shape<D>;
 
+    /// Returns the number of bytes occupied by the elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The estimated memory footprint of the tensor, which is the number of elements times the size
+    /// of an element by default. Backends allocating padded or aligned buffers can report the
+    /// actual size instead.
+    fn int_memory_footprint<const D: usize>(tensor: &IntTensor<B, D>) -> usize {
+        B::int_shape(tensor).num_elements() * core::mem::size_of::<IntElem<B>>()
+    }
+
     /// Converts the tensor to a data structure.
     ///
     /// # Arguments
@@ -579,6 +594,66 @@ pub trait IntTensorOps<B: Backend> {
     /// The result of the division.
     fn int_div_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D>;
 
+    /// Elementwise remainder.
+    ///
+    /// The result has the same sign as the divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        // The truncated remainder has the sign of the dividend, adding the divisor before taking
+        // the remainder a second time gives it the sign of the divisor.
+        let truncated = B::int_sub(
+            lhs.clone(),
+            B::int_mul(rhs.clone(), B::int_div(lhs, rhs.clone())),
+        );
+        let shifted = B::int_add(truncated, rhs.clone());
+
+        B::int_sub(
+            shifted.clone(),
+            B::int_mul(rhs.clone(), B::int_div(shifted, rhs)),
+        )
+    }
+
+    /// Elementwise remainder with a scalar.
+    ///
+    /// The result has the same sign as the divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division.
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        // The truncated remainder has the sign of the dividend, adding the divisor before taking
+        // the remainder a second time gives it the sign of the divisor.
+        let truncated = B::int_sub(
+            lhs.clone(),
+            B::int_mul_scalar(B::int_div_scalar(lhs, rhs), rhs),
+        );
+        let shifted = B::int_add_scalar(truncated, rhs);
+
+        B::int_sub(
+            shifted.clone(),
+            B::int_mul_scalar(B::int_div_scalar(shifted, rhs), rhs),
+        )
+    }
+
     /// Elementwise negation.
     ///
     /// # Arguments
@@ -822,6 +897,24 @@ pub trait IntTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with absolute values.
     fn int_abs<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D>;
 
+    /// Returns a new tensor with the sign of the elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the sign of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with `-1` for negative values, `0` for zeros and
+    /// `1` for positive values.
+    fn int_sign<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        let zero = 0.elem();
+        let positive = B::bool_into_int(B::int_greater_elem(tensor.clone(), zero));
+        let negative = B::bool_into_int(B::int_lower_elem(tensor, zero));
+
+        B::int_sub(positive, negative)
+    }
+
     /// Transposes an int tensor.
     ///
     /// # Arguments
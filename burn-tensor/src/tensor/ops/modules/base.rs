@@ -114,6 +114,34 @@ pub struct UnfoldOptions {
     pub dilation: [usize; 2],
 }
 
+/// The algorithm used to compute the values of an [interpolation](crate::module::interpolate).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum InterpolateMode {
+    /// Takes the value of the nearest input element, using the `floor(output_index * scale)`
+    /// convention of PyTorch.
+    Nearest,
+
+    /// Linearly interpolates between the two nearest input elements of each spatial dimension.
+    Bilinear,
+}
+
+/// Interpolation operation options.
+#[derive(new, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct InterpolateOptions {
+    /// The interpolation algorithm.
+    pub mode: InterpolateMode,
+
+    /// How the output coordinates are mapped to the input coordinates in bilinear mode.
+    ///
+    /// When `true`, the corner pixels of the input and the output are aligned, so the corner
+    /// values are preserved. When `false`, the pixels are treated as areas and their centers are
+    /// aligned, as with the default `half_pixel` coordinate transformation of ONNX.
+    ///
+    /// PyTorch and ONNX both default to `false`, but older exporters often use `true`, which
+    /// shifts every interpolated value by up to half a pixel.
+    pub align_corners: bool,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -285,8 +313,16 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 3> {
-        pool::avg_pool1d_from_2d::<B>(x, kernel_size, stride, padding, count_include_pad)
+        pool::avg_pool1d_from_2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
     /// Backward pass for the [avg pooling 1d](ModuleOps::avg_pool1d) operation.
     fn avg_pool1d_backward(
@@ -296,6 +332,7 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 3> {
         pool::avg_pool1d_backward_from_2d::<B>(
             x,
@@ -304,6 +341,7 @@ pub trait ModuleOps<B: Backend> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
         )
     }
     /// Two dimensional avg pooling.
@@ -317,6 +355,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 4>;
     /// Backward pass for the [avg pooling 2d](ModuleOps::avg_pool2d) operation.
     fn avg_pool2d_backward(
@@ -326,6 +365,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 4>;
     /// Two dimensional adaptive avg pooling.
     ///
@@ -364,8 +404,9 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 3> {
-        pool::max_pool1d_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+        pool::max_pool1d_from_2d::<B>(x, kernel_size, stride, padding, dilation, ceil_mode)
     }
 
     /// One dimensional max pooling with indices.
@@ -379,16 +420,26 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<B> {
-        pool::max_pool1d_with_indices_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+        pool::max_pool1d_with_indices_from_2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        )
     }
     /// Backward pass for the [max pooling 1d](ModuleOps::max_pool1d_with_indices) operation.
+    #[allow(clippy::too_many_arguments)]
     fn max_pool1d_with_indices_backward(
         x: FloatTensor<B, 3>,
         kernel_size: usize,
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
         output_grad: FloatTensor<B, 3>,
         indices: IntTensor<B, 3>,
     ) -> MaxPool1dBackward<B> {
@@ -398,6 +449,7 @@ pub trait ModuleOps<B: Backend> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             output_grad,
             indices,
         )
@@ -414,6 +466,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> FloatTensor<B, 4>;
 
     /// Two dimensional max pooling with indices.
@@ -427,15 +480,40 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<B>;
     /// Backward pass for the [max pooling 2d](ModuleOps::max_pool2d_with_indices) operation.
+    #[allow(clippy::too_many_arguments)]
     fn max_pool2d_with_indices_backward(
         x: FloatTensor<B, 4>,
         kernel_size: [usize; 2],
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: FloatTensor<B, 4>,
         indices: IntTensor<B, 4>,
     ) -> MaxPool2dBackward<B>;
+
+    /// Two dimensional max unpooling, the partial inverse of
+    /// [max pooling 2d](ModuleOps::max_pool2d_with_indices).
+    ///
+    /// Each value is written back at the position recorded in `indices`, all the other positions
+    /// are set to zero. When `output_size` is `None`, the size of the output is inferred from the
+    /// pooling parameters.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    /// indices: [batch_size, channels, height, width],
+    fn max_unpool2d(
+        x: FloatTensor<B, 4>,
+        indices: IntTensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        output_size: Option<[usize; 2]>,
+    ) -> FloatTensor<B, 4> {
+        pool::max_unpool2d_from_scatter::<B>(x, indices, kernel_size, stride, padding, output_size)
+    }
 }
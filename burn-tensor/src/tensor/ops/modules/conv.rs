@@ -45,14 +45,33 @@ pub fn calculate_conv_transpose_output_size(
 }
 
 /// Calculate the expected output size when doing a pooling operation.
+///
+/// When `ceil_mode` is enabled, the size is computed with a ceiling division so that a last,
+/// partial window is kept, unless that window would start in the right padding.
 pub fn calculate_pool_output_size(
     kernel_size: usize,
     stride: usize,
     padding: usize,
     dilation: usize,
     size_in: usize,
+    ceil_mode: bool,
 ) -> usize {
-    ((size_in + 2 * padding - dilation * (kernel_size - 1) - 1) / stride) + 1
+    let span = size_in + 2 * padding - dilation * (kernel_size - 1) - 1;
+
+    if !ceil_mode {
+        return span / stride + 1;
+    }
+
+    // `usize::div_ceil` is only stable since Rust 1.73
+    #[allow(clippy::manual_div_ceil)]
+    let size_out = (span + stride - 1) / stride + 1;
+
+    // The last window must start inside the input or the left padding.
+    if (size_out - 1) * stride >= size_in + padding {
+        return size_out - 1;
+    }
+
+    size_out
 }
 
 /// Calculate the [1D convolution](crate::ops::ModuleOps::conv1d) backward pass using convolutions.
@@ -1,7 +1,7 @@
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
-    Shape,
+    ElementConversion, Shape,
 };
 
 use super::{MaxPool1dBackward, MaxPool1dWithIndices};
@@ -12,6 +12,7 @@ pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     stride: usize,
     padding: usize,
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> FloatTensor<B, 3> {
     let [batch_size, channels, length] = B::shape(&x).dims;
 
@@ -22,6 +23,7 @@ pub(crate) fn avg_pool1d_from_2d<B: Backend>(
         [stride, 1],
         [padding, 0],
         count_include_pad,
+        ceil_mode,
     );
 
     let [batch_size, channels, length, _] = B::shape(&x).dims;
@@ -36,6 +38,7 @@ pub(crate) fn avg_pool1d_backward_from_2d<B: Backend>(
     stride: usize,
     padding: usize,
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> FloatTensor<B, 3> {
     let [batch_size, channels, length_in] = B::shape(&x).dims;
     let [_, _, length_out] = B::shape(&grad).dims;
@@ -50,6 +53,7 @@ pub(crate) fn avg_pool1d_backward_from_2d<B: Backend>(
         [stride, 1],
         [padding, 0],
         count_include_pad,
+        ceil_mode,
     );
 
     B::reshape(grad_x, Shape::from([batch_size, channels, length_in]))
@@ -90,6 +94,7 @@ pub(crate) fn max_pool1d_from_2d<B: Backend>(
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
 ) -> FloatTensor<B, 3> {
     let [batch_size, channels, length] = B::shape(&x).dims;
 
@@ -100,6 +105,7 @@ pub(crate) fn max_pool1d_from_2d<B: Backend>(
         [stride, 1],
         [padding, 0],
         [dilation, 1],
+        ceil_mode,
     );
 
     let [batch_size, channels, length, _] = B::shape(&x).dims;
@@ -113,29 +119,33 @@ pub(crate) fn max_pool1d_with_indices_from_2d<B: Backend>(
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
 ) -> MaxPool1dWithIndices<B> {
     let [batch_size, channels, length] = B::shape(&x).dims;
 
-    let x = B::reshape(x, Shape::from([batch_size, channels, 1, length]));
+    let x = B::reshape(x, Shape::from([batch_size, channels, length, 1]));
     let x = B::max_pool2d_with_indices(
         x,
-        [1, kernel_size],
-        [1, stride],
-        [0, padding],
-        [1, dilation],
+        [kernel_size, 1],
+        [stride, 1],
+        [padding, 0],
+        [dilation, 1],
+        ceil_mode,
     );
-    let [batch_size, channels, _, length] = B::shape(&x.output).dims;
+    let [batch_size, channels, length, _] = B::shape(&x.output).dims;
     let output = B::reshape(x.output, Shape::from([batch_size, channels, length]));
     let indices = B::int_reshape(x.indices, Shape::from([batch_size, channels, length]));
     MaxPool1dWithIndices::new(output, indices)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
     x: FloatTensor<B, 3>,
     kernel_size: usize,
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
     output_grad: FloatTensor<B, 3>,
     indices: IntTensor<B, 3>,
 ) -> MaxPool1dBackward<B> {
@@ -155,6 +165,7 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         [stride, 1],
         [padding, 0],
         [dilation, 1],
+        ceil_mode,
         grad_x,
         indices,
     )
@@ -165,3 +176,42 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+pub(crate) fn max_unpool2d_from_scatter<B: Backend>(
+    x: FloatTensor<B, 4>,
+    indices: IntTensor<B, 4>,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    output_size: Option<[usize; 2]>,
+) -> FloatTensor<B, 4> {
+    let [batch_size, channels, height_in, width_in] = B::shape(&x).dims;
+    let device = B::device(&x);
+
+    let [height_out, width_out] = output_size.unwrap_or([
+        (height_in - 1) * stride[0] + kernel_size[0] - 2 * padding[0],
+        (width_in - 1) * stride[1] + kernel_size[1] - 2 * padding[1],
+    ]);
+
+    let shape_in = Shape::new([batch_size, channels, height_in * width_in]);
+    let shape_out = Shape::new([batch_size, channels, height_out * width_out]);
+
+    let x = B::reshape(x, shape_in.clone());
+    let indices = B::int_reshape(indices, shape_in.clone());
+
+    // Overlapping windows may select the same position more than once, so the scattered sum is
+    // divided by the number of hits to write each value only once.
+    let sum = B::scatter(2, B::zeros(shape_out.clone(), &device), indices.clone(), x);
+    let count = B::scatter(
+        2,
+        B::zeros(shape_out, &device),
+        indices,
+        B::ones(shape_in, &device),
+    );
+    let output = B::div(sum, B::clamp_min(count, 1.elem()));
+
+    B::reshape(
+        output,
+        Shape::new([batch_size, channels, height_out, width_out]),
+    )
+}
@@ -1,6 +1,7 @@
 use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Float};
 use crate::{tensor::api::chunk, tensor::api::narrow};
+use alloc::vec;
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -97,6 +98,21 @@ pub trait TensorOps<B: Backend> {
     /// The shape of the tensor.
     fn shape<const D: usize>(tensor: &FloatTensor<B, D>) -> Shape<D>;
 
+    /// Returns the number of bytes occupied by the elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The estimated memory footprint of the tensor, which is the number of elements times the size
+    /// of an element by default. Backends allocating padded or aligned buffers can report the
+    /// actual size instead.
+    fn memory_footprint<const D: usize>(tensor: &FloatTensor<B, D>) -> usize {
+        B::shape(tensor).num_elements() * core::mem::size_of::<FloatElem<B>>()
+    }
+
     /// Converts the tensor to a data structure.
     ///
     /// # Arguments
@@ -394,6 +410,48 @@ pub trait TensorOps<B: Backend> {
     /// The result of dividing the tensor by the scalar.
     fn div_scalar<const D: usize>(lhs: FloatTensor<B, D>, rhs: FloatElem<B>) -> FloatTensor<B, D>;
 
+    /// Computes the remainder of the division of two tensors element-wise.
+    ///
+    /// The result has the same sign as the divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division, which is `NaN` when dividing by zero.
+    fn remainder<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        let quotient = B::floor(B::div(lhs.clone(), rhs.clone()));
+
+        B::sub(lhs, B::mul(rhs, quotient))
+    }
+
+    /// Computes the remainder of the division of a tensor by a scalar.
+    ///
+    /// The result has the same sign as the divisor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the division, which is `NaN` when dividing by zero.
+    fn remainder_scalar<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        let quotient = B::floor(B::div_scalar(lhs.clone(), rhs));
+
+        B::sub(lhs, B::mul_scalar(quotient, rhs))
+    }
+
     /// Multiplies two tensors together using matrix multiplication.
     ///
     /// # Arguments
@@ -414,6 +472,40 @@ pub trait TensorOps<B: Backend> {
     /// Calculates the reciprocals elementwise
     fn recip<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Rounds the elements of a tensor down to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to floor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with floored values.
+    fn floor<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Rounds the elements of a tensor up to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to ceil.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with ceiled values.
+    fn ceil<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Rounds the elements of a tensor to the nearest integer, with halfway cases rounded to
+    /// the nearest even integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with rounded values.
+    fn round<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
+
     /// Transposes a tensor.
     ///
     /// # Arguments
@@ -610,6 +702,33 @@ pub trait TensorOps<B: Backend> {
     /// A boolean tensor with the result of the comparison.
     fn equal<const D: usize>(lhs: FloatTensor<B, D>, rhs: FloatTensor<B, D>) -> BoolTensor<B, D>;
 
+    /// Returns which elements of the tensor are NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor which is true where `tensor` is NaN.
+    fn is_nan<const D: usize>(tensor: FloatTensor<B, D>) -> BoolTensor<B, D> {
+        // NaN is the only value which isn't lower or equal to infinity.
+        B::bool_not(B::lower_equal_elem(tensor, f64::INFINITY.elem()))
+    }
+
+    /// Returns which elements of the tensor are infinite.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor which is true where `tensor` is positive or negative infinity.
+    fn is_inf<const D: usize>(tensor: FloatTensor<B, D>) -> BoolTensor<B, D> {
+        B::equal_elem(B::abs(tensor), f64::INFINITY.elem())
+    }
+
     /// Equal comparison of a tensor and a scalar.
     ///
     /// # Arguments
@@ -762,6 +881,44 @@ pub trait TensorOps<B: Backend> {
     /// A scalar tensor with the sum of all elements in `tensor`.
     fn sum<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, 1>;
 
+    /// Product of all elements in a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to multiply.
+    ///
+    /// # Returns
+    ///
+    /// A scalar tensor with the product of all elements in `tensor`, which is one for an empty
+    /// tensor.
+    fn prod<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, 1> {
+        let num_elements = B::shape(&tensor).num_elements();
+
+        if num_elements == 0 {
+            return B::ones(Shape::new([1]), &B::device(&tensor));
+        }
+
+        let mut tensor = B::reshape(tensor, Shape::new([num_elements]));
+        let mut size = num_elements;
+
+        // Multiply the two halves until a single element remains, the odd element is kept as is.
+        while size > 1 {
+            let half = size / 2;
+            let lhs = B::narrow(tensor.clone(), 0, 0, half);
+            let rhs = B::narrow(tensor.clone(), 0, half, half);
+            let mut product = B::mul(lhs, rhs);
+
+            if size % 2 == 1 {
+                product = B::cat(vec![product, B::narrow(tensor, 0, 2 * half, 1)], 0);
+            }
+
+            tensor = product;
+            size = half + size % 2;
+        }
+
+        tensor
+    }
+
     /// Sum of all elements in a tensor along a dimension.
     ///
     /// # Arguments
@@ -788,6 +945,84 @@ pub trait TensorOps<B: Backend> {
         B::div_scalar(B::sum(tensor), (num_elems as i64).elem())
     }
 
+    /// Sum of all elements in a tensor using the compensated (Kahan) summation, which keeps the
+    /// rounding error bounded for long reductions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    ///
+    /// # Returns
+    ///
+    /// A scalar tensor with the sum of all elements in `tensor`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation splits the elements into about `sqrt(n)` rows and accumulates
+    /// the rows one after the other with a running compensation per column. The partial sums of
+    /// the columns are then reduced the same way.
+    fn sum_stable<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, 1> {
+        let device = B::device(&tensor);
+        let num_elems = B::shape(&tensor).num_elements();
+
+        if num_elems == 0 {
+            return B::zeros(Shape::new([1]), &device);
+        }
+
+        let tensor = B::reshape(tensor, Shape::new([num_elems]));
+        if num_elems == 1 {
+            return tensor;
+        }
+
+        let mut num_rows = 1;
+        while num_rows * num_rows < num_elems {
+            num_rows += 1;
+        }
+        // `usize::div_ceil` isn't available on the minimum supported Rust version
+        #[allow(clippy::manual_div_ceil)]
+        let num_cols = (num_elems + num_rows - 1) / num_rows;
+
+        // Pad with zeros, which don't change the sum
+        let padding = num_rows * num_cols - num_elems;
+        let tensor = match padding {
+            0 => tensor,
+            _ => B::cat(vec![tensor, B::zeros(Shape::new([padding]), &device)], 0),
+        };
+        let tensor = B::reshape(tensor, Shape::new([num_rows, num_cols]));
+
+        let mut sum = B::zeros(Shape::new([num_cols]), &device);
+        let mut compensation = B::zeros(Shape::new([num_cols]), &device);
+
+        for row in 0..num_rows {
+            let value = B::reshape(
+                B::slice(tensor.clone(), [row..row + 1, 0..num_cols]),
+                Shape::new([num_cols]),
+            );
+            let value = B::sub(value, compensation);
+            let total = B::add(sum.clone(), value.clone());
+
+            // The low-order bits of the value lost in the addition
+            compensation = B::sub(B::sub(total.clone(), sum), value);
+            sum = total;
+        }
+
+        B::sum_stable(B::sub(sum, compensation))
+    }
+
+    /// Mean of all elements in a tensor using the compensated (Kahan) summation.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to mean.
+    ///
+    /// # Returns
+    ///
+    /// A scalar tensor with the mean of all elements in `tensor`.
+    fn mean_stable<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, 1> {
+        let num_elems = B::shape(&tensor).num_elements();
+        B::div_scalar(B::sum_stable(tensor), (num_elems as i64).elem())
+    }
+
     /// Mean of all elements in a tensor along a dimension.
     ///
     /// # Arguments
@@ -893,6 +1128,24 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with absolute values.
     fn abs<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Returns a new tensor with the sign of the elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the sign of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with `-1` for negative values, `0` for zeros
+    /// (including negative zeros) and `1` for positive values.
+    fn sign<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let zero = 0.elem();
+        let positive = B::bool_into_float(B::greater_elem(tensor.clone(), zero));
+        let negative = B::bool_into_float(B::lower_elem(tensor, zero));
+
+        B::sub(positive, negative)
+    }
+
     /// Returns a new tensor with cosine values.
     ///
     /// # Arguments
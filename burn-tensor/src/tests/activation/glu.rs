@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(glu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_glu_last_dim() {
+        let tensor = TestTensor::from([[1.0, -2.0, 0.5, 3.0], [-1.0, 2.0, 0.0, -0.5]]);
+
+        let data_actual = activation::glu(tensor, 1).into_data();
+
+        let data_expected = Data::from([[0.6225, -1.9051], [-0.5, 0.7551]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn test_glu_first_dim() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, -1.0]]);
+
+        let data_actual = activation::glu(tensor, 0).into_data();
+
+        let data_expected = Data::from([[0.9526, 0.5379]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_glu_odd_dim() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0]]);
+
+        let _output = activation::glu(tensor, 1);
+    }
+}
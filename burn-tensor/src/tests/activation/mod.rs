@@ -1,4 +1,6 @@
 pub(crate) mod gelu;
+pub(crate) mod glu;
+pub(crate) mod leaky_relu;
 pub(crate) mod mish;
 pub(crate) mod relu;
 pub(crate) mod sigmoid;
@@ -10,6 +10,8 @@ macro_rules! testgen_all {
     () => {
         // test activation
         burn_tensor::testgen_gelu!();
+        burn_tensor::testgen_glu!();
+        burn_tensor::testgen_leaky_relu!();
         burn_tensor::testgen_mish!();
         burn_tensor::testgen_relu!();
         burn_tensor::testgen_softmax!();
@@ -27,60 +29,97 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_unfold4d!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
+        burn_tensor::testgen_module_max_unpool2d!();
+        burn_tensor::testgen_module_local_response_norm!();
+        burn_tensor::testgen_module_interpolate!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_global_pool!();
+        burn_tensor::testgen_module_bilinear!();
 
         // test ops
         burn_tensor::testgen_add!();
+        burn_tensor::testgen_all_equal!();
+        burn_tensor::testgen_any_all!();
         burn_tensor::testgen_aggregation!();
         burn_tensor::testgen_arange!();
         burn_tensor::testgen_arange_step!();
+        burn_tensor::testgen_bool_logical!();
+        burn_tensor::testgen_broadcast!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_argwhere!();
+        burn_tensor::testgen_assert_shape!();
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_checked!();
         burn_tensor::testgen_chunk!();
+        burn_tensor::testgen_split!();
         burn_tensor::testgen_clamp!();
+        burn_tensor::testgen_clamp_tensor!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_diag!();
+        burn_tensor::testgen_cumlogsumexp!();
         burn_tensor::testgen_create_like!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
+        burn_tensor::testgen_expand!();
+        burn_tensor::testgen_fftshift!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_index_fill!();
         burn_tensor::testgen_init!();
+        burn_tensor::testgen_is_nan_inf!();
         burn_tensor::testgen_iter_dim!();
+        burn_tensor::testgen_into_data_chunks!();
+        burn_tensor::testgen_into_scalar!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
         burn_tensor::testgen_map_comparison!();
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
+        burn_tensor::testgen_meshgrid!();
+        burn_tensor::testgen_min_max_normalize!();
+        burn_tensor::testgen_prefix_max!();
+        burn_tensor::testgen_memory_footprint!();
         burn_tensor::testgen_maxmin!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_narrow!();
+        burn_tensor::testgen_pad!();
         burn_tensor::testgen_neg!();
         burn_tensor::testgen_one_hot!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_prod!();
         burn_tensor::testgen_random!();
         burn_tensor::testgen_recip!();
+        burn_tensor::testgen_floor!();
+        burn_tensor::testgen_ceil!();
+        burn_tensor::testgen_round!();
+        burn_tensor::testgen_remainder!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_roll!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
+        burn_tensor::testgen_sort!();
         burn_tensor::testgen_stack!();
         burn_tensor::testgen_sqrt!();
         burn_tensor::testgen_abs!();
+        burn_tensor::testgen_sign!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
+        burn_tensor::testgen_sum_stable!();
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_tri!();
 
         // test stats
         burn_tensor::testgen_var!();
+        burn_tensor::testgen_std_dev!();
         burn_tensor::testgen_cov!();
         burn_tensor::testgen_diagonal!();
         burn_tensor::testgen_display!();
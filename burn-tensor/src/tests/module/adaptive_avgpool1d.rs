@@ -46,6 +46,42 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_adaptive_avg_pool1d_uneven_bigger_output() {
+        // The overlapping windows average the neighbors of the repeated values
+        let test = AdaptiveAvgPool1dTestCase {
+            batch_size: 1,
+            channels: 1,
+            length: 3,
+            length_out: 5,
+        };
+
+        test.assert_output(TestTensor::from([[[0.0, 0.5, 1.0, 1.5, 2.0]]]));
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool1d_identity() {
+        let test = AdaptiveAvgPool1dTestCase {
+            batch_size: 2,
+            channels: 2,
+            length: 3,
+            length_out: 3,
+        };
+
+        test.assert_output(TestTensor::from([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_adaptive_avg_pool1d_zero_output_size() {
+        let x = TestTensor::<3>::zeros([1, 2, 4], &Default::default());
+
+        adaptive_avg_pool1d(x, 0);
+    }
+
     struct AdaptiveAvgPool1dTestCase {
         batch_size: usize,
         channels: usize,
@@ -14,6 +14,7 @@ mod tests {
             stride: 1,
             length: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[1., 2., 3., 4.]]]));
@@ -29,6 +30,7 @@ mod tests {
             stride: 2,
             length: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[
@@ -47,6 +49,7 @@ mod tests {
             stride: 2,
             length: 6,
             count_include_pad: false,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[
@@ -55,6 +58,41 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_avg_pool1d_ceil_mode_exact_fit() {
+        // With a length of 5, the windows fit exactly and both modes give the same output.
+        for ceil_mode in [false, true] {
+            let test = AvgPool1dTestCase {
+                batch_size: 1,
+                channels: 1,
+                kernel_size: 3,
+                padding: 1,
+                stride: 2,
+                length: 5,
+                count_include_pad: true,
+                ceil_mode,
+            };
+
+            test.assert_output(TestTensor::from([[[0.3333, 2.0000, 2.3333]]]));
+        }
+    }
+
+    #[test]
+    fn test_avg_pool1d_ceil_mode() {
+        let test = AvgPool1dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: 3,
+            padding: 0,
+            stride: 2,
+            length: 6,
+            count_include_pad: true,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from([[[1.0000, 3.0000, 4.5000]]]));
+    }
+
     struct AvgPool1dTestCase {
         batch_size: usize,
         channels: usize,
@@ -63,6 +101,7 @@ mod tests {
         stride: usize,
         length: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     }
 
     impl AvgPool1dTestCase {
@@ -80,6 +119,7 @@ mod tests {
                 self.stride,
                 self.padding,
                 self.count_include_pad,
+                self.ceil_mode,
             );
 
             y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -18,6 +18,7 @@ mod tests {
             height: 6,
             width: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[
@@ -42,6 +43,7 @@ mod tests {
             height: 4,
             width: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[
@@ -66,6 +68,7 @@ mod tests {
             height: 4,
             width: 6,
             count_include_pad: false,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[
@@ -76,6 +79,30 @@ mod tests {
         ]]]));
     }
 
+    #[test]
+    fn test_avg_pool2d_ceil_mode() {
+        let test = AvgPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 1,
+            padding_2: 1,
+            stride_1: 2,
+            stride_2: 2,
+            height: 4,
+            width: 4,
+            count_include_pad: true,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [1.1111, 2.6667, 1.6667],
+            [5.6667, 10.0000, 5.5000],
+            [4.1667, 7.0000, 3.7500],
+        ]]]));
+    }
+
     struct AvgPool2dTestCase {
         batch_size: usize,
         channels: usize,
@@ -88,6 +115,7 @@ mod tests {
         height: usize,
         width: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     }
 
     impl AvgPool2dTestCase {
@@ -105,6 +133,7 @@ mod tests {
                 [self.stride_1, self.stride_2],
                 [self.padding_1, self.padding_2],
                 self.count_include_pad,
+                self.ceil_mode,
             );
 
             y.to_data().assert_approx_eq(&output.into_data(), 3);
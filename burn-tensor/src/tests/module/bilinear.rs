@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(module_bilinear)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::bilinear;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_bilinear_with_bias() {
+        let device = Default::default();
+        let x1 = TestTensor::from_floats([[1.0, 2.0], [3.0, -1.0]], &device);
+        let x2 = TestTensor::from_floats([[4.0, 5.0], [2.0, 1.0]], &device);
+        let weight = TestTensor::from_floats(
+            [[[1.0, 0.0], [0.0, 1.0]], [[0.0, 1.0], [2.0, 0.0]]],
+            &device,
+        );
+        let bias = TestTensor::from_floats([0.5, -1.0], &device);
+
+        let output = bilinear(x1, x2, weight, Some(bias));
+
+        // The first output is the dot product of the inputs, and the second one is
+        // x1[0] * x2[1] + 2 * x1[1] * x2[0].
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[14.5, 20.0], [5.5, -2.0]]), 3);
+    }
+
+    #[test]
+    fn test_bilinear_without_bias() {
+        let device = Default::default();
+        let x1 = TestTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+        let x2 = TestTensor::from_floats([[2.0, -1.0]], &device);
+        let weight = TestTensor::from_floats([[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]], &device);
+
+        let output = bilinear(x1, x2, weight, None);
+
+        // x1^T W = [22, 28], then [22, 28] . [2, -1] = 16
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[16.0]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bilinear_weight_not_matching_inputs() {
+        let device = Default::default();
+        let x1 = TestTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+        let x2 = TestTensor::from_floats([[2.0, -1.0]], &device);
+        let weight = TestTensor::<3>::zeros([1, 2, 2], &device);
+
+        let _ = bilinear(x1, x2, weight, None);
+    }
+}
@@ -69,6 +69,78 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv2d_dilation() {
+        let test = Conv2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            padding_1: 2,
+            padding_2: 2,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 2,
+            dilation_2: 2,
+            groups: 1,
+            height: 5,
+            width: 5,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [2068., 2152., 3121., 1996., 2072.],
+                [2488., 2572., 3721., 2376., 2452.],
+                [3459., 3567., 5145., 3273., 3369.],
+                [1924., 1984., 2839., 1788., 1840.],
+                [2224., 2284., 3259., 2048., 2100.],
+            ],
+            [
+                [4733., 4961., 7334., 4805., 5025.],
+                [5873., 6101., 9014., 5905., 6125.],
+                [8536., 8860., 13084., 8566., 8878.],
+                [5309., 5513., 8132., 5317., 5513.],
+                [6329., 6533., 9632., 6297., 6493.],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv2d_groups_dilation() {
+        let test = Conv2dTestCase {
+            batch_size: 1,
+            channels_in: 4,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 1,
+            padding_2: 1,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 2,
+            dilation_2: 2,
+            groups: 2,
+            height: 4,
+            width: 4,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [162., 300., 318., 144.],
+                [288., 528., 556., 248.],
+                [352., 640., 668., 296.],
+                [134., 236., 246., 104.],
+            ],
+            [
+                [1203., 2317., 2367., 1137.],
+                [2241., 4305., 4397., 2105.],
+                [2433., 4673., 4765., 2281.],
+                [1111., 2125., 2167., 1033.],
+            ],
+        ]]));
+    }
+
     #[test]
     fn test_conv2d_complex() {
         let test = Conv2dTestCase {
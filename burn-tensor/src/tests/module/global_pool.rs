@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(module_global_pool)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{
+        global_avg_pool1d, global_avg_pool2d, global_max_pool1d, global_max_pool2d,
+    };
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_global_avg_pool2d_per_channel_means() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 5.0], [0.0, 8.0]]]]);
+
+        let output = global_avg_pool2d(x);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.5]], [[3.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_global_max_pool2d() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 5.0], [0.0, 8.0]]]]);
+
+        let output = global_max_pool2d(x);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[4.0]], [[8.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_global_pool1d() {
+        let x = TestTensor::from([[[1.0, -2.0, 4.0, 1.0], [0.0, 6.0, -3.0, 1.0]]]);
+
+        let avg = global_avg_pool1d(x.clone());
+        let max = global_max_pool1d(x);
+
+        avg.into_data()
+            .assert_approx_eq(&Data::from([[[1.0], [1.0]]]), 3);
+        max.into_data()
+            .assert_approx_eq(&Data::from([[[4.0], [6.0]]]), 3);
+    }
+}
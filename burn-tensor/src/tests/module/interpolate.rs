@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(module_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let y = TestTensor::from([[[
+            [1.0, 1.3333, 1.6667, 2.0],
+            [1.6667, 2.0, 2.3333, 2.6667],
+            [2.3333, 2.6667, 3.0, 3.3333],
+            [3.0, 3.3333, 3.6667, 4.0],
+        ]]]);
+
+        let output = interpolate(
+            x,
+            [4, 4],
+            InterpolateOptions::new(InterpolateMode::Bilinear, true),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_half_pixel() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let y = TestTensor::from([[[
+            [1.0, 1.25, 1.75, 2.0],
+            [1.5, 1.75, 2.25, 2.5],
+            [2.5, 2.75, 3.25, 3.5],
+            [3.0, 3.25, 3.75, 4.0],
+        ]]]);
+
+        let output = interpolate(
+            x,
+            [4, 4],
+            InterpolateOptions::new(InterpolateMode::Bilinear, false),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_interpolate_nearest() {
+        let x = TestTensor::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let y = TestTensor::from([[[[1.0, 2.0], [1.0, 2.0], [4.0, 5.0]]]]);
+
+        let output = interpolate(
+            x,
+            [3, 2],
+            InterpolateOptions::new(InterpolateMode::Nearest, false),
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+}
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(module_local_response_norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::local_response_norm;
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn test_local_response_norm_odd_size() {
+        let x = TestTensor::from([[
+            [-2.5, -2.0, -1.5],
+            [-1.0, -0.5, 0.0],
+            [0.5, 1.0, 1.5],
+            [2.0, 2.5, 3.0],
+        ]]);
+        let y = TestTensor::from([[
+            [-1.38, -1.3384, -1.1813],
+            [-0.5443, -0.312, 0.0],
+            [0.312, 0.5443, 0.6794],
+            [1.3384, 1.38, 1.3588],
+        ]]);
+
+        let output = local_response_norm(x, 3, 0.5, 0.75, 1.0);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_local_response_norm_even_size() {
+        let x = TestTensor::from([[
+            [-2.5, -2.0, -1.5],
+            [-1.0, -0.5, 0.0],
+            [0.5, 1.0, 1.5],
+            [2.0, 2.5, 3.0],
+        ]]);
+        let y = TestTensor::from([[
+            [-1.0541, -0.9847, -0.8485],
+            [-0.6172, -0.3086, 0.0],
+            [0.2462, 0.4216, 0.5432],
+            [1.0, 1.1043, 1.1767],
+        ]]);
+
+        let output = local_response_norm(x, 2, 1.0, 0.5, 2.0);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_local_response_norm_zero_size() {
+        let x = TestTensor::from([[[1.0, 2.0], [3.0, 4.0]]]);
+
+        local_response_norm(x, 0, 1.0, 0.5, 2.0);
+    }
+}
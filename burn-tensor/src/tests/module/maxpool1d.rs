@@ -22,7 +22,7 @@ mod tests {
             [0.949, 0.949, 0.949, 0.789],
         ]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -37,7 +37,7 @@ mod tests {
         let x = TestTensor::from([[[0.6309, 0.6112, 0.6998, 0.4708]]]);
         let y = TestTensor::from([[[0.6309, 0.6998]]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -52,7 +52,7 @@ mod tests {
         let x = TestTensor::from([[[-0.6309, -0.6112, -0.6998, -0.4708]]]);
         let y = TestTensor::from([[[-0.6112, -0.6112, -0.4708, -0.4708]]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -73,7 +73,7 @@ mod tests {
             [0.5474, 0.9490, 0.7890, 0.9490, 0.7890, 0.5537],
         ]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -90,7 +90,7 @@ mod tests {
         let y = TestTensor::from([[[0.6386, 0.6386, 0.5742]]]);
 
         let (output, output_indices) =
-            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation);
+            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         assert_eq!(indices.value, output_indices.into_data().value);
@@ -108,9 +108,50 @@ mod tests {
         let y = TestTensor::from([[[0.5388, 0.7122, 0.8316, 0.8316, 0.8316, 0.8316]]]);
 
         let (output, output_indices) =
-            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation);
+            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         assert_eq!(indices.value, output_indices.into_data().value);
     }
+
+    #[test]
+    fn test_max_pool1d_ceil_mode_exact_fit() {
+        let kernel_size = 3;
+        let padding = 0;
+        let stride = 2;
+        let dilation = 1;
+
+        // With a length of 5, the windows fit exactly and both modes give the same output.
+        for ceil_mode in [false, true] {
+            let x = TestTensor::from([[[0.1, 0.9, 0.3, 0.7, 0.5]]]);
+            let y = TestTensor::from([[[0.9, 0.7]]]);
+
+            let output = max_pool1d(x, kernel_size, stride, padding, dilation, ceil_mode);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn test_max_pool1d_ceil_mode() {
+        let kernel_size = 3;
+        let padding = 0;
+        let stride = 2;
+        let dilation = 1;
+
+        let x = TestTensor::from([[[0.1, 0.9, 0.3, 0.7, 0.5, 0.8]]]);
+        let indices = Data::<IntElem, 3>::from([[[1, 3, 5]]]);
+        let y = TestTensor::from([[[0.9, 0.7, 0.8]]]);
+        let y_floor = TestTensor::from([[[0.9, 0.7]]]);
+
+        let (output, output_indices) =
+            max_pool1d_with_indices(x.clone(), kernel_size, stride, padding, dilation, true);
+        let output_floor = max_pool1d(x, kernel_size, stride, padding, dilation, false);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        y_floor
+            .to_data()
+            .assert_approx_eq(&output_floor.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
 }
@@ -102,6 +102,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -143,6 +144,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -185,6 +187,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -224,6 +227,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -268,6 +272,42 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
+
+    #[test]
+    fn test_max_pool2d_with_indices_dilation() {
+        let batch_size = 1;
+        let channels_in = 1;
+        let kernel_size_1 = 2;
+        let kernel_size_2 = 2;
+        let padding_1 = 0;
+        let padding_2 = 0;
+        let stride_1 = 1;
+        let stride_2 = 1;
+        let dilation_1 = 2;
+        let dilation_2 = 2;
+
+        let x = TestTensor::from([[[
+            [0.20, 0.90, 0.10, 0.50, 0.30],
+            [0.70, 0.40, 0.80, 0.60, 0.00],
+            [0.30, 0.60, 0.95, 0.20, 0.85],
+            [0.50, 0.10, 0.40, 0.75, 0.65],
+        ]]]);
+        let indices = Data::<IntElem, 4>::from([[[[12, 1, 12], [7, 18, 7]]]]);
+        let y = TestTensor::from([[[[0.95, 0.90, 0.95], [0.80, 0.75, 0.80]]]]);
+
+        let (output, output_indices) = max_pool2d_with_indices(
+            x,
+            [kernel_size_1, kernel_size_2],
+            [stride_1, stride_2],
+            [padding_1, padding_2],
+            [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -316,9 +356,33 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         assert_eq!(indices.value, output_indices.into_data().value);
     }
+
+    #[test]
+    fn test_max_pool2d_ceil_mode() {
+        let x = TestTensor::from([[[
+            [0., 1., 2., 3., 4.],
+            [5., 6., 7., 8., 9.],
+            [10., 11., 12., 13., 14.],
+            [15., 16., 17., 18., 19.],
+            [20., 21., 22., 23., 24.],
+        ]]]);
+        let y_floor = TestTensor::from([[[[6., 8.], [16., 18.]]]]);
+        let y_ceil = TestTensor::from([[[[6., 8., 9.], [16., 18., 19.], [21., 23., 24.]]]]);
+
+        let output_floor = max_pool2d(x.clone(), [2, 2], [2, 2], [0, 0], [1, 1], false);
+        let output_ceil = max_pool2d(x, [2, 2], [2, 2], [0, 0], [1, 1], true);
+
+        y_floor
+            .to_data()
+            .assert_approx_eq(&output_floor.into_data(), 3);
+        y_ceil
+            .to_data()
+            .assert_approx_eq(&output_ceil.into_data(), 3);
+    }
 }
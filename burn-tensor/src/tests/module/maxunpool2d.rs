@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(module_max_unpool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool2d_with_indices, max_unpool2d};
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn test_max_unpool2d_round_trip() {
+        let x = TestTensor::from([[[
+            [1., 5., 2., 0.],
+            [3., 4., 8., 6.],
+            [9., 0., 1., 2.],
+            [7., 6., 3., 4.],
+        ]]]);
+        let y = TestTensor::from([[[
+            [0., 5., 0., 0.],
+            [0., 0., 8., 0.],
+            [9., 0., 0., 0.],
+            [0., 0., 0., 4.],
+        ]]]);
+
+        let (pooled, indices) = max_pool2d_with_indices(x, [2, 2], [2, 2], [0, 0], [1, 1], false);
+        let output = max_unpool2d(pooled, indices, [2, 2], [2, 2], [0, 0], None);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_unpool2d_overlapping_windows() {
+        let x = TestTensor::from([[[[1., 2., 3.], [4., 9., 5.], [6., 7., 8.]]]]);
+        let y = TestTensor::from([[[[0., 0., 0.], [0., 9., 0.], [0., 0., 0.]]]]);
+
+        let (pooled, indices) = max_pool2d_with_indices(x, [2, 2], [1, 1], [0, 0], [1, 1], false);
+        let output = max_unpool2d(pooled, indices, [2, 2], [1, 1], [0, 0], None);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_unpool2d_output_size() {
+        let x = TestTensor::from([[[
+            [0., 1., 2., 3., 4.],
+            [5., 6., 7., 8., 9.],
+            [10., 11., 12., 13., 14.],
+            [15., 16., 17., 18., 19.],
+            [20., 21., 22., 23., 24.],
+        ]]]);
+        let y = TestTensor::from([[[
+            [0., 0., 0., 0., 0.],
+            [0., 6., 0., 8., 0.],
+            [0., 0., 0., 0., 0.],
+            [0., 16., 0., 18., 0.],
+            [0., 0., 0., 0., 0.],
+        ]]]);
+
+        let (pooled, indices) = max_pool2d_with_indices(x, [2, 2], [2, 2], [0, 0], [1, 1], false);
+        let output = max_unpool2d(pooled, indices, [2, 2], [2, 2], [0, 0], Some([5, 5]));
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+}
@@ -2,11 +2,16 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod avgpool1d;
 mod avgpool2d;
+mod bilinear;
 mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod forward;
+mod global_pool;
+mod interpolate;
+mod local_response_norm;
 mod maxpool1d;
 mod maxpool2d;
+mod maxunpool2d;
 mod unfold4d;
@@ -122,4 +122,37 @@ mod tests {
             Data::new(vec![5.0, 5.0, 3.0, 11.0, -3.0, 6.0], Shape::new([2, 1, 3]))
         );
     }
+
+    #[test]
+    fn test_should_sum_dims_3d() {
+        let tensor = TestTensor::from([
+            [[2.0, 4.0, 1.0], [7.0, -5.0, 3.0]],
+            [[3.0, 1.0, 2.0], [4.0, 2.0, 3.0]],
+        ]);
+
+        let data_actual = tensor.sum_dims(&[0, 2]).into_data();
+
+        assert_eq!(data_actual, Data::from([[[13.0], [14.0]]]));
+    }
+
+    #[test]
+    fn test_should_mean_dims_3d() {
+        let tensor = TestTensor::from([
+            [[2.0, 4.0, 1.0], [7.0, -5.0, 3.0]],
+            [[3.0, 1.0, 2.0], [4.0, 2.0, 3.0]],
+        ]);
+
+        let data_actual = tensor.mean_dims(&[0, 2]).into_data();
+
+        data_actual.assert_approx_eq(&Data::from([[[13.0 / 6.0], [14.0 / 6.0]]]), 3);
+    }
+
+    #[test]
+    fn test_should_sum_dims_int() {
+        let tensor = TestTensorInt::from([[[0, 1, 2], [3, 4, 5]], [[6, 7, 8], [9, 10, 11]]]);
+
+        let data_actual = tensor.sum_dims(&[0, 2]).into_data();
+
+        assert_eq!(data_actual, Data::from([[[24], [42]]]));
+    }
 }
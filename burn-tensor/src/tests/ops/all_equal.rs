@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(all_equal)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+
+    #[test]
+    fn should_compare_float_tensors() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        assert!(tensor.all_equal(&TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device)));
+        assert!(!tensor.all_equal(&TestTensor::from_floats([[1.0, 2.0], [3.0, 4.5]], &device)));
+    }
+
+    #[test]
+    fn should_compare_int_and_bool_tensors() {
+        let device = Default::default();
+        let int = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+        let bool = Tensor::<TestBackend, 1, Bool>::from_data(Data::from([true, false]), &device);
+
+        assert!(int.all_equal(&Tensor::arange(1..4, &device)));
+        assert!(!int.all_equal(&Tensor::from_ints([1, 2, 4], &device)));
+        assert!(bool.all_equal(&bool.clone()));
+        assert!(!bool.all_equal(&bool.clone().bool_not()));
+    }
+
+    #[test]
+    fn should_not_be_equal_with_different_shapes() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 1.0]], &device);
+
+        assert!(!tensor.all_equal(&TestTensor::ones([2, 1], &device)));
+        assert!(!tensor.all_equal(&TestTensor::ones([1, 3], &device)));
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(any_all)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    fn bool_tensor(data: [[bool; 3]; 2]) -> Tensor<TestBackend, 2, Bool> {
+        Tensor::from_bool(Data::from(data), &Default::default())
+    }
+
+    #[test]
+    fn should_reduce_all_false() {
+        let tensor = bool_tensor([[false; 3]; 2]);
+
+        assert_eq!(tensor.clone().any().into_data(), Data::from([false]));
+        assert_eq!(tensor.clone().all().into_data(), Data::from([false]));
+        assert_eq!(
+            tensor.clone().any_dim(1).into_data(),
+            Data::from([[false], [false]])
+        );
+        assert_eq!(
+            tensor.all_dim(0).into_data(),
+            Data::from([[false, false, false]])
+        );
+    }
+
+    #[test]
+    fn should_reduce_all_true() {
+        let tensor = bool_tensor([[true; 3]; 2]);
+
+        assert_eq!(tensor.clone().any().into_data(), Data::from([true]));
+        assert_eq!(tensor.clone().all().into_data(), Data::from([true]));
+        assert_eq!(
+            tensor.clone().any_dim(0).into_data(),
+            Data::from([[true, true, true]])
+        );
+        assert_eq!(tensor.all_dim(1).into_data(), Data::from([[true], [true]]));
+    }
+
+    #[test]
+    fn should_reduce_mixed() {
+        let tensor = bool_tensor([[true, false, true], [true, true, true]]);
+
+        assert_eq!(tensor.clone().any().into_data(), Data::from([true]));
+        assert_eq!(tensor.clone().all().into_data(), Data::from([false]));
+        assert_eq!(
+            tensor.clone().all_dim(0).into_data(),
+            Data::from([[true, false, true]])
+        );
+        assert_eq!(
+            tensor.clone().all_dim(1).into_data(),
+            Data::from([[false], [true]])
+        );
+        assert_eq!(
+            tensor.bool_not().any_dim(1).into_data(),
+            Data::from([[true], [false]])
+        );
+    }
+}
@@ -11,4 +11,11 @@ mod tests {
         assert_eq!(tensor.clone().into_data(), Data::from([2, 3, 4]));
         assert_eq!(tensor.device(), device);
     }
+
+    #[test]
+    fn test_arange_from_zero() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..5, &device);
+        assert_eq!(tensor.into_data(), Data::from([0, 1, 2, 3, 4]));
+    }
 }
@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(argwhere)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_return_coordinates_of_true_elements() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[false, true, false], [true, false, true]]),
+            &Default::default(),
+        );
+
+        let coordinates = tensor.argwhere();
+
+        assert_eq!(coordinates, vec![[0, 1], [1, 0], [1, 2]]);
+    }
+
+    #[test]
+    fn should_return_no_coordinates_without_true_elements() {
+        let tensor = TestTensor::<3>::ones([2, 1, 3], &Default::default()).equal_elem(0.0);
+
+        assert!(tensor.argwhere().is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(assert_shape)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_return_the_tensor_when_the_shape_matches() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &Default::default());
+
+        let output = tensor.assert_shape([2, 3]).mul_scalar(2.0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 4.0, 6.0], [8.0, 10.0, 12.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected shape [3, 2], got shape [2, 3].")]
+    fn should_panic_when_the_shape_does_not_match() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &Default::default());
+
+        tensor.assert_shape([3, 2]);
+    }
+}
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(bool_logical)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    fn masks() -> (Tensor<TestBackend, 2, Bool>, Tensor<TestBackend, 2, Bool>) {
+        let device = Default::default();
+        let row = Tensor::from_bool(Data::from([[true, false, true, false]]), &device);
+        let mask = Tensor::from_bool(
+            Data::from([
+                [true, true, false, false],
+                [false, false, false, false],
+                [true, true, true, true],
+            ]),
+            &device,
+        );
+
+        (row, mask)
+    }
+
+    #[test]
+    fn should_support_bool_and_with_broadcast() {
+        let (row, mask) = masks();
+
+        assert_eq!(
+            row.bool_and(mask).into_data(),
+            Data::from([
+                [true, false, false, false],
+                [false, false, false, false],
+                [true, false, true, false]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_bool_or_with_broadcast() {
+        let (row, mask) = masks();
+
+        assert_eq!(
+            mask.bool_or(row).into_data(),
+            Data::from([
+                [true, true, true, false],
+                [true, false, true, false],
+                [true, true, true, true]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_bool_xor_with_broadcast() {
+        let (row, mask) = masks();
+
+        assert_eq!(
+            row.bool_xor(mask).into_data(),
+            Data::from([
+                [false, true, true, false],
+                [true, false, true, false],
+                [false, true, false, true]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_select_range_with_composite_mask() {
+        let tensor = TestTensor::from_floats([0.0, 1.0, 2.0, 3.0, 4.0], &Default::default());
+
+        let mask = tensor
+            .clone()
+            .greater_elem(0.5)
+            .bool_and(tensor.lower_elem(3.5));
+
+        assert_eq!(
+            mask.clone().into_data(),
+            Data::from([false, true, true, true, false])
+        );
+        assert_eq!(
+            mask.bool_not().into_data(),
+            Data::from([true, false, false, false, true])
+        );
+    }
+}
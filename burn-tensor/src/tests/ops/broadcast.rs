@@ -0,0 +1,90 @@
+#[burn_tensor_testgen::testgen(broadcast)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    fn operands() -> (TestTensor<3>, TestTensor<3>) {
+        let device = Default::default();
+        let lhs =
+            TestTensor::from_floats([[[1.0, 2.0, 3.0, 4.0]], [[5.0, 6.0, 7.0, 8.0]]], &device);
+        let rhs = TestTensor::from_floats([[[1.0], [2.0], [4.0]]], &device);
+
+        (lhs, rhs)
+    }
+
+    #[test]
+    fn should_broadcast_both_operands() {
+        let (lhs, rhs) = operands();
+
+        let add = lhs.clone() + rhs.clone();
+        let sub = lhs.clone() - rhs.clone();
+        let mul = lhs.clone() * rhs.clone();
+        let div = lhs.clone() / rhs.clone();
+
+        for output in [&add, &sub, &mul, &div] {
+            assert_eq!(output.dims(), [2, 3, 4]);
+        }
+        let lhs = lhs.into_data().value;
+        let rhs = rhs.into_data().value;
+        let (add, sub, mul, div) = (
+            add.into_data().value,
+            sub.into_data().value,
+            mul.into_data().value,
+            div.into_data().value,
+        );
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..4 {
+                    let (a, b) = (lhs[i * 4 + k], rhs[j]);
+                    let index = (i * 3 + j) * 4 + k;
+                    assert_eq!(add[index], a + b);
+                    assert_eq!(sub[index], a - b);
+                    assert_eq!(mul[index], a * b);
+                    assert_eq!(div[index], a / b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_broadcast_rows_and_columns() {
+        let device = Default::default();
+        let column = TestTensor::from_floats([[1.0], [2.0], [3.0]], &device);
+        let row = TestTensor::from_floats([[10.0, 20.0, 30.0, 40.0, 50.0]], &device);
+
+        let output = column + row;
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [11.0, 21.0, 31.0, 41.0, 51.0],
+                [12.0, 22.0, 32.0, 42.0, 52.0],
+                [13.0, 23.0, 33.0, 43.0, 53.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_broadcast_single_element() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0], &device);
+        let scalar = TestTensor::from_floats([2.0], &device);
+
+        assert_eq!(
+            (scalar.clone() - tensor.clone()).into_data(),
+            Data::from([1.0, 0.0, -1.0])
+        );
+        assert_eq!((tensor / scalar).into_data(), Data::from([0.5, 1.0, 1.5]));
+    }
+
+    #[test]
+    fn should_broadcast_both_int_operands() {
+        let device = Default::default();
+        let column = Tensor::<TestBackend, 2, Int>::from_ints([[1], [2]], &device);
+        let row = Tensor::<TestBackend, 2, Int>::from_ints([[10, 20, 30]], &device);
+
+        let output = column * row;
+
+        assert_eq!(output.into_data(), Data::from([[10, 20, 30], [20, 40, 60]]));
+    }
+}
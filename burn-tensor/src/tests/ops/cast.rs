@@ -39,4 +39,44 @@ mod tests {
         let expected = Data::from([[1., 0., 1.], [0., 0., 1.]]);
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn cast_float_as_int_tensor() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.4, 5.5, 6.6]]);
+        let other = TestTensorInt::from([[0, 0], [0, 0]]);
+
+        let actual = tensor.type_as(&other).into_data();
+        let expected = Data::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn cast_int_as_float_tensor() {
+        let tensor = TestTensorInt::from([[1, 2, 3], [4, 5, 6]]);
+        let other = TestTensor::from([0.0]);
+
+        let actual = tensor.type_as(&other).into_data();
+        let expected = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn cast_float_as_bool_tensor() {
+        let tensor = TestTensor::from([[0.0, 1.5, -2.0], [0.0, 0.0, 3.0]]);
+        let other = TestTensorBool::from([true]);
+
+        let actual = tensor.type_as(&other).into_data();
+        let expected = Data::from([[false, true, true], [false, false, true]]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn cast_int_as_bool_tensor() {
+        let tensor = TestTensorInt::from([[0, 1, -2], [0, 0, 3]]);
+        let other = TestTensorBool::from([true]);
+
+        let actual = tensor.type_as(&other).into_data();
+        let expected = Data::from([[false, true, true], [false, false, true]]);
+        assert_eq!(expected, actual);
+    }
 }
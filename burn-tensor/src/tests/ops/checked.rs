@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(checked)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    /// The message printed by the panic of the unchecked operation.
+    fn panic_message<F: FnOnce()>(func: F) -> String {
+        let payload = catch_unwind(AssertUnwindSafe(func)).unwrap_err();
+
+        payload.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn checked_add_should_return_the_panic_message_for_incompatible_shapes() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let error = tensor_1.clone().checked_add(tensor_2.clone()).unwrap_err();
+        let message = panic_message(|| {
+            let _ = tensor_1 + tensor_2;
+        });
+
+        assert_eq!(error.to_string(), message);
+    }
+
+    #[test]
+    fn checked_add_should_add_compatible_tensors() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[10.0, 20.0]], &device);
+
+        let output = tensor_1.checked_add(tensor_2).unwrap();
+
+        assert_eq!(output.into_data(), Data::from([[11.0, 22.0], [13.0, 24.0]]));
+    }
+
+    #[test]
+    fn checked_matmul_should_return_the_panic_message_for_incompatible_shapes() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+
+        let error = tensor_1
+            .clone()
+            .checked_matmul(tensor_2.clone())
+            .unwrap_err();
+        let message = panic_message(|| {
+            let _ = tensor_1.matmul(tensor_2);
+        });
+
+        assert_eq!(error.to_string(), message);
+    }
+
+    #[test]
+    fn checked_cat_should_return_the_panic_message_for_incompatible_shapes() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[4.0, 5.0]], &device);
+
+        let error =
+            TestTensor::checked_cat(vec![tensor_1.clone(), tensor_2.clone()], 0).unwrap_err();
+        let message = panic_message(|| {
+            let _ = TestTensor::cat(vec![tensor_1, tensor_2], 0);
+        });
+
+        assert_eq!(error.to_string(), message);
+    }
+
+    #[test]
+    fn checked_cat_should_concatenate_tensors() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[1.0, 2.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[3.0, 4.0]], &device);
+
+        let output = TestTensor::checked_cat(vec![tensor_1, tensor_2], 0).unwrap();
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 2.0], [3.0, 4.0]]));
+    }
+}
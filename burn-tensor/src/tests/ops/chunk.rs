@@ -4,6 +4,7 @@ mod tests {
     use alloc::vec::Vec;
     use burn_tensor::{Data, Int, Shape, Tensor};
 
+    #[test]
     fn test_chunk_evenly_divisible() {
         let tensors: Vec<Tensor<TestBackend, 1, Int>> =
             Tensor::arange(0..12, &Default::default()).chunk(6, 0);
@@ -56,6 +57,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chunk_not_evenly_divisible_returns_less_chunks() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..7, &Default::default()).chunk(5, 0);
+        assert_eq!(tensors.len(), 4);
+
+        let expected = vec![
+            Data::from([0, 1]),
+            Data::from([2, 3]),
+            Data::from([4, 5]),
+            Data::from([6]),
+        ];
+
+        for (index, tensor) in tensors.iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
     #[test]
     fn test_chunk_not_divisible() {
         let tensors: Vec<Tensor<TestBackend, 1, Int>> =
@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(clamp_tensor)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_clamp_between_tensor_bounds() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[-2.0, 0.5, 3.0], [1.0, -1.0, 4.0]], &device);
+        let min = TestTensor::from_floats([[-1.0, 1.0, 0.0], [0.0, 0.0, 0.0]], &device);
+        let max = TestTensor::from_floats([[1.0, 2.0, 2.0], [3.0, 3.0, 3.0]], &device);
+
+        let output = tensor.clamp_tensor(min, max);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[-1.0, 1.0, 2.0], [1.0, 0.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_clamp_with_broadcasted_bounds() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[0, 5, 9], [2, 7, 4]], &device);
+        let min = Tensor::from_ints([[1], [3]], &device);
+        let max = Tensor::from_ints([[4, 6, 8]], &device);
+
+        let output = tensor.clamp_tensor(min, max);
+
+        assert_eq!(output.into_data(), Data::from([[1, 5, 8], [3, 6, 4]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_bounds_cannot_be_broadcasted() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0]], &device);
+
+        tensor.clamp_tensor(
+            TestTensor::zeros([2, 2], &device),
+            TestTensor::ones([1, 2], &device),
+        );
+    }
+}
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(cumlogsumexp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn should_match_naive_cumlogsumexp() {
+        let values = [[0.0, 1.0, -2.0, 0.5], [3.0, -1.0, 2.0, 2.0]];
+        let tensor = TestTensor::from_floats(values, &Default::default());
+
+        let output = tensor.cumlogsumexp(1);
+
+        // log(cumsum(exp(x))) is accurate enough for small values
+        let mut expected = [[0.0; 4]; 2];
+        for (row, expected) in values.iter().zip(expected.iter_mut()) {
+            let mut sum = 0.0f32;
+            for (value, expected) in row.iter().zip(expected.iter_mut()) {
+                sum += value.exp();
+                *expected = sum.ln();
+            }
+        }
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from(expected), 4);
+    }
+
+    #[test]
+    fn should_support_cumlogsumexp_dim_0() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [0.0, -1.0]], &Default::default());
+
+        let output = tensor.cumlogsumexp(0);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[0.0, 1.0], [core::f32::consts::LN_2, 1.126928]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_not_overflow_with_large_values() {
+        let tensor = TestTensor::from_floats([[1000.0, 1000.0, 999.0]], &Default::default());
+
+        let output = tensor.cumlogsumexp(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1000.0, 1000.6931, 1000.8620]]), 3);
+    }
+
+    #[test]
+    fn should_support_negative_infinity_prefix() {
+        let tensor = TestTensor::from_floats(
+            [[f32::NEG_INFINITY, f32::NEG_INFINITY, 0.0, f32::NEG_INFINITY]],
+            &Default::default(),
+        );
+
+        let output = tensor.cumlogsumexp(1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[f32::NEG_INFINITY, f32::NEG_INFINITY, 0.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn should_return_empty_tensor_unchanged() {
+        let tensor = TestTensor::<2>::zeros([2, 0], &Default::default());
+
+        let output = tensor.cumlogsumexp(1);
+
+        assert_eq!(output.shape(), Shape::new([2, 0]));
+    }
+}
@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(diag)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_extract_the_diagonal() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]], &device);
+
+        let diagonal = tensor.diag();
+
+        assert_eq!(diagonal.into_data(), Data::from([1.0, 5.0, 9.0]));
+    }
+
+    #[test]
+    fn should_embed_the_diagonal() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+
+        let matrix = tensor.diag_embed();
+
+        assert_eq!(
+            matrix.into_data(),
+            Data::from([[1, 0, 0], [0, 2, 0], [0, 0, 3]])
+        );
+    }
+
+    #[test]
+    fn should_extract_the_embedded_diagonal() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([-1.5, 0.0, 2.5, 4.0], &device);
+
+        let diagonal = tensor.clone().diag_embed().diag();
+
+        assert_eq!(diagonal.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_matrix_is_not_square() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        tensor.diag();
+    }
+}
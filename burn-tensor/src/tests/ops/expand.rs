@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn should_expand_dims_of_size_one() {
+        let tensor = TestTensor::from([[1.0], [2.0]]);
+
+        let output = tensor.expand([2, 3]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn should_expand_as_other_tensor() {
+        let tensor = TestTensorInt::from([[[1, 2]]]);
+        let other = TestTensor::zeros([3, 1, 2], &Default::default());
+
+        let output = tensor.expand_as(&other);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[1, 2]], [[1, 2]], [[1, 2]]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_expanding_dim_larger_than_one() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output = tensor.expand([2, 4]);
+    }
+
+    #[test]
+    fn should_reshape_as_other_tensor() {
+        let tensor = TestTensor::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let other = TestTensorInt::zeros([3, 2], &Default::default());
+
+        let output = tensor.reshape_as(&other);
+
+        assert_eq!(output.shape(), Shape::new([3, 2]));
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_reshape_as_other_tensor_with_different_rank() {
+        let tensor = TestTensor::from([[0.0, 1.0], [2.0, 3.0]]);
+        let other = Tensor::<TestBackend, 1>::zeros([4], &Default::default());
+
+        let output = tensor.reshape_as(&other);
+
+        assert_eq!(output.into_data(), Data::from([0.0, 1.0, 2.0, 3.0]));
+    }
+}
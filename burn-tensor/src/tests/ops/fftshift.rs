@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(fftshift)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_fftshift_even() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..6, &Default::default());
+
+        let data_actual = tensor.fftshift(0).into_data();
+
+        let data_expected = Data::from([3, 4, 5, 0, 1, 2]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_fftshift_odd() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..5, &Default::default());
+
+        let data_actual = tensor.fftshift(0).into_data();
+
+        let data_expected = Data::from([3, 4, 0, 1, 2]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn ifftshift_should_revert_fftshift_even() {
+        let tensor = TestTensor::from([[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]]);
+
+        let data_actual = tensor.clone().fftshift(1).ifftshift(1).into_data();
+
+        assert_eq!(tensor.into_data(), data_actual);
+    }
+
+    #[test]
+    fn ifftshift_should_revert_fftshift_odd() {
+        let tensor = TestTensor::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]);
+
+        let shifted = tensor.clone().fftshift(0).fftshift(1);
+        let data_actual = shifted.ifftshift(0).ifftshift(1).into_data();
+
+        assert_eq!(tensor.into_data(), data_actual);
+    }
+}
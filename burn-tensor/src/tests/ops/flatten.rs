@@ -30,6 +30,33 @@ mod tests {
         assert_eq!(flattened_tensor.shape(), expected_shape);
     }
 
+    /// Test if the function keeps the batch dimension and flattens the other ones.
+    #[test]
+    fn should_flatten_keep_batch() {
+        let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([8, 3, 4, 4]), &Default::default());
+        let flattened_tensor = tensor.flatten_keep_batch();
+        let expected_shape = Shape::new([8, 48]);
+        assert_eq!(flattened_tensor.shape(), expected_shape);
+    }
+
+    /// Test if the function can flatten the features of a 4D tensor while keeping the batch.
+    #[test]
+    fn should_flatten_from_dim() {
+        let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 4, 4]), &Default::default());
+        let flattened_tensor = tensor.flatten_from_dim(1);
+        let expected_shape = Shape::new([2, 48]);
+        assert_eq!(flattened_tensor.shape(), expected_shape);
+    }
+
+    /// Test if the function keeps the element order when flattening from a given dimension.
+    #[test]
+    fn should_flatten_from_dim_values() {
+        let tensor = TestTensor::from([[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]);
+        let flattened_tensor = tensor.flatten_from_dim(2);
+        let expected = Data::from([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0]]);
+        assert_eq!(flattened_tensor.into_data(), expected);
+    }
+
     /// Test if the function can successfully flatten the last dimensions of a 4D tensor.
     #[test]
     fn should_flatten_end() {
@@ -0,0 +1,15 @@
+#[burn_tensor_testgen::testgen(floor)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_floor_ops() {
+        let tensor = TestTensor::from([[-2.5, -1.5, -0.5], [0.5, 1.5, 2.5]]);
+
+        let data_actual = tensor.floor().into_data();
+
+        let data_expected = Data::from([[-3.0, -2.0, -1.0], [0.0, 1.0, 2.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -53,6 +53,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_gather_nd_2d() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[1, 2], [0, 0], [1, 0], [0, 2]], &device);
+
+        let output = tensor.gather_nd(indices);
+
+        assert_eq!(output.into_data(), Data::from([5.0, 0.0, 3.0, 2.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_nd_should_panic_on_incomplete_coordinates() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[1], [0]], &device);
+
+        tensor.gather_nd(indices);
+    }
+
     #[test]
     fn should_gather_3d_dim1() {
         let device = Default::default();
@@ -188,6 +209,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_scatter_nd_2d() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[1, 2], [0, 0]], &device);
+        let values = TestTensor::from_floats([7.0, 8.0], &device);
+
+        let output = tensor.scatter_nd(indices, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[8.0, 1.0, 2.0], [3.0, 4.0, 7.0]])
+        );
+    }
+
+    #[test]
+    fn should_scatter_nd_overwriting_large_values() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0e8, 1.0], [2.0, -1.0e8]], &device);
+        let indices = TestTensorInt::from_ints([[0, 0], [1, 1]], &device);
+        let values = TestTensor::from_floats([1.0, 3.0], &device);
+
+        let output = tensor.scatter_nd(indices, values);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 1.0], [2.0, 3.0]]));
+    }
+
     #[test]
     #[should_panic]
     fn scatter_should_panic_on_mismatch_of_shapes() {
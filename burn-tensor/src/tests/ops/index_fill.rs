@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(index_fill)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_fill_rows() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats(
+            [
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+            ],
+            &device,
+        );
+        let indices = Tensor::from_ints([0, 2], &device);
+
+        let output = tensor.index_fill(0, indices, -1.0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [-1.0, -1.0, -1.0, -1.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [-1.0, -1.0, -1.0, -1.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_fill_int_channels_with_repeated_indices() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..8, &device).reshape([2, 2, 2]);
+        let indices = Tensor::from_ints([1, 1], &device);
+
+        let output = tensor.index_fill(2, indices, 0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[0, 0], [2, 0]], [[4, 0], [6, 0]]])
+        );
+    }
+}
@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(into_data_chunks)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor};
+
+    #[test]
+    fn test_into_data_chunks() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..24, &Default::default())
+            .float()
+            .reshape([2, 3, 4]);
+
+        let chunks: Vec<_> = tensor.clone().into_data_chunks(5).collect();
+
+        assert_eq!(chunks.len(), 5);
+        assert!(chunks[..4].iter().all(|chunk| chunk.len() == 5));
+        assert_eq!(chunks[4].len(), 4);
+        assert_eq!(chunks.concat(), tensor.into_data().value);
+    }
+
+    #[test]
+    fn test_into_data_chunks_int() {
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [3, 4]], &Default::default());
+
+        let chunks: Vec<_> = tensor.into_data_chunks(8).collect();
+
+        assert_eq!(chunks, vec![vec![1, 2, 3, 4]]);
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(into_scalar)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_single_element_tensor_into_scalar() {
+        let tensor = TestTensor::<1>::from_floats([4.5], &Default::default());
+
+        assert_eq!(tensor.try_into_scalar(), Ok(4.5));
+    }
+
+    #[test]
+    fn should_return_error_when_tensor_has_more_than_one_element() {
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0], &Default::default());
+
+        let error = tensor.try_into_scalar().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "=== Tensor Operation Error ===\n  Operation: 'Into Scalar'\n  Reason:\n    1. Only \
+             tensors with 1 element can be converted into scalar. Current tensor has 2 elements \n"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_converting_multiple_elements_into_scalar() {
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0], &Default::default());
+
+        tensor.into_scalar();
+    }
+}
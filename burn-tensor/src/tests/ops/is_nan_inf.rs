@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(is_nan_inf)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_find_nan_from_division() {
+        let device = Default::default();
+        let numerator = TestTensor::from_floats([[0.0, 1.0], [0.0, -2.0]], &device);
+        let denominator = TestTensor::from_floats([[0.0, 2.0], [1.0, 0.0]], &device);
+
+        let output = numerator / denominator;
+
+        assert_eq!(
+            output.clone().is_nan().into_data(),
+            Data::from([[true, false], [false, false]])
+        );
+        assert_eq!(
+            output.clone().is_inf().into_data(),
+            Data::from([[false, false], [false, true]])
+        );
+        assert!(output.contains_nan());
+    }
+
+    #[test]
+    fn should_find_nan_and_inf_in_data() {
+        let tensor = TestTensor::from_floats(
+            [f32::NAN, 1.0, f32::INFINITY, f32::NEG_INFINITY, -0.0],
+            &Default::default(),
+        );
+
+        assert_eq!(
+            tensor.clone().is_nan().into_data(),
+            Data::from([true, false, false, false, false])
+        );
+        assert_eq!(
+            tensor.is_inf().into_data(),
+            Data::from([false, false, true, true, false])
+        );
+    }
+
+    #[test]
+    fn should_not_contain_nan() {
+        let tensor = TestTensor::<2>::from_floats([[1.0, f32::INFINITY]], &Default::default());
+
+        assert!(!tensor.contains_nan());
+    }
+}
@@ -66,4 +66,34 @@ mod tests {
         let data_expected = Data::from([[9, 7], [2, 9]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_select_where_ops() {
+        let device = Default::default();
+        let condition = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[true, false], [false, true]]),
+            &device,
+        );
+        let a = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device);
+        let b = TestTensor::from_data([[1.8, 2.8], [3.8, 4.8]], &device);
+
+        let data_actual = Tensor::select_where(condition, a, b).into_data();
+
+        let data_expected = Data::from([[1.0, 2.8], [3.8, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_select_where_when_shapes_are_not_equal() {
+        let device = Default::default();
+        let condition = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[true, false], [false, true]]),
+            &device,
+        );
+        let a = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device);
+        let b = TestTensor::from_data([[1.8, 2.8]], &device);
+
+        let _ = Tensor::select_where(condition, a, b);
+    }
 }
@@ -120,4 +120,115 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn test_matmul_broadcast_rhs_batch() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats(
+            [
+                [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+                [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+                [[12.0, 13.0, 14.0], [15.0, 16.0, 17.0]],
+                [[18.0, 19.0, 20.0], [21.0, 22.0, 23.0]],
+            ],
+            &device,
+        );
+        let tensor_2 = TestTensor::from_floats(
+            [[
+                [0.0, 1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0, 9.0],
+                [10.0, 11.0, 12.0, 13.0, 14.0],
+            ]],
+            &device,
+        );
+
+        let tensor_3 = tensor_1.matmul(tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([
+                [
+                    [25.0, 28.0, 31.0, 34.0, 37.0],
+                    [70.0, 82.0, 94.0, 106.0, 118.0]
+                ],
+                [
+                    [115.0, 136.0, 157.0, 178.0, 199.0],
+                    [160.0, 190.0, 220.0, 250.0, 280.0]
+                ],
+                [
+                    [205.0, 244.0, 283.0, 322.0, 361.0],
+                    [250.0, 298.0, 346.0, 394.0, 442.0]
+                ],
+                [
+                    [295.0, 352.0, 409.0, 466.0, 523.0],
+                    [340.0, 406.0, 472.0, 538.0, 604.0]
+                ]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_matmul_broadcast_lhs_batch() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]], &device);
+        let tensor_2 = TestTensor::from_floats(
+            [
+                [
+                    [0.0, 1.0, 2.0, 3.0, 4.0],
+                    [5.0, 6.0, 7.0, 8.0, 9.0],
+                    [10.0, 11.0, 12.0, 13.0, 14.0],
+                ],
+                [
+                    [15.0, 16.0, 17.0, 18.0, 19.0],
+                    [20.0, 21.0, 22.0, 23.0, 24.0],
+                    [25.0, 26.0, 27.0, 28.0, 29.0],
+                ],
+                [
+                    [30.0, 31.0, 32.0, 33.0, 34.0],
+                    [35.0, 36.0, 37.0, 38.0, 39.0],
+                    [40.0, 41.0, 42.0, 43.0, 44.0],
+                ],
+                [
+                    [45.0, 46.0, 47.0, 48.0, 49.0],
+                    [50.0, 51.0, 52.0, 53.0, 54.0],
+                    [55.0, 56.0, 57.0, 58.0, 59.0],
+                ],
+            ],
+            &device,
+        );
+
+        let tensor_3 = tensor_1.matmul(tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([
+                [
+                    [25.0, 28.0, 31.0, 34.0, 37.0],
+                    [70.0, 82.0, 94.0, 106.0, 118.0]
+                ],
+                [
+                    [70.0, 73.0, 76.0, 79.0, 82.0],
+                    [250.0, 262.0, 274.0, 286.0, 298.0]
+                ],
+                [
+                    [115.0, 118.0, 121.0, 124.0, 127.0],
+                    [430.0, 442.0, 454.0, 466.0, 478.0]
+                ],
+                [
+                    [160.0, 163.0, 166.0, 169.0, 172.0],
+                    [610.0, 622.0, 634.0, 646.0, 658.0]
+                ]
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_batch_dimensions_are_not_broadcastable() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<3>::zeros([2, 2, 3], &device);
+        let tensor_2 = TestTensor::<3>::zeros([3, 3, 5], &device);
+
+        let _ = tensor_1.matmul(tensor_2);
+    }
 }
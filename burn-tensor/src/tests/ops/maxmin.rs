@@ -133,4 +133,54 @@ mod tests {
         assert_eq!(output_expected, output_actual.into_data());
         assert_eq!(index_expected, index_actual.into_data());
     }
+
+    #[test]
+    fn test_max_with_argmax_2d() {
+        let tensor =
+            TestTensor::from_floats([[0.0, 7.0, 2.0], [3.0, 4.0, 5.0]], &Default::default());
+
+        let (output_actual, index_actual) = tensor.max_with_argmax();
+
+        assert_eq!(Data::from([7.]), output_actual.into_data());
+        assert_eq!(Data::from([1]), index_actual.into_data());
+    }
+
+    #[test]
+    fn test_min_with_argmin_2d() {
+        let tensor =
+            TestTensor::from_floats([[6.0, 1.0, 2.0], [3.0, -4.0, 5.0]], &Default::default());
+
+        let (output_actual, index_actual) = tensor.min_with_argmin();
+
+        assert_eq!(Data::from([-4.]), output_actual.into_data());
+        assert_eq!(Data::from([4]), index_actual.into_data());
+    }
+
+    #[test]
+    fn test_maximum_2d() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[0.0, 7.0, 2.0], [3.0, -4.0, 5.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[1.0, 6.0, 2.0], [-3.0, 4.0, 8.0]], &device);
+
+        let output = tensor_1.maximum(tensor_2);
+
+        assert_eq!(
+            Data::from([[1.0, 7.0, 2.0], [3.0, 4.0, 8.0]]),
+            output.into_data()
+        );
+    }
+
+    #[test]
+    fn test_minimum_2d() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_floats([[0.0, 7.0, 2.0], [3.0, -4.0, 5.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[1.0, 6.0, 2.0], [-3.0, 4.0, 8.0]], &device);
+
+        let output = tensor_1.minimum(tensor_2);
+
+        assert_eq!(
+            Data::from([[0.0, 6.0, 2.0], [-3.0, -4.0, 5.0]]),
+            output.into_data()
+        );
+    }
 }
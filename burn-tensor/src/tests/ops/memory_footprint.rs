@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(memory_footprint)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Bool, Data, Int, Tensor};
+    use core::mem::size_of;
+
+    #[test]
+    fn test_memory_footprint() {
+        let tensor = Tensor::<TestBackend, 2>::zeros([100, 100], &Default::default());
+
+        // 40000 bytes with f32 elements.
+        assert_eq!(
+            tensor.memory_footprint(),
+            100 * 100 * size_of::<<TestBackend as Backend>::FloatElem>()
+        );
+    }
+
+    #[test]
+    fn test_memory_footprint_int_and_bool() {
+        let device = Default::default();
+        let int = Tensor::<TestBackend, 1, Int>::arange(0..10, &device);
+        let bool = Tensor::<TestBackend, 2, Bool>::from_data(Data::from([[true, false]]), &device);
+
+        assert_eq!(
+            int.memory_footprint(),
+            10 * size_of::<<TestBackend as Backend>::IntElem>()
+        );
+        assert_eq!(bool.memory_footprint(), 2 * size_of::<bool>());
+    }
+}
@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(meshgrid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_meshgrid_of_aranges() {
+        let device = Default::default();
+        let rows = Tensor::<TestBackend, 1, Int>::arange(0..2, &device);
+        let cols = Tensor::<TestBackend, 1, Int>::arange(0..3, &device);
+
+        let (grid_rows, grid_cols) = rows.meshgrid(cols);
+
+        assert_eq!(grid_rows.into_data(), Data::from([[0, 0, 0], [1, 1, 1]]));
+        assert_eq!(grid_cols.into_data(), Data::from([[0, 1, 2], [0, 1, 2]]));
+    }
+
+    #[test]
+    fn should_support_meshgrid_float() {
+        let x = TestTensor::from_floats([0.5, 1.5, 2.5], &Default::default());
+        let y = TestTensor::from_floats([-1.0, 1.0], &Default::default());
+
+        let (grid_x, grid_y) = x.meshgrid(y);
+
+        assert_eq!(
+            grid_x.into_data(),
+            Data::from([[0.5, 0.5], [1.5, 1.5], [2.5, 2.5]])
+        );
+        assert_eq!(
+            grid_y.into_data(),
+            Data::from([[-1.0, 1.0], [-1.0, 1.0], [-1.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_meshgrid_with_single_element() {
+        let x = TestTensor::from_floats([3.0], &Default::default());
+        let y = TestTensor::from_floats([1.0, 2.0], &Default::default());
+
+        let (grid_x, grid_y) = x.meshgrid(y);
+
+        assert_eq!(grid_x.into_data(), Data::from([[3.0, 3.0]]));
+        assert_eq!(grid_y.into_data(), Data::from([[1.0, 2.0]]));
+    }
+}
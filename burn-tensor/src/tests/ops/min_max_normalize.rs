@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(min_max_normalize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_normalize_rows_to_unit_range() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [-4.0, 0.0, 4.0]], &Default::default());
+
+        let output = tensor.min_max_normalize(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.5, 1.0], [0.0, 0.5, 1.0]]), 5);
+    }
+
+    #[test]
+    fn should_normalize_constant_row_to_zeros() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [5.0, 5.0, 5.0]], &Default::default());
+
+        let output = tensor.min_max_normalize(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.5, 1.0], [0.0, 0.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_normalize_columns() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 10.0], [3.0, 10.0], [2.0, 10.0]], &Default::default());
+
+        let output = tensor.min_max_normalize(0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [1.0, 0.0], [0.5, 0.0]]), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_dim_is_out_of_bounds() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0]], &Default::default());
+
+        let output = tensor.min_max_normalize(2);
+    }
+}
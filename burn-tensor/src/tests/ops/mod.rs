@@ -1,22 +1,40 @@
 mod abs;
 mod add;
 mod aggregation;
+mod all_equal;
+mod any_all;
 mod arange;
 mod arange_step;
 mod arg;
+mod argwhere;
+mod assert_shape;
+mod bool_logical;
+mod broadcast;
 mod cast;
 mod cat;
+mod ceil;
+mod checked;
 mod chunk;
 mod clamp;
+mod clamp_tensor;
 mod cos;
 mod create_like;
+mod cumlogsumexp;
+mod diag;
 mod div;
 mod erf;
 mod exp;
+mod expand;
+mod fftshift;
 mod flatten;
+mod floor;
 mod full;
 mod gather_scatter;
+mod index_fill;
 mod init;
+mod into_data_chunks;
+mod into_scalar;
+mod is_nan_inf;
 mod iter_dim;
 mod log;
 mod log1p;
@@ -24,22 +42,35 @@ mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
+mod memory_footprint;
+mod meshgrid;
+mod min_max_normalize;
 mod mul;
 mod narrow;
 mod neg;
 mod one_hot;
+mod pad;
 mod powf;
+mod prefix_max;
+mod prod;
 mod random;
 mod recip;
+mod remainder;
 mod repeat;
 mod reshape;
+mod roll;
+mod round;
 mod select;
+mod sign;
 mod sin;
 mod slice;
+mod sort;
+mod split;
 mod sqrt;
 mod squeeze;
 mod stack;
 mod sub;
+mod sum_stable;
 mod tanh;
 mod transpose;
 mod tri;
@@ -23,6 +23,40 @@ mod tests {
             .assert_approx_eq(&Data::from([[2., 3.], [5., 6.], [8., 9.]]), 3);
     }
 
+    #[test]
+    fn test_narrow_middle_dim_3d() {
+        let tensor: Tensor<TestBackend, 3> = Tensor::from_data(
+            Data::from([
+                [
+                    [0., 1., 2.],
+                    [3., 4., 5.],
+                    [6., 7., 8.],
+                    [9., 10., 11.],
+                    [12., 13., 14.],
+                ],
+                [
+                    [15., 16., 17.],
+                    [18., 19., 20.],
+                    [21., 22., 23.],
+                    [24., 25., 26.],
+                    [27., 28., 29.],
+                ],
+            ]),
+            &Default::default(),
+        );
+
+        let output = tensor.narrow(1, 2, 2);
+
+        assert_eq!(output.shape(), Shape::from([2, 2, 3]));
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [[6., 7., 8.], [9., 10., 11.]],
+                [[21., 22., 23.], [24., 25., 26.]],
+            ]),
+            3,
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_narrow_invalid_dim() {
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(neg)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn should_support_neg_ops() {
@@ -13,4 +13,26 @@ mod tests {
         let data_expected = Data::from([[-0.0, -1.0, -2.0], [-3.0, -4.0, -5.0]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_neg_ops_int() {
+        let data = Data::from([[0, 1, -2], [3, -4, 5]]);
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(data, &Default::default());
+
+        let data_actual = tensor.neg().into_data();
+
+        let data_expected = Data::from([[0, -1, 2], [-3, 4, -5]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_neg_operator_int() {
+        let data = Data::from([3, -7]);
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data(data, &Default::default());
+
+        let data_actual = (-tensor).into_data();
+
+        let data_expected = Data::from([-3, 7]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(one_hot)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Int};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn should_support_one_hot() {
@@ -33,4 +33,25 @@ mod tests {
         let device = Default::default();
         let tensor = TestTensor::<1>::one_hot(0, 0, &device);
     }
+
+    #[test]
+    fn should_support_one_hot_from_int_indices() {
+        let device = Default::default();
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 1, 2], &device);
+
+        let tensor = indices.one_hot(3);
+
+        assert_eq!(
+            tensor.to_data(),
+            Data::from([[1., 0., 0.], [0., 0., 1.], [0., 1., 0.], [0., 0., 1.]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_int_one_hot_has_no_classes() {
+        let device = Default::default();
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([0, 1], &device);
+        let tensor = indices.one_hot(0);
+    }
 }
@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_pad_with_constant_value() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &Default::default());
+
+        let output = tensor.pad([(1, 0), (0, 2)], 9.0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [9.0, 9.0, 9.0, 9.0],
+                [1.0, 2.0, 9.0, 9.0],
+                [3.0, 4.0, 9.0, 9.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_int_tensor() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &Default::default());
+
+        let output = tensor.pad([(2, 1)], 0);
+
+        assert_eq!(output.into_data(), Data::from([0, 0, 1, 2, 3, 0]));
+    }
+}
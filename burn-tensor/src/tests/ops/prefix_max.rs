@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(prefix_max)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_compute_the_envelope_of_a_noisy_increasing_sequence() {
+        let values = [0.0, 0.5, 0.3, 1.2, 0.9, 1.1, 2.0, 1.8, 2.5, 2.4, 2.3];
+        let tensor = TestTensor::from_floats(values, &Default::default());
+
+        let output = tensor.prefix_max(0);
+
+        let expected = [0.0, 0.5, 0.5, 1.2, 1.2, 1.2, 2.0, 2.0, 2.5, 2.5, 2.5];
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from(expected), 3);
+
+        // The running maximum is monotonic
+        let output = output.into_data().value;
+        assert!(output.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn should_support_prefix_max_dim_0() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 5.0], [3.0, 2.0], [2.0, 6.0]], &Default::default());
+
+        let output = tensor.prefix_max(0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 5.0], [3.0, 5.0], [3.0, 6.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_prefix_max_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints(
+            [[3, -1, 4, 1, 5], [-2, -7, -1, -8, 0]],
+            &Default::default(),
+        );
+
+        let output = tensor.prefix_max(1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3, 3, 4, 4, 5], [-2, -2, -1, -1, 0]])
+        );
+    }
+}
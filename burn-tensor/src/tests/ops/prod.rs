@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(prod)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_prod_ops() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, -3.0], [0.5, 4.0, 2.0]], &Default::default());
+
+        let output = tensor.prod();
+
+        output.into_data().assert_approx_eq(&Data::from([-24.0]), 3);
+    }
+
+    #[test]
+    fn should_support_prod_of_odd_and_single_sizes() {
+        let device = Default::default();
+        let odd = TestTensor::<1>::from_floats([2.0, 3.0, 4.0, 5.0, 6.0], &device);
+        let single = TestTensor::<1>::from_floats([7.0], &device);
+
+        odd.prod()
+            .into_data()
+            .assert_approx_eq(&Data::from([720.0]), 3);
+        single
+            .prod()
+            .into_data()
+            .assert_approx_eq(&Data::from([7.0]), 3);
+    }
+}
@@ -13,4 +13,14 @@ mod tests {
         let data_expected = Data::from([[2.0, 1.0, 0.5], [0.33333, -0.25, -0.2]]);
         data_expected.assert_approx_eq(&data_actual, 3);
     }
+
+    #[test]
+    fn should_match_division_of_ones() {
+        let tensor =
+            TestTensor::from_floats([[0.5, 1.0, 3.0], [-4.0, 7.0, -0.1]], &Default::default());
+
+        let expected = tensor.ones_like().div(tensor.clone()).into_data();
+
+        tensor.recip().into_data().assert_approx_eq(&expected, 5);
+    }
 }
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(remainder)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_remainder_ops() {
+        let device = Default::default();
+        let lhs = TestTensor::from_data([[-7.0, 7.0, -7.0], [7.0, 5.5, -6.0]], &device);
+        let rhs = TestTensor::from_data([[3.0, 3.0, -3.0], [-3.0, 2.0, 3.0]], &device);
+
+        let data_actual = lhs.remainder(rhs).into_data();
+
+        let data_expected = Data::from([[2.0, 1.0, -1.0], [-2.0, 1.5, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_remainder_scalar_ops() {
+        let tensor = TestTensor::from([[-7.0, -1.5, 0.0], [1.5, 7.0, 9.0]]);
+
+        let data_actual = tensor.remainder_scalar(3.0).into_data();
+
+        let data_expected = Data::from([[2.0, 1.5, 0.0], [1.5, 1.0, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_remainder_ops_int() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2, Int>::from_data([[-7, 7, -7], [7, 6, -6]], &device);
+        let rhs = Tensor::<TestBackend, 2, Int>::from_data([[3, 3, -3], [-3, 3, -3]], &device);
+
+        let data_actual = lhs.remainder(rhs).into_data();
+
+        let data_expected = Data::from([[2, 1, -1], [-2, 0, 0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_remainder_scalar_ops_int() {
+        let tensor = TestTensorInt::from([[-7, -1, 0], [1, 7, 9]]);
+
+        let data_actual = (tensor % 3).into_data();
+
+        let data_expected = Data::from([[2, 2, 0], [1, 1, 0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_rem_operator() {
+        let device = Default::default();
+        let lhs = TestTensor::from_data([-7.0, 7.0], &device);
+        let rhs = TestTensor::from_data([3.0, -3.0], &device);
+
+        let data_actual = (lhs % rhs).into_data();
+
+        let data_expected = Data::from([2.0, -2.0]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_roll_forward() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let output = tensor.roll(1, 1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_roll_backward_with_negative_shift() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+
+        let output = tensor.roll(-1, 1);
+
+        assert_eq!(output.into_data(), Data::from([[2, 3, 1], [5, 6, 4]]));
+    }
+
+    #[test]
+    fn should_not_change_the_tensor_when_the_shift_is_the_dim_size() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let output = tensor.clone().roll(2, 0);
+
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn should_roll_by_the_shift_modulo_the_dim_size() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..4, &device);
+
+        assert_eq!(
+            tensor.clone().roll(5, 0).into_data(),
+            Data::from([3, 0, 1, 2])
+        );
+        assert_eq!(tensor.roll(-6, 0).into_data(), Data::from([2, 3, 0, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_dim_is_out_of_range() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        tensor.roll(1, 2);
+    }
+}
@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(round)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_round_ops() {
+        let tensor = TestTensor::from([[-2.5, -1.5, -0.5], [0.5, 1.5, 2.5]]);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([[-2.0, -2.0, -0.0], [0.0, 2.0, 2.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_round_to_nearest_integer() {
+        let tensor = TestTensor::from([[-2.7, -1.2, 0.2], [0.7, 1.4, 2.6]]);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([[-3.0, -1.0, 0.0], [1.0, 1.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn test_sort_1d_float() {
+        let tensor = TestTensor::from_floats([0.5, -1.0, 3.0, 0.0, 2.5], &Default::default());
+
+        let output = tensor.sort(0);
+
+        assert_eq!(output.into_data(), Data::from([-1.0, 0.0, 0.5, 2.5, 3.0]));
+    }
+
+    #[test]
+    fn test_sort_with_indices_2d_dim_0() {
+        let tensor = TestTensor::from_floats(
+            [[3.0, 1.0, 2.0], [1.0, 5.0, 0.0], [2.0, 4.0, 1.0]],
+            &Default::default(),
+        );
+
+        let (values, indices) = tensor.sort_with_indices(0);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[1.0, 1.0, 0.0], [2.0, 4.0, 1.0], [3.0, 5.0, 2.0]])
+        );
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[1, 0, 1], [2, 2, 2], [0, 1, 0]])
+        );
+    }
+
+    #[test]
+    fn test_argsort_int_is_stable() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints(
+            [[2, 1, 2, 0], [5, 5, 3, 5]],
+            &Default::default(),
+        );
+
+        let indices = tensor.argsort(1);
+
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[3, 1, 0, 2], [2, 0, 1, 3]])
+        );
+    }
+}
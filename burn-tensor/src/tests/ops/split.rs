@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(split)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn test_split_even_sizes() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..6, &Default::default()).split(&[3, 3], 0);
+        assert_eq!(tensors.len(), 2);
+
+        let expected = vec![Data::from([0, 1, 2]), Data::from([3, 4, 5])];
+
+        for (index, tensor) in tensors.iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    fn test_split_uneven_sizes() {
+        let tensor = TestTensor::from_floats(
+            [
+                [0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+                [6.0, 7.0, 8.0, 9.0, 10.0, 11.0],
+            ],
+            &Default::default(),
+        );
+
+        let tensors = tensor.split(&[1, 3, 2], 1);
+        assert_eq!(tensors.len(), 3);
+
+        let expected = vec![
+            Data::from([[0.0], [6.0]]),
+            Data::from([[1.0, 2.0, 3.0], [7.0, 8.0, 9.0]]),
+            Data::from([[4.0, 5.0], [10.0, 11.0]]),
+        ];
+
+        for (index, tensor) in tensors.iter().enumerate() {
+            assert_eq!(tensor.to_data(), expected[index]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_sizes_not_summing_to_dim_size() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..6, &Default::default()).split(&[3, 2], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_zero_size() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..6, &Default::default()).split(&[3, 0, 3], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_invalid_dim() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..6, &Default::default()).split(&[3, 3], 1);
+    }
+}
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(sum_stable)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_sum_stable_small_tensor() {
+        let tensor =
+            TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &Default::default());
+
+        let sum = tensor.clone().sum_stable().into_scalar();
+        let mean = tensor.mean_stable().into_scalar();
+
+        assert_eq!(sum, 21.0);
+        assert_eq!(mean, 3.5);
+    }
+
+    #[test]
+    fn should_sum_stable_single_and_empty_tensor() {
+        let device = Default::default();
+
+        let single = TestTensor::<1>::from_floats([4.5], &device);
+        assert_eq!(single.sum_stable().into_scalar(), 4.5);
+
+        let empty = TestTensor::<1>::empty([0], &device);
+        assert_eq!(empty.sum_stable().into_scalar(), 0.0);
+    }
+
+    #[test]
+    fn should_sum_stable_match_f64_reference() {
+        // One large value followed by many values below its rounding precision
+        let mut values = vec![1.0f32];
+        values.extend(core::iter::repeat(1e-8f32).take(99_999));
+
+        let reference: f64 = values.iter().map(|&v| v as f64).sum();
+        let naive = values.iter().fold(0.0f32, |acc, &v| acc + v);
+        assert!((naive as f64 - reference).abs() > 1e-4);
+
+        let tensor = TestTensor::<1>::from_data(
+            Data::new(values.clone(), [values.len()].into()).convert(),
+            &Default::default(),
+        );
+
+        let sum = tensor.clone().sum_stable().into_scalar() as f64;
+        let mean = tensor.mean_stable().into_scalar() as f64;
+
+        assert!((sum - reference).abs() < 1e-6);
+        assert!((mean - reference / values.len() as f64).abs() < 1e-10);
+    }
+}
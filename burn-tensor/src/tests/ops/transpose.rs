@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(transpose)]
 mod tests {
     use super::*;
-    use burn_tensor::{Bool, Data, Int, Tensor};
+    use burn_tensor::{Bool, Data, Int, Shape, Tensor};
 
     #[test]
     fn should_support_transpose_ops() {
@@ -22,6 +22,21 @@ mod tests {
         data_expected.assert_approx_eq(&data_actual, 3);
     }
 
+    #[test]
+    fn should_transpose_matrix() {
+        let tensor =
+            TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &Default::default());
+
+        let transposed = tensor.clone().t();
+
+        assert_eq!(transposed.shape(), Shape::new([3, 2]));
+        assert_eq!(
+            transposed.to_data(),
+            Data::from([[0.0, 3.0], [1.0, 4.0], [2.0, 5.0]])
+        );
+        assert_eq!(transposed.into_data(), tensor.swap_dims(0, 1).into_data());
+    }
+
     #[test]
     fn should_support_swap_dims() {
         let tensor = TestTensor::from_floats(
@@ -42,6 +57,24 @@ mod tests {
         data_expected.assert_approx_eq(&data_actual, 3);
     }
 
+    #[test]
+    fn should_return_the_same_tensor_when_swapping_a_dim_with_itself() {
+        let data = Data::from([[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]);
+        let tensor = TestTensor::from_floats(data.clone(), &Default::default());
+
+        let data_actual = tensor.swap_dims(1, 1).into_data();
+
+        assert_eq!(data, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_swapping_an_out_of_range_dim_with_itself() {
+        let tensor = TestTensor::from_floats([[[0.0, 1.0, 2.0]]], &Default::default());
+
+        let _ = tensor.swap_dims(3, 3);
+    }
+
     #[test]
     fn should_support_transpose_ops_int() {
         let tensor = Tensor::<TestBackend, 3, Int>::from_data(
@@ -131,6 +131,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tril_non_square() {
+        // causal mask of 2 queries attending the 2 previous keys and themselves
+        let tensor: Tensor<TestBackend, 2> = Tensor::ones([2, 4], &Default::default());
+
+        let output = tensor.tril(2);
+        assert_eq!(
+            output.to_data(),
+            Data::from([[1., 1., 1., 0.], [1., 1., 1., 1.]])
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_tril_too_few_dims() {
@@ -1,4 +1,5 @@
 mod cov;
 mod diagonal;
 mod display;
+mod std_dev;
 mod var;
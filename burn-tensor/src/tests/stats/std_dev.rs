@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(std_dev)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_std() {
+        let tensor = TestTensor::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let data_actual = tensor.std(1).into_data();
+
+        let data_expected = Data::from([[1.57772], [3.91578]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_std_mean() {
+        let tensor = TestTensor::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let (std, mean) = tensor.clone().std_mean(1);
+
+        let std_expected = tensor.clone().std(1).into_data();
+        let mean_expected = tensor.mean_dim(1).into_data();
+
+        std_expected.assert_approx_eq(&std.into_data(), 5);
+        mean_expected.assert_approx_eq(&mean.into_data(), 5);
+    }
+
+    #[test]
+    fn test_std_bias() {
+        let tensor = TestTensor::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let data_actual = tensor.std_bias(1).into_data();
+
+        let data_expected = Data::from([[1.36634], [3.39116]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_std_mean_bias() {
+        let tensor = TestTensor::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let (std, mean) = tensor.clone().std_mean_bias(1);
+
+        let std_expected = tensor.clone().std_bias(1).into_data();
+        let mean_expected = tensor.mean_dim(1).into_data();
+
+        std_expected.assert_approx_eq(&std.into_data(), 5);
+        mean_expected.assert_approx_eq(&mean.into_data(), 5);
+    }
+}
@@ -71,6 +71,18 @@ pub enum Operator {
         input: Variable,
         out: Variable,
     },
+    Floor {
+        input: Variable,
+        out: Variable,
+    },
+    Ceil {
+        input: Variable,
+        out: Variable,
+    },
+    Round {
+        input: Variable,
+        out: Variable,
+    },
     Equal {
         lhs: Variable,
         rhs: Variable,
@@ -220,6 +232,18 @@ impl Operator {
                 input: input.vectorize(vectorize),
                 out: out.vectorize(vectorize),
             },
+            Operator::Floor { input, out } => Operator::Floor {
+                input: input.vectorize(vectorize),
+                out: out.vectorize(vectorize),
+            },
+            Operator::Ceil { input, out } => Operator::Ceil {
+                input: input.vectorize(vectorize),
+                out: out.vectorize(vectorize),
+            },
+            Operator::Round { input, out } => Operator::Round {
+                input: input.vectorize(vectorize),
+                out: out.vectorize(vectorize),
+            },
             Operator::AssignGlobal { input, out } => Operator::AssignGlobal {
                 input: input.vectorize(vectorize),
                 out: out.vectorize(vectorize),
@@ -315,6 +339,15 @@ impl Display for Operator {
             Operator::Recip { input, out } => {
                 f.write_fmt(format_args!("let {out} = 1.0 / {input};"))
             }
+            Operator::Floor { input, out } => {
+                f.write_fmt(format_args!("let {out} = floor({input});"))
+            }
+            Operator::Ceil { input, out } => {
+                f.write_fmt(format_args!("let {out} = ceil({input});"))
+            }
+            Operator::Round { input, out } => {
+                f.write_fmt(format_args!("let {out} = round({input});"))
+            }
             Operator::Equal { lhs, rhs, out } => comparison(lhs, rhs, out, "==", f),
             Operator::Lower { lhs, rhs, out } => comparison(lhs, rhs, out, "<", f),
             Operator::Greater { lhs, rhs, out } => comparison(lhs, rhs, out, ">", f),
@@ -277,6 +277,18 @@ where
                     mark(input, &mut local_tensor_ids_input);
                     mark(out, &mut local_tensor_ids_output);
                 }
+                Operator::Floor { input, out } => {
+                    mark(input, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
+                Operator::Ceil { input, out } => {
+                    mark(input, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
+                Operator::Round { input, out } => {
+                    mark(input, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
                 Operator::Lower { lhs, rhs, out } => {
                     mark(lhs, &mut local_tensor_ids_input);
                     mark(rhs, &mut local_tensor_ids_input);
@@ -452,6 +464,21 @@ where
                     Operator::Recip { input, out }
                 })
             }
+            FloatOpsDescription::Floor(desc) => {
+                self.register_unary_ops(desc, (E::elem_type(), E::elem_type()), |input, out| {
+                    Operator::Floor { input, out }
+                })
+            }
+            FloatOpsDescription::Ceil(desc) => {
+                self.register_unary_ops(desc, (E::elem_type(), E::elem_type()), |input, out| {
+                    Operator::Ceil { input, out }
+                })
+            }
+            FloatOpsDescription::Round(desc) => {
+                self.register_unary_ops(desc, (E::elem_type(), E::elem_type()), |input, out| {
+                    Operator::Round { input, out }
+                })
+            }
             _ => false,
         }
     }
@@ -38,9 +38,10 @@ pub(crate) fn avg_pool2d<E: WgpuElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> WgpuTensor<E, 4> {
     let (info_handle, output) =
-        build_output_and_info_pool2d(&x, kernel_size, stride, padding, [1, 1]);
+        build_output_and_info_pool2d(&x, kernel_size, stride, padding, [1, 1], ceil_mode);
 
     let workgroup = elemwise_workgroup(output.shape.num_elements(), WORKGROUP_DEFAULT);
     let kernel: Box<dyn Kernel> = match count_include_pad {
@@ -119,9 +120,22 @@ mod tests {
         let padding = [1, 2];
         let count_include_pad = true;
 
-        let pooled = module::avg_pool2d(tensor, kernel_size, stride, padding, count_include_pad);
-        let pooled_ref =
-            module::avg_pool2d(tensor_ref, kernel_size, stride, padding, count_include_pad);
+        let pooled = module::avg_pool2d(
+            tensor,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            false,
+        );
+        let pooled_ref = module::avg_pool2d(
+            tensor_ref,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            false,
+        );
 
         pooled
             .into_data()
@@ -150,6 +164,7 @@ mod tests {
             stride,
             padding,
             count_include_pad,
+            false,
         )
         .shape();
         let grad_output =
@@ -165,6 +180,7 @@ mod tests {
                 stride,
                 padding,
                 count_include_pad,
+                false,
             ));
         let grad_ref: Tensor<ReferenceBackend, 4> =
             Tensor::from_primitive(ReferenceBackend::avg_pool2d_backward(
@@ -174,6 +190,7 @@ mod tests {
                 stride,
                 padding,
                 count_include_pad,
+                false,
             ));
 
         grad.into_data().assert_approx_eq(&grad_ref.into_data(), 3);
@@ -1,7 +1,7 @@
 use crate::{
     compute::WgpuHandle, element::WgpuElement, ops::numeric::empty_device, tensor::WgpuTensor,
 };
-use burn_tensor::Shape;
+use burn_tensor::{ops::conv::calculate_pool_output_size, Shape};
 
 /// Build basic info to launch pool 2d kernels.
 pub fn build_output_and_info_pool2d<E: WgpuElement>(
@@ -10,6 +10,7 @@ pub fn build_output_and_info_pool2d<E: WgpuElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> (WgpuHandle, WgpuTensor<E, 4>) {
     let [kernel_height, kernel_width] = kernel_size;
     let [padding_height, padding_width] = padding;
@@ -17,12 +18,22 @@ pub fn build_output_and_info_pool2d<E: WgpuElement>(
     let [dilation_height, dilation_width] = dilation;
     let [batch_size, channels, x_height, x_width] = x.shape.dims;
 
-    let out_height = ((x_height + 2 * padding_height - dilation_height * (kernel_height - 1) - 1)
-        / stride_height)
-        + 1;
-    let out_width = ((x_width + 2 * padding_width - dilation_width * (kernel_width - 1) - 1)
-        / stride_width)
-        + 1;
+    let out_height = calculate_pool_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        x_height,
+        ceil_mode,
+    );
+    let out_width = calculate_pool_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        x_width,
+        ceil_mode,
+    );
     let shape_out = Shape::new([batch_size, channels, out_height, out_width]);
     let output = empty_device(x.client.clone(), x.device.clone(), shape_out);
 
@@ -27,9 +27,10 @@ pub(crate) fn max_pool2d<E: WgpuElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> WgpuTensor<E, 4> {
     let (info_handle, output) =
-        build_output_and_info_pool2d(&x, kernel_size, stride, padding, dilation);
+        build_output_and_info_pool2d(&x, kernel_size, stride, padding, dilation, ceil_mode);
     let kernel = StaticKernel::<
         KernelSettings<MaxPool2d, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
     >::new(elemwise_workgroup(
@@ -49,9 +50,10 @@ pub(crate) fn max_pool2d_with_indices<E: WgpuElement, I: WgpuElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> (WgpuTensor<E, 4>, WgpuTensor<I, 4>) {
     let (info_handle, output) =
-        build_output_and_info_pool2d(&x, kernel_size, stride, padding, dilation);
+        build_output_and_info_pool2d(&x, kernel_size, stride, padding, dilation, ceil_mode);
     let indices = empty_device(x.client.clone(), x.device, output.shape.clone());
 
     let kernel = StaticKernel::<
@@ -120,8 +122,9 @@ mod tests {
         let padding = [1, 1];
         let dilation = [1, 1];
 
-        let pooled = module::max_pool2d(tensor, kernel_size, stride, padding, dilation);
-        let pooled_ref = module::max_pool2d(tensor_ref, kernel_size, stride, padding, dilation);
+        let pooled = module::max_pool2d(tensor, kernel_size, stride, padding, dilation, false);
+        let pooled_ref =
+            module::max_pool2d(tensor_ref, kernel_size, stride, padding, dilation, false);
 
         pooled
             .into_data()
@@ -143,9 +146,15 @@ mod tests {
         let dilation = [1, 1];
 
         let (pooled, indices) =
-            module::max_pool2d_with_indices(tensor, kernel_size, stride, padding, dilation);
-        let (pooled_ref, indices_ref) =
-            module::max_pool2d_with_indices(tensor_ref, kernel_size, stride, padding, dilation);
+            module::max_pool2d_with_indices(tensor, kernel_size, stride, padding, dilation, false);
+        let (pooled_ref, indices_ref) = module::max_pool2d_with_indices(
+            tensor_ref,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            false,
+        );
 
         pooled
             .into_data()
@@ -169,14 +178,21 @@ mod tests {
         let padding = [1, 1];
         let dilation = [1, 1];
 
-        let (_, indices) =
-            module::max_pool2d_with_indices(tensor.clone(), kernel_size, stride, padding, dilation);
+        let (_, indices) = module::max_pool2d_with_indices(
+            tensor.clone(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            false,
+        );
         let (_, indices_ref) = module::max_pool2d_with_indices(
             tensor_ref.clone(),
             kernel_size,
             stride,
             padding,
             dilation,
+            false,
         );
         let grad = TestBackend::max_pool2d_with_indices_backward(
             tensor.into_primitive(),
@@ -184,6 +200,7 @@ mod tests {
             stride,
             padding,
             dilation,
+            false,
             grad_output.into_primitive(),
             indices.into_primitive(),
         )
@@ -194,6 +211,7 @@ mod tests {
             stride,
             padding,
             dilation,
+            false,
             grad_output_ref.into_primitive(),
             indices_ref.into_primitive(),
         )
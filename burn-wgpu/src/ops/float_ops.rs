@@ -502,6 +502,45 @@ where
         )
     }
 
+    fn floor<const D: usize>(
+        tensor: FloatTensor<Wgpu<G, F, I>, D>,
+    ) -> FloatTensor<Wgpu<G, F, I>, D> {
+        unary!(
+            operator: |elem: Elem| Operator::Floor {
+                input: Variable::Input(0, Item::Scalar(elem)),
+                out: Variable::Local(0, Item::Scalar(elem)),
+            },
+            input: tensor,
+            elem: F
+        )
+    }
+
+    fn ceil<const D: usize>(
+        tensor: FloatTensor<Wgpu<G, F, I>, D>,
+    ) -> FloatTensor<Wgpu<G, F, I>, D> {
+        unary!(
+            operator: |elem: Elem| Operator::Ceil {
+                input: Variable::Input(0, Item::Scalar(elem)),
+                out: Variable::Local(0, Item::Scalar(elem)),
+            },
+            input: tensor,
+            elem: F
+        )
+    }
+
+    fn round<const D: usize>(
+        tensor: FloatTensor<Wgpu<G, F, I>, D>,
+    ) -> FloatTensor<Wgpu<G, F, I>, D> {
+        unary!(
+            operator: |elem: Elem| Operator::Round {
+                input: Variable::Input(0, Item::Scalar(elem)),
+                out: Variable::Local(0, Item::Scalar(elem)),
+            },
+            input: tensor,
+            elem: F
+        )
+    }
+
     fn repeat<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
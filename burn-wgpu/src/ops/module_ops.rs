@@ -39,8 +39,16 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
-        kernel::pool::avg_pool2d(x, kernel_size, stride, padding, count_include_pad)
+        kernel::pool::avg_pool2d(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
 
     fn avg_pool2d_backward(
@@ -50,6 +58,7 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        _ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         kernel::pool::avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
     }
@@ -60,8 +69,9 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
-        kernel::pool::max_pool2d(x, kernel_size, stride, padding, dilation)
+        kernel::pool::max_pool2d(x, kernel_size, stride, padding, dilation, ceil_mode)
     }
 
     fn max_pool2d_with_indices(
@@ -70,9 +80,16 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<Wgpu<G, F, I>> {
-        let (output, indices) =
-            kernel::pool::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation);
+        let (output, indices) = kernel::pool::max_pool2d_with_indices(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
 
         MaxPool2dWithIndices::new(output, indices)
     }
@@ -83,6 +100,7 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        _ceil_mode: bool,
         output_grad: FloatTensor<Self, 4>,
         indices: IntTensor<Self, 4>,
     ) -> MaxPool2dBackward<Wgpu<G, F, I>> {
@@ -81,7 +81,10 @@ fn main(
     }
 
     if COUNT_INCLUDE_PAD {
-        count = {{ elem }}(kernel_size_1 * kernel_size_0);
+        // Padding is counted, but not the part of the window that overflows it.
+        let ih_end = min(oh * pool_stride_0 + kernel_size_0, input_shape_2 + 2u * padding_0);
+        let iw_end = min(ow * pool_stride_1 + kernel_size_1, input_shape_3 + 2u * padding_1);
+        count = {{ elem }}((ih_end - oh * pool_stride_0) * (iw_end - ow * pool_stride_1));
     }
     output[id] = sum / count;
 }
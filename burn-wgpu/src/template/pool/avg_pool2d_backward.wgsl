@@ -76,8 +76,10 @@ fn main(
             var ih_start = oh * pool_stride_0;
             var iw_start = ow * pool_stride_1;
 
-            var ih_end = ih_start + kernel_size_0;
-            var iw_end = iw_start + kernel_size_1;
+            var ih_end = min(ih_start + kernel_size_0, input_shape_2 + 2u * padding_0);
+            var iw_end = min(iw_start + kernel_size_1, input_shape_3 + 2u * padding_1);
+
+            let count_padded = (ih_end - ih_start) * (iw_end - iw_start);
 
             ih_start = max(ih_start, padding_0);
             iw_start = max(iw_start, padding_1);
@@ -96,7 +98,7 @@ fn main(
             var count = 0.0;
 
             if COUNT_INCLUDE_PAD {
-                count = {{ elem }}(kernel_size_0 * kernel_size_1);
+                count = {{ elem }}(count_padded);
             } else {
                 count = {{ elem }}((ih_end - ih_start) * (iw_end - iw_start));
             }
@@ -84,7 +84,7 @@ fn main(
 
             if max_val < val {
                 max_val = val;
-                index = ih_pad * input_shape_2 + iw_pad;
+                index = ih_pad * input_shape_3 + iw_pad;
             }
         }
     }